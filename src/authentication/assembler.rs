@@ -0,0 +1,345 @@
+use crate::authentication::Authentication;
+use crate::authentication::authentication_type::AuthenticationType;
+use crate::authentication::initial::Initial;
+use crate::error::Error;
+use crate::system::Timestamp;
+
+/// Authentication Page Assembler
+///
+/// Collects the pages of one authentication set, from page `0` to
+/// [`Initial::last_page_index`], and reassembles the authentication data they carry.
+///
+/// Pages may be pushed in any order. Every page must share the same [`AuthenticationType`] and no
+/// page may be pushed twice. Once every page is present, [`Assembler::try_assemble`] returns the
+/// first [`Initial::total_length`] bytes of the concatenated page data.
+///
+/// Broadcasts repeat the same set over and over, so a receiver will generally see
+/// [`Error::DuplicatePage`] from [`Assembler::try_push`] on rebroadcasts; a failed push leaves the
+/// assembler unchanged. Use [`Assembler::reset`] to start collecting a new set.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Assembler {
+    authentication_type: Option<AuthenticationType>,
+    initial: Option<Initial>,
+    // bit `n` is set once page `n` is received.
+    received_pages: u16,
+    data: [u8; Assembler::MAX_DATA_LENGTH],
+}
+
+impl Assembler {
+    /// Byte length of the data carried by the [`Initial`] page.
+    pub const INITIAL_DATA_LENGTH: usize = 17;
+
+    /// Byte length of the data carried by each [`Subsequent`](crate::authentication::Subsequent)
+    /// page.
+    pub const SUBSEQUENT_DATA_LENGTH: usize = 23;
+
+    /// Maximum number of pages in a set.
+    pub const MAX_PAGES: usize = 16;
+
+    /// Maximum byte length of the data carried by a full set of pages (`17 + 15 * 23`).
+    pub const MAX_DATA_LENGTH: usize = 362;
+
+    /// Constructs a new, empty assembler.
+    pub fn new() -> Self {
+        Self {
+            authentication_type: None,
+            initial: None,
+            received_pages: 0,
+            data: [0u8; Self::MAX_DATA_LENGTH],
+        }
+    }
+
+    /// Returns the authentication type shared by the pages, if any page was pushed.
+    pub fn authentication_type(&self) -> Option<AuthenticationType> {
+        self.authentication_type
+    }
+
+    /// Returns the system [`Timestamp`] of the set, if the [`Initial`] page was pushed.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.initial.map(|initial| initial.timestamp())
+    }
+
+    /// Returns true if every page from `0` to [`Initial::last_page_index`] was pushed.
+    pub fn is_complete(&self) -> bool {
+        self.missing_pages().next().is_none()
+    }
+
+    /// Returns an iterator over the page numbers not yet pushed.
+    ///
+    /// Until the [`Initial`] page is pushed, the last page index is unknown, so only the pages up
+    /// to the highest page number pushed so far are reported.
+    pub fn missing_pages(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..=self.last_page_index()).filter(|page_number| !self.is_received(*page_number))
+    }
+
+    /// Pushes one authentication page.
+    ///
+    /// Returns an error if:
+    ///
+    /// - the page type differs from the pages already pushed.
+    /// - the page was already pushed.
+    /// - the page number is beyond the last page index of the [`Initial`] page.
+    /// - a [`Subsequent`](crate::authentication::Subsequent) page has page number `0`.
+    pub fn try_push(&mut self, authentication: &Authentication) -> Result<(), Error> {
+        let (authentication_type, page_number) = match authentication {
+            Authentication::Initial(initial) => (initial.authentication_type(), 0),
+            Authentication::Subsequent(subsequent) => {
+                if subsequent.page_number() == 0 {
+                    return Err(Error::InvalidInteger);
+                }
+
+                (subsequent.authentication_type(), subsequent.page_number())
+            }
+        };
+
+        if self
+            .authentication_type
+            .is_some_and(|expected| expected != authentication_type)
+        {
+            return Err(Error::AuthenticationTypeMismatch);
+        }
+
+        if self.is_received(page_number) {
+            return Err(Error::DuplicatePage);
+        }
+
+        match authentication {
+            Authentication::Initial(initial) => {
+                // pages already pushed must fit within the declared set.
+                let beyond_last_page = self
+                    .received_pages
+                    .checked_shr(initial.last_page_index() as u32 + 1)
+                    .unwrap_or(0);
+
+                if beyond_last_page != 0 {
+                    return Err(Error::InvalidInteger);
+                }
+
+                self.data[..Self::INITIAL_DATA_LENGTH].clone_from_slice(initial.data());
+
+                self.initial = Some(*initial);
+            }
+            Authentication::Subsequent(subsequent) => {
+                if self
+                    .initial
+                    .is_some_and(|initial| page_number > initial.last_page_index())
+                {
+                    return Err(Error::InvalidInteger);
+                }
+
+                let start =
+                    Self::INITIAL_DATA_LENGTH + (page_number - 1) * Self::SUBSEQUENT_DATA_LENGTH;
+
+                self.data[start..start + Self::SUBSEQUENT_DATA_LENGTH]
+                    .clone_from_slice(subsequent.data());
+            }
+        }
+
+        self.authentication_type = Some(authentication_type);
+        self.received_pages |= 1 << page_number;
+
+        Ok(())
+    }
+
+    /// Returns the reassembled authentication data, [`Initial::total_length`] bytes long.
+    ///
+    /// Returns an error if:
+    ///
+    /// - any page is missing.
+    /// - the total length exceeds the data carried by the pages.
+    pub fn try_assemble(&self) -> Result<&[u8], Error> {
+        let Some(initial) = self.initial else {
+            return Err(Error::MissingPage);
+        };
+
+        if !self.is_complete() {
+            return Err(Error::MissingPage);
+        }
+
+        let carried_length =
+            Self::INITIAL_DATA_LENGTH + initial.last_page_index() * Self::SUBSEQUENT_DATA_LENGTH;
+
+        if initial.total_length() > carried_length {
+            return Err(Error::InvalidDataLength);
+        }
+
+        Ok(&self.data[..initial.total_length()])
+    }
+
+    /// Clears every page, to start collecting a new set.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    // last page index of the initial page if known, otherwise the highest page number received.
+    fn last_page_index(&self) -> usize {
+        match self.initial {
+            Some(initial) => initial.last_page_index(),
+            None => (u16::BITS - self.received_pages.leading_zeros()).saturating_sub(1) as usize,
+        }
+    }
+
+    fn is_received(&self, page_number: usize) -> bool {
+        self.received_pages & (1 << page_number) != 0
+    }
+}
+
+impl Default for Assembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::{Assembler, Authentication, AuthenticationType, Initial, Subsequent},
+        error::Error,
+        system::Timestamp,
+    };
+
+    fn initial(last_page_index: usize, total_length: usize) -> Authentication {
+        Authentication::Initial(
+            Initial::try_new(
+                AuthenticationType::UASIDSignature,
+                last_page_index,
+                total_length,
+                Timestamp::new(1),
+                [0u8; 17],
+            )
+            .unwrap(),
+        )
+    }
+
+    fn subsequent(page_number: usize) -> Authentication {
+        Authentication::Subsequent(
+            Subsequent::try_new(
+                AuthenticationType::UASIDSignature,
+                page_number,
+                [page_number as u8; 23],
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_assemble_single_page() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&initial(0, 10)).unwrap();
+
+        assert!(assembler.is_complete());
+        assert_eq!(
+            assembler.authentication_type(),
+            Some(AuthenticationType::UASIDSignature)
+        );
+        assert_eq!(assembler.timestamp(), Some(Timestamp::new(1)));
+        assert_eq!(assembler.try_assemble(), Ok([0u8; 10].as_ref()));
+    }
+
+    #[test]
+    fn test_assemble_out_of_order() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&subsequent(2)).unwrap();
+        assembler.try_push(&subsequent(1)).unwrap();
+
+        assert!(!assembler.is_complete());
+        assert_eq!(assembler.timestamp(), None);
+
+        assembler.try_push(&initial(2, 17 + 23 + 5)).unwrap();
+
+        let data = assembler.try_assemble().unwrap();
+
+        assert_eq!(data.len(), 45);
+        assert_eq!(data[..17], [0u8; 17]);
+        assert_eq!(data[17..40], [1u8; 23]);
+        assert_eq!(data[40..], [2u8; 5]);
+    }
+
+    #[test]
+    fn test_missing_pages() {
+        let mut assembler = Assembler::new();
+
+        assert_eq!(assembler.missing_pages().next(), Some(0));
+
+        assembler.try_push(&subsequent(2)).unwrap();
+
+        assert!(assembler.missing_pages().eq([0, 1]));
+
+        assembler.try_push(&initial(4, 100)).unwrap();
+
+        assert!(assembler.missing_pages().eq([1, 3, 4]));
+        assert_eq!(assembler.try_assemble(), Err(Error::MissingPage));
+    }
+
+    #[test]
+    fn test_push_fails_type_mismatch() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&initial(1, 20)).unwrap();
+
+        let other_type = Authentication::Subsequent(
+            Subsequent::try_new(AuthenticationType::OperatorIDSignature, 1, [0u8; 23]).unwrap(),
+        );
+
+        assert_eq!(
+            assembler.try_push(&other_type),
+            Err(Error::AuthenticationTypeMismatch)
+        );
+        assert!(assembler.missing_pages().eq([1]));
+    }
+
+    #[test]
+    fn test_push_fails_duplicate_page() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&subsequent(1)).unwrap();
+
+        assert_eq!(
+            assembler.try_push(&subsequent(1)),
+            Err(Error::DuplicatePage)
+        );
+    }
+
+    #[test]
+    fn test_push_fails_beyond_last_page_index() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&initial(1, 20)).unwrap();
+
+        assert_eq!(
+            assembler.try_push(&subsequent(2)),
+            Err(Error::InvalidInteger)
+        );
+
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&subsequent(2)).unwrap();
+
+        assert_eq!(
+            assembler.try_push(&initial(1, 20)),
+            Err(Error::InvalidInteger)
+        );
+    }
+
+    #[test]
+    fn test_assemble_fails_invalid_total_length() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&initial(0, 18)).unwrap();
+
+        assert_eq!(assembler.try_assemble(), Err(Error::InvalidDataLength));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut assembler = Assembler::new();
+
+        assembler.try_push(&initial(0, 10)).unwrap();
+        assembler.reset();
+
+        assert_eq!(assembler, Assembler::default());
+        assert_eq!(assembler.try_assemble(), Err(Error::MissingPage));
+    }
+}
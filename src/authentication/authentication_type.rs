@@ -9,6 +9,7 @@ use crate::error::Error;
 /// Enumeration is used for authentication messages. Values of `6` to `9` are reserved, though
 /// values from `0x0A` to `0x0F` are available for private use.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AuthenticationType {
     /// No authentication.
     None,
@@ -27,10 +28,11 @@ pub enum AuthenticationType {
     /// Uses the Internet Assigned Number Authority (IANA) "Specification Required" system specified
     /// by the Internet Engineering Task Force's (IETF) RFC-8126.
     SpecificAuthenticationMessage,
-    /// Reserved.
-    ReservedForSpec,
-    /// Available for private use, particularly for local custom authentication types.
-    AvailableForPrivateUse,
+    /// Reserved for the specification, codes 6 to 9.
+    ReservedForSpec(u8),
+    /// Available for private use, particularly for local custom authentication types, codes 10
+    /// to 15.
+    AvailableForPrivateUse(u8),
 }
 
 impl AuthenticationType {
@@ -53,11 +55,11 @@ impl TryFrom<u8> for AuthenticationType {
         }
 
         if value >= Self::PRIVATE_USE_THRESHOLD {
-            return Ok(Self::AvailableForPrivateUse);
+            return Ok(Self::AvailableForPrivateUse(value));
         }
 
         if value >= Self::RESERVED_THRESHOLD {
-            return Ok(Self::ReservedForSpec);
+            return Ok(Self::ReservedForSpec(value));
         }
 
         // todo: de-nest
@@ -82,8 +84,14 @@ impl From<AuthenticationType> for u8 {
             AuthenticationType::MessageSetSignature => 3,
             AuthenticationType::NetworkRemoteIDAuthentication => 4,
             AuthenticationType::SpecificAuthenticationMessage => 5,
-            AuthenticationType::ReservedForSpec => AuthenticationType::RESERVED_THRESHOLD,
-            AuthenticationType::AvailableForPrivateUse => AuthenticationType::PRIVATE_USE_THRESHOLD,
+            AuthenticationType::ReservedForSpec(n) => n.clamp(
+                AuthenticationType::RESERVED_THRESHOLD,
+                AuthenticationType::PRIVATE_USE_THRESHOLD - 1,
+            ),
+            // the type is encoded in four bits.
+            AuthenticationType::AvailableForPrivateUse(n) => {
+                n.clamp(AuthenticationType::PRIVATE_USE_THRESHOLD, 0x0f)
+            }
         }
     }
 }
@@ -106,7 +114,7 @@ mod tests {
 
     #[test]
     fn test_encode_decode_reserved() {
-        let reserved = AuthenticationType::ReservedForSpec;
+        let reserved = AuthenticationType::ReservedForSpec(AuthenticationType::RESERVED_THRESHOLD);
 
         let encoded = u8::from(reserved);
 
@@ -118,7 +126,8 @@ mod tests {
 
     #[test]
     fn test_encode_decode_private_use() {
-        let private_use = AuthenticationType::AvailableForPrivateUse;
+        let private_use =
+            AuthenticationType::AvailableForPrivateUse(AuthenticationType::PRIVATE_USE_THRESHOLD);
 
         let encoded = u8::from(private_use);
 
@@ -128,6 +137,34 @@ mod tests {
         assert_eq!(private_use, decoded);
     }
 
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=0x0f {
+            let decoded = AuthenticationType::try_from(code).unwrap();
+
+            assert_eq!(u8::from(decoded), code);
+        }
+
+        assert_eq!(
+            AuthenticationType::try_from(0x0c),
+            Ok(AuthenticationType::AvailableForPrivateUse(0x0c))
+        );
+    }
+
+    #[test]
+    fn test_encode_clamps_invalid_value() {
+        assert_eq!(u8::from(AuthenticationType::ReservedForSpec(0)), 6);
+        assert_eq!(u8::from(AuthenticationType::ReservedForSpec(0xff)), 9);
+        assert_eq!(
+            u8::from(AuthenticationType::AvailableForPrivateUse(0)),
+            0x0a
+        );
+        assert_eq!(
+            u8::from(AuthenticationType::AvailableForPrivateUse(0xff)),
+            0x0f
+        );
+    }
+
     #[test]
     fn test_decode_fails_value_too_large() {
         let invalid_value = AuthenticationType::MAX + 1;
@@ -0,0 +1,156 @@
+use crate::authentication::drip::signed_evidence::SignedEvidence;
+use crate::error::Error;
+use crate::system::Timestamp;
+use crate::try_serialize::TrySerialize;
+
+/// DRIP Frame
+///
+/// [`SignedEvidence`] whose evidence is a one byte frame type followed by a raw transport frame, for
+/// example one of the structures in [`transport`](crate::transport).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Frame(SignedEvidence);
+
+impl Frame {
+    /// Maximum byte length of the frame.
+    pub const MAX_FRAME_LENGTH: usize = SignedEvidence::MAX_EVIDENCE_LENGTH - 1;
+
+    /// Constructs a new signed frame.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `frame` is longer than [`Frame::MAX_FRAME_LENGTH`].
+    pub fn try_new(
+        valid_not_before: Timestamp,
+        valid_not_after: Timestamp,
+        frame_type: u8,
+        frame: &[u8],
+        ua_det: [u8; 16],
+        signature: [u8; 64],
+    ) -> Result<Self, Error> {
+        if frame.len() > Self::MAX_FRAME_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let mut evidence = [0u8; SignedEvidence::MAX_EVIDENCE_LENGTH];
+
+        evidence[0] = frame_type;
+        evidence[1..1 + frame.len()].clone_from_slice(frame);
+
+        Ok(Self(SignedEvidence::try_new(
+            valid_not_before,
+            valid_not_after,
+            &evidence[..1 + frame.len()],
+            ua_det,
+            signature,
+        )?))
+    }
+
+    /// Returns the signed evidence.
+    pub fn signed_evidence(&self) -> &SignedEvidence {
+        &self.0
+    }
+
+    /// Returns a copy with the given signature, for signing after construction.
+    pub fn with_signature(self, signature: [u8; 64]) -> Self {
+        Self(self.0.with_signature(signature))
+    }
+
+    /// Returns the frame type.
+    pub fn frame_type(&self) -> u8 {
+        self.0.evidence()[0]
+    }
+
+    /// Returns the raw frame.
+    pub fn frame(&self) -> &[u8] {
+        &self.0.evidence()[1..]
+    }
+
+    /// Returns the encoded byte length.
+    pub fn encoding_byte_length(&self) -> usize {
+        self.0.encoding_byte_length()
+    }
+}
+
+impl TryFrom<&[u8]> for Frame {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let signed_evidence = SignedEvidence::try_from(value)?;
+
+        if signed_evidence.evidence().is_empty() {
+            return Err(Error::InvalidDataLength);
+        }
+
+        Ok(Self(signed_evidence))
+    }
+}
+
+impl TrySerialize for Frame {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.try_serialize(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::drip::Frame, error::Error, system::Timestamp, try_serialize::TrySerialize,
+    };
+
+    fn frame() -> Frame {
+        Frame::try_new(
+            Timestamp::new(10),
+            Timestamp::new(20),
+            1,
+            &[7u8; 31],
+            [5u8; 16],
+            [6u8; 64],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_try_new() {
+        let frame = frame();
+
+        assert_eq!(frame.frame_type(), 1);
+        assert_eq!(frame.frame(), &[7u8; 31]);
+        assert_eq!(frame.encoding_byte_length(), 88 + 32);
+    }
+
+    #[test]
+    fn test_try_new_fails_invalid_length() {
+        assert_eq!(
+            Frame::try_new(
+                Timestamp::new(10),
+                Timestamp::new(20),
+                1,
+                &[7u8; 112],
+                [5u8; 16],
+                [6u8; 64],
+            ),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let mut encoded = [0u8; 120];
+        frame().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[8], 1);
+        assert_eq!(Frame::try_from(encoded.as_ref()), Ok(frame()));
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let encoded = [0u8; 88];
+
+        assert_eq!(
+            Frame::try_from(encoded.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+}
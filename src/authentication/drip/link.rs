@@ -0,0 +1,233 @@
+use crate::error::Error;
+use crate::system::Timestamp;
+use crate::try_serialize::TrySerialize;
+
+/// DRIP Link
+///
+/// A broadcast endorsement of the UA by its HHIT Domain Authority (HDA), binding the UA DRIP Entity
+/// Tag (DET) to its Host Identity (HI), the Ed25519 public key receivers verify UA signatures with.
+///
+/// | Offset | Length | Field                 |
+/// | ------ | ------ | --------------------- |
+/// | 0      | 4      | Valid not before      |
+/// | 4      | 4      | Valid not after       |
+/// | 8      | 16     | UA DET                |
+/// | 24     | 32     | UA HI                 |
+/// | 56     | 16     | HDA DET               |
+/// | 72     | 64     | HDA Ed25519 signature |
+///
+/// The signature covers every field before it, see [`Link::signed_bytes`]. Timestamps use the
+/// system [`Timestamp`] encoding.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Link {
+    // every field up to the signature; the bytes covered by the signature.
+    signed: [u8; Link::SIGNED_LENGTH],
+    signature: [u8; 64],
+}
+
+impl Link {
+    /// Byte length of the encoded link.
+    pub const ENCODED_LENGTH: usize = 136;
+
+    // byte length of the bytes covered by the signature.
+    const SIGNED_LENGTH: usize = 72;
+
+    /// Constructs a new link.
+    pub fn new(
+        valid_not_before: Timestamp,
+        valid_not_after: Timestamp,
+        ua_det: [u8; 16],
+        ua_host_identity: [u8; 32],
+        hda_det: [u8; 16],
+        signature: [u8; 64],
+    ) -> Self {
+        let mut signed = [0u8; Self::SIGNED_LENGTH];
+
+        signed[0..4].clone_from_slice(&u32::from(valid_not_before).to_le_bytes());
+        signed[4..8].clone_from_slice(&u32::from(valid_not_after).to_le_bytes());
+        signed[8..24].clone_from_slice(&ua_det);
+        signed[24..56].clone_from_slice(&ua_host_identity);
+        signed[56..72].clone_from_slice(&hda_det);
+
+        Self { signed, signature }
+    }
+
+    /// Returns a copy with the given signature, for signing after construction.
+    pub fn with_signature(mut self, signature: [u8; 64]) -> Self {
+        self.signature = signature;
+
+        self
+    }
+
+    /// Returns the time before which the endorsement is not valid.
+    pub fn valid_not_before(&self) -> Timestamp {
+        u32::from_le_bytes([
+            self.signed[0],
+            self.signed[1],
+            self.signed[2],
+            self.signed[3],
+        ])
+        .into()
+    }
+
+    /// Returns the time after which the endorsement is not valid.
+    pub fn valid_not_after(&self) -> Timestamp {
+        u32::from_le_bytes([
+            self.signed[4],
+            self.signed[5],
+            self.signed[6],
+            self.signed[7],
+        ])
+        .into()
+    }
+
+    /// Returns true if `timestamp` is within the validity window, inclusive.
+    pub fn is_valid_at(&self, timestamp: Timestamp) -> bool {
+        let time = timestamp.system_time();
+
+        self.valid_not_before().system_time() <= time
+            && time <= self.valid_not_after().system_time()
+    }
+
+    /// Returns the DRIP Entity Tag of the UA, see
+    /// [`DroneEntityTag`](crate::basic_id::DroneEntityTag) to decode it.
+    pub fn ua_det(&self) -> [u8; 16] {
+        self.signed[8..24]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the Host Identity of the UA, its Ed25519 public key.
+    pub fn ua_host_identity(&self) -> [u8; 32] {
+        self.signed[24..56]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the DRIP Entity Tag of the HHIT Domain Authority, see
+    /// [`DroneEntityTag`](crate::basic_id::DroneEntityTag) to decode it.
+    pub fn hda_det(&self) -> [u8; 16] {
+        self.signed[56..72]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the HDA signature.
+    pub fn signature(&self) -> &[u8; 64] {
+        &self.signature
+    }
+
+    /// Returns the bytes covered by the signature, from the valid not before timestamp through the
+    /// HDA DET.
+    pub fn signed_bytes(&self) -> &[u8] {
+        &self.signed
+    }
+}
+
+impl TryFrom<&[u8]> for Link {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() != Self::ENCODED_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        Ok(Self {
+            signed: value[..Self::SIGNED_LENGTH]
+                .try_into()
+                .map_err(|_| Error::Unreachable)
+                .unwrap(),
+            signature: value[Self::SIGNED_LENGTH..]
+                .try_into()
+                .map_err(|_| Error::Unreachable)
+                .unwrap(),
+        })
+    }
+}
+
+impl TrySerialize for Link {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != Self::ENCODED_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer[..Self::SIGNED_LENGTH].clone_from_slice(&self.signed);
+        buffer[Self::SIGNED_LENGTH..].clone_from_slice(&self.signature);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{authentication::drip::Link, system::Timestamp, try_serialize::TrySerialize};
+
+    fn link() -> Link {
+        Link::new(
+            Timestamp::new(10),
+            Timestamp::new(20),
+            [1u8; 16],
+            [2u8; 32],
+            [3u8; 16],
+            [4u8; 64],
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let link = link();
+
+        assert_eq!(link.valid_not_before(), Timestamp::new(10));
+        assert_eq!(link.valid_not_after(), Timestamp::new(20));
+        assert_eq!(link.ua_det(), [1u8; 16]);
+        assert_eq!(link.ua_host_identity(), [2u8; 32]);
+        assert_eq!(link.hda_det(), [3u8; 16]);
+        assert_eq!(link.signature(), &[4u8; 64]);
+        assert_eq!(link.signed_bytes().len(), 72);
+        assert!(link.is_valid_at(Timestamp::new(15)));
+        assert!(!link.is_valid_at(Timestamp::new(21)));
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut encoded = [0u8; Link::ENCODED_LENGTH];
+        link().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[..8], [10, 0, 0, 0, 20, 0, 0, 0]);
+        assert_eq!(encoded[8..24], [1u8; 16]);
+        assert_eq!(encoded[24..56], [2u8; 32]);
+        assert_eq!(encoded[56..72], [3u8; 16]);
+        assert_eq!(encoded[72..], [4u8; 64]);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 135];
+        let mut too_long = [0u8; 137];
+
+        assert!(link().try_serialize(&mut too_short).is_err());
+        assert!(link().try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut encoded = [0u8; Link::ENCODED_LENGTH];
+        link().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(Link::try_from(encoded.as_ref()), Ok(link()));
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let too_short = [0u8; 135];
+        let too_long = [0u8; 137];
+
+        assert!(Link::try_from(too_short.as_ref()).is_err());
+        assert!(Link::try_from(too_long.as_ref()).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::authentication::drip::signed_evidence::SignedEvidence;
+use crate::error::Error;
+use crate::system::Timestamp;
+use crate::try_serialize::TrySerialize;
+
+/// DRIP Manifest
+///
+/// [`SignedEvidence`] whose evidence is a list of 8 byte message hashes, so one signature can cover
+/// more messages than fit in a [`Wrapper`](crate::authentication::drip::Wrapper).
+///
+/// | Offset | Length  | Field                   |
+/// | ------ | ------- | ----------------------- |
+/// | 0      | 8       | Previous manifest hash  |
+/// | 8      | 8       | Current manifest hash   |
+/// | 16     | `8 * n` | Message hashes, 1 to 12 |
+///
+/// The hashes themselves are computed by the caller, this library only carries them.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Manifest(SignedEvidence);
+
+impl Manifest {
+    /// Byte length of each hash.
+    pub const HASH_LENGTH: usize = 8;
+
+    /// Maximum number of message hashes.
+    pub const MAX_MESSAGE_HASHES: usize = 12;
+
+    /// Constructs a new manifest.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `message_hashes` is empty or contains more than [`Manifest::MAX_MESSAGE_HASHES`] hashes.
+    pub fn try_new(
+        valid_not_before: Timestamp,
+        valid_not_after: Timestamp,
+        previous_manifest_hash: [u8; 8],
+        current_manifest_hash: [u8; 8],
+        message_hashes: &[[u8; 8]],
+        ua_det: [u8; 16],
+        signature: [u8; 64],
+    ) -> Result<Self, Error> {
+        if message_hashes.is_empty() || message_hashes.len() > Self::MAX_MESSAGE_HASHES {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let mut evidence = [0u8; SignedEvidence::MAX_EVIDENCE_LENGTH];
+
+        evidence[..8].clone_from_slice(&previous_manifest_hash);
+        evidence[8..16].clone_from_slice(&current_manifest_hash);
+
+        for (hash, buffer) in message_hashes
+            .iter()
+            .zip(evidence[16..].chunks_mut(Self::HASH_LENGTH))
+        {
+            buffer.clone_from_slice(hash);
+        }
+
+        let evidence_length = 16 + message_hashes.len() * Self::HASH_LENGTH;
+
+        Ok(Self(SignedEvidence::try_new(
+            valid_not_before,
+            valid_not_after,
+            &evidence[..evidence_length],
+            ua_det,
+            signature,
+        )?))
+    }
+
+    /// Returns the signed evidence.
+    pub fn signed_evidence(&self) -> &SignedEvidence {
+        &self.0
+    }
+
+    /// Returns a copy with the given signature, for signing after construction.
+    pub fn with_signature(self, signature: [u8; 64]) -> Self {
+        Self(self.0.with_signature(signature))
+    }
+
+    /// Returns the hash of the previous manifest.
+    pub fn previous_manifest_hash(&self) -> [u8; 8] {
+        self.hash_at(0)
+    }
+
+    /// Returns the hash of this manifest.
+    pub fn current_manifest_hash(&self) -> [u8; 8] {
+        self.hash_at(1)
+    }
+
+    /// Returns an iterator over the message hashes.
+    pub fn message_hashes(&self) -> impl Iterator<Item = [u8; 8]> + '_ {
+        (2..self.0.evidence().len() / Self::HASH_LENGTH).map(|index| self.hash_at(index))
+    }
+
+    /// Returns the encoded byte length.
+    pub fn encoding_byte_length(&self) -> usize {
+        self.0.encoding_byte_length()
+    }
+
+    // returns the hash at the given index of the evidence, which is at least two hashes long.
+    fn hash_at(&self, index: usize) -> [u8; 8] {
+        let start = index * Self::HASH_LENGTH;
+
+        self.0.evidence()[start..start + Self::HASH_LENGTH]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+}
+
+impl TryFrom<&[u8]> for Manifest {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let signed_evidence = SignedEvidence::try_from(value)?;
+
+        let evidence_length = signed_evidence.evidence().len();
+
+        if evidence_length < 3 * Self::HASH_LENGTH
+            || evidence_length % Self::HASH_LENGTH != 0
+            || evidence_length > (2 + Self::MAX_MESSAGE_HASHES) * Self::HASH_LENGTH
+        {
+            return Err(Error::InvalidDataLength);
+        }
+
+        Ok(Self(signed_evidence))
+    }
+}
+
+impl TrySerialize for Manifest {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.try_serialize(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::drip::Manifest, error::Error, system::Timestamp,
+        try_serialize::TrySerialize,
+    };
+
+    fn manifest() -> Manifest {
+        Manifest::try_new(
+            Timestamp::new(10),
+            Timestamp::new(20),
+            [1u8; 8],
+            [2u8; 8],
+            &[[3u8; 8], [4u8; 8]],
+            [5u8; 16],
+            [6u8; 64],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_try_new() {
+        let manifest = manifest();
+
+        assert_eq!(manifest.previous_manifest_hash(), [1u8; 8]);
+        assert_eq!(manifest.current_manifest_hash(), [2u8; 8]);
+        assert!(manifest.message_hashes().eq([[3u8; 8], [4u8; 8]]));
+        assert_eq!(manifest.encoding_byte_length(), 88 + 32);
+    }
+
+    #[test]
+    fn test_try_new_fails_invalid_number_of_hashes() {
+        for message_hashes in [&[][..], &[[0u8; 8]; 13][..]] {
+            assert_eq!(
+                Manifest::try_new(
+                    Timestamp::new(10),
+                    Timestamp::new(20),
+                    [1u8; 8],
+                    [2u8; 8],
+                    message_hashes,
+                    [5u8; 16],
+                    [6u8; 64],
+                ),
+                Err(Error::InvalidDataLength)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let mut encoded = [0u8; 120];
+        manifest().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[8..16], [1u8; 8]);
+        assert_eq!(Manifest::try_from(encoded.as_ref()), Ok(manifest()));
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        // evidence of 20 bytes is not a whole number of hashes.
+        let encoded = [0u8; 88 + 20];
+
+        assert_eq!(
+            Manifest::try_from(encoded.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+}
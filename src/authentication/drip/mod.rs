@@ -0,0 +1,221 @@
+//! ## DRIP Authentication Formats
+//!
+//! The IETF Drone Remote ID Protocol (DRIP) defines authentication formats carried in the
+//! authentication data of an
+//! [`AuthenticationType::SpecificAuthenticationMessage`](crate::authentication::AuthenticationType),
+//! as specified in RFC 9575. The reassembled data, see
+//! [`Assembler`](crate::authentication::Assembler), starts with a [`SamType`] byte identifying the
+//! format, followed by the format itself.
+//!
+//! 1. [`Link`] is a broadcast endorsement of the UA by its HHIT Domain Authority.
+//! 2. [`Wrapper`] signs up to four F3411 messages directly.
+//! 3. [`Manifest`] signs hashes of F3411 messages.
+//! 4. [`Frame`] signs a raw transport frame.
+//!
+//! The [`Wrapper`], [`Manifest`], and [`Frame`] formats share the [`SignedEvidence`] structure.
+//! Each format exposes the exact bytes its signature covers, to be checked with a
+//! [`Verifier`](crate::authentication::Verifier).
+mod frame;
+mod link;
+mod manifest;
+mod sam_type;
+mod signed_evidence;
+mod wrapper;
+
+pub use frame::Frame;
+pub use link::Link;
+pub use manifest::Manifest;
+pub use sam_type::SamType;
+pub use signed_evidence::SignedEvidence;
+pub use wrapper::Wrapper;
+
+use crate::error::Error;
+use crate::try_serialize::TrySerialize;
+
+/// DRIP Authentication
+///
+/// The authentication data of a Specific Authentication Message in one of the DRIP formats.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DripAuthentication {
+    /// DRIP Link.
+    Link(Link),
+    /// DRIP Wrapper.
+    Wrapper(Wrapper),
+    /// DRIP Manifest.
+    Manifest(Manifest),
+    /// DRIP Frame.
+    Frame(Frame),
+}
+
+impl DripAuthentication {
+    /// Returns the SAM type of the format.
+    pub fn sam_type(&self) -> SamType {
+        match self {
+            Self::Link(_) => SamType::Link,
+            Self::Wrapper(_) => SamType::Wrapper,
+            Self::Manifest(_) => SamType::Manifest,
+            Self::Frame(_) => SamType::Frame,
+        }
+    }
+
+    /// Returns the encoded byte length, including the SAM type byte.
+    pub fn encoding_byte_length(&self) -> usize {
+        1 + match self {
+            Self::Link(_) => Link::ENCODED_LENGTH,
+            Self::Wrapper(wrapper) => wrapper.encoding_byte_length(),
+            Self::Manifest(manifest) => manifest.encoding_byte_length(),
+            Self::Frame(frame) => frame.encoding_byte_length(),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for DripAuthentication {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let sam_type = SamType::from(*value.first().ok_or(Error::InvalidDataLength)?);
+
+        let format = &value[1..];
+
+        match sam_type {
+            SamType::Link => Ok(Self::Link(format.try_into()?)),
+            SamType::Wrapper => Ok(Self::Wrapper(format.try_into()?)),
+            SamType::Manifest => Ok(Self::Manifest(format.try_into()?)),
+            SamType::Frame => Ok(Self::Frame(format.try_into()?)),
+            SamType::Reserved(_) | SamType::PrivateUse(_) => {
+                Err(Error::UnsupportedAuthenticationType)
+            }
+        }
+    }
+}
+
+impl TrySerialize for DripAuthentication {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != self.encoding_byte_length() {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer[0] = self.sam_type().into();
+
+        match self {
+            Self::Link(link) => link.try_serialize(&mut buffer[1..]),
+            Self::Wrapper(wrapper) => wrapper.try_serialize(&mut buffer[1..]),
+            Self::Manifest(manifest) => manifest.try_serialize(&mut buffer[1..]),
+            Self::Frame(frame) => frame.try_serialize(&mut buffer[1..]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::{
+            Assembler, AuthenticationType,
+            drip::{DripAuthentication, Frame, Link, SamType},
+            paginate,
+        },
+        error::Error,
+        system::Timestamp,
+        try_serialize::TrySerialize,
+    };
+
+    fn link() -> DripAuthentication {
+        DripAuthentication::Link(Link::new(
+            Timestamp::new(10),
+            Timestamp::new(20),
+            [1u8; 16],
+            [2u8; 32],
+            [3u8; 16],
+            [4u8; 64],
+        ))
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut encoded = [0u8; 137];
+        link().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[0], 0x01);
+        assert_eq!(encoded[1..5], [10, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 136];
+        let mut too_long = [0u8; 138];
+
+        assert!(link().try_serialize(&mut too_short).is_err());
+        assert!(link().try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let frame = DripAuthentication::Frame(
+            Frame::try_new(
+                Timestamp::new(10),
+                Timestamp::new(20),
+                1,
+                &[7u8; 31],
+                [5u8; 16],
+                [6u8; 64],
+            )
+            .unwrap(),
+        );
+
+        let mut encoded = [0u8; 121];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(frame.sam_type(), SamType::Frame);
+        assert_eq!(DripAuthentication::try_from(encoded.as_ref()), Ok(frame));
+    }
+
+    #[test]
+    fn test_decode_paginated() {
+        let mut encoded = [0u8; 137];
+        link().try_serialize(&mut encoded).unwrap();
+
+        let pages = paginate(
+            AuthenticationType::SpecificAuthenticationMessage,
+            Timestamp::new(15),
+            &encoded,
+        )
+        .unwrap();
+
+        let mut assembler = Assembler::new();
+
+        for page in pages.pages() {
+            assembler.try_push(page).unwrap();
+        }
+
+        assert_eq!(
+            DripAuthentication::try_from(assembler.try_assemble().unwrap()),
+            Ok(link())
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_unsupported_sam_type() {
+        let mut encoded = [0u8; 137];
+        link().try_serialize(&mut encoded).unwrap();
+        encoded[0] = 0xa0;
+
+        assert_eq!(
+            DripAuthentication::try_from(encoded.as_ref()),
+            Err(Error::UnsupportedAuthenticationType)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        assert_eq!(
+            DripAuthentication::try_from([].as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            DripAuthentication::try_from([0x01; 136].as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::error::Error;
+
+/// Type of Specific Authentication Message (SAM)
+///
+/// The first byte of the authentication data of an
+/// [`AuthenticationType::SpecificAuthenticationMessage`](crate::authentication::AuthenticationType)
+/// identifies its format. Values `0x01` to `0x04` are the DRIP formats, `0x00` and `0x05` to `0x9F`
+/// are reserved, and `0xA0` to `0xFF` are available for private use.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SamType {
+    /// DRIP Link, a broadcast endorsement of the UA by its HHIT Domain Authority.
+    Link,
+    /// DRIP Wrapper, F3411 messages signed by the UA.
+    Wrapper,
+    /// DRIP Manifest, hashes of F3411 messages signed by the UA.
+    Manifest,
+    /// DRIP Frame, a raw transport frame signed by the UA.
+    Frame,
+    /// Reserved, code 0x00 and codes 0x05 to 0x9f.
+    Reserved(u8),
+    /// Available for private use, codes 0xa0 to 0xff.
+    PrivateUse(u8),
+}
+
+impl SamType {
+    /// Special value representing the smallest "reserved" value, above the DRIP formats.
+    pub const RESERVED_THRESHOLD: u8 = 0x05;
+
+    /// Special value representing the smallest "private use" value.
+    pub const PRIVATE_USE_THRESHOLD: u8 = 0xa0;
+}
+
+impl From<u8> for SamType {
+    fn from(value: u8) -> Self {
+        if value >= Self::PRIVATE_USE_THRESHOLD {
+            return Self::PrivateUse(value);
+        }
+
+        if value >= Self::RESERVED_THRESHOLD {
+            return Self::Reserved(value);
+        }
+
+        match value {
+            0x00 => Self::Reserved(value),
+            0x01 => Self::Link,
+            0x02 => Self::Wrapper,
+            0x03 => Self::Manifest,
+            0x04 => Self::Frame,
+            #[allow(clippy::unnecessary_literal_unwrap)]
+            _ => Err(Error::Unreachable).unwrap(),
+        }
+    }
+}
+
+impl From<SamType> for u8 {
+    fn from(value: SamType) -> Self {
+        match value {
+            SamType::Link => 0x01,
+            SamType::Wrapper => 0x02,
+            SamType::Manifest => 0x03,
+            SamType::Frame => 0x04,
+            SamType::Reserved(0x00) => 0x00,
+            SamType::Reserved(n) => n.clamp(
+                SamType::RESERVED_THRESHOLD,
+                SamType::PRIVATE_USE_THRESHOLD - 1,
+            ),
+            SamType::PrivateUse(n) => n.max(SamType::PRIVATE_USE_THRESHOLD),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::authentication::drip::SamType;
+
+    #[test]
+    fn test_encode_decode() {
+        for (value, sam_type) in [
+            (0x01, SamType::Link),
+            (0x02, SamType::Wrapper),
+            (0x03, SamType::Manifest),
+            (0x04, SamType::Frame),
+        ] {
+            assert_eq!(SamType::from(value), sam_type);
+            assert_eq!(u8::from(sam_type), value);
+        }
+    }
+
+    #[test]
+    fn test_decode_reserved() {
+        assert_eq!(SamType::from(0x00), SamType::Reserved(0x00));
+        assert_eq!(SamType::from(0x05), SamType::Reserved(0x05));
+        assert_eq!(SamType::from(0x9f), SamType::Reserved(0x9f));
+    }
+
+    #[test]
+    fn test_decode_private_use() {
+        assert_eq!(SamType::from(0xa0), SamType::PrivateUse(0xa0));
+        assert_eq!(SamType::from(0xff), SamType::PrivateUse(0xff));
+    }
+
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=u8::MAX {
+            assert_eq!(u8::from(SamType::from(code)), code);
+        }
+    }
+
+    #[test]
+    fn test_encode_clamps_invalid_value() {
+        assert_eq!(
+            u8::from(SamType::Reserved(0x02)),
+            SamType::RESERVED_THRESHOLD
+        );
+        assert_eq!(u8::from(SamType::Reserved(0xff)), 0x9f);
+        assert_eq!(
+            u8::from(SamType::PrivateUse(0x01)),
+            SamType::PRIVATE_USE_THRESHOLD
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use crate::error::Error;
+use crate::system::Timestamp;
+use crate::try_serialize::TrySerialize;
+
+/// UA Signed Evidence
+///
+/// The structure shared by the DRIP [`Wrapper`](crate::authentication::drip::Wrapper),
+/// [`Manifest`](crate::authentication::drip::Manifest), and
+/// [`Frame`](crate::authentication::drip::Frame) formats: evidence bound to a validity window and
+/// signed by the UA with the key of its DRIP Entity Tag (DET).
+///
+/// | Offset       | Length   | Field                     |
+/// | ------------ | -------- | ------------------------- |
+/// | 0            | 4        | Valid not before          |
+/// | 4            | 4        | Valid not after           |
+/// | 8            | `n`      | Evidence, up to 112 bytes |
+/// | `8 + n`      | 16       | UA DET                    |
+/// | `24 + n`     | 64       | UA Ed25519 signature      |
+///
+/// The signature covers every field before it, see [`SignedEvidence::signed_bytes`]. Timestamps
+/// use the system [`Timestamp`] encoding.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SignedEvidence {
+    // valid not before, valid not after, evidence, and UA DET; the bytes covered by the signature.
+    signed: [u8; SignedEvidence::MAX_SIGNED_LENGTH],
+    signed_length: usize,
+    signature: [u8; SignedEvidence::SIGNATURE_LENGTH],
+}
+
+impl SignedEvidence {
+    /// Maximum byte length of the evidence.
+    pub const MAX_EVIDENCE_LENGTH: usize = 112;
+
+    /// Byte length of a DRIP Entity Tag.
+    pub const DET_LENGTH: usize = 16;
+
+    /// Byte length of an Ed25519 signature.
+    pub const SIGNATURE_LENGTH: usize = 64;
+
+    /// Byte length of the structure without evidence.
+    pub const MIN_LENGTH: usize = 8 + Self::DET_LENGTH + Self::SIGNATURE_LENGTH;
+
+    /// Byte length of the structure with the maximum evidence.
+    pub const MAX_LENGTH: usize = Self::MIN_LENGTH + Self::MAX_EVIDENCE_LENGTH;
+
+    // maximum byte length of the bytes covered by the signature.
+    const MAX_SIGNED_LENGTH: usize = Self::MAX_LENGTH - Self::SIGNATURE_LENGTH;
+
+    /// Constructs new signed evidence.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `evidence` is longer than [`SignedEvidence::MAX_EVIDENCE_LENGTH`].
+    pub fn try_new(
+        valid_not_before: Timestamp,
+        valid_not_after: Timestamp,
+        evidence: &[u8],
+        ua_det: [u8; 16],
+        signature: [u8; 64],
+    ) -> Result<Self, Error> {
+        if evidence.len() > Self::MAX_EVIDENCE_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let det_start = 8 + evidence.len();
+
+        let signed_length = det_start + Self::DET_LENGTH;
+
+        let mut signed = [0u8; Self::MAX_SIGNED_LENGTH];
+
+        signed[0..4].clone_from_slice(&u32::from(valid_not_before).to_le_bytes());
+        signed[4..8].clone_from_slice(&u32::from(valid_not_after).to_le_bytes());
+        signed[8..det_start].clone_from_slice(evidence);
+        signed[det_start..signed_length].clone_from_slice(&ua_det);
+
+        Ok(Self {
+            signed,
+            signed_length,
+            signature,
+        })
+    }
+
+    /// Returns a copy with the given signature, for signing after construction.
+    pub fn with_signature(mut self, signature: [u8; 64]) -> Self {
+        self.signature = signature;
+
+        self
+    }
+
+    /// Returns the time before which the evidence is not valid.
+    pub fn valid_not_before(&self) -> Timestamp {
+        u32::from_le_bytes([
+            self.signed[0],
+            self.signed[1],
+            self.signed[2],
+            self.signed[3],
+        ])
+        .into()
+    }
+
+    /// Returns the time after which the evidence is not valid.
+    pub fn valid_not_after(&self) -> Timestamp {
+        u32::from_le_bytes([
+            self.signed[4],
+            self.signed[5],
+            self.signed[6],
+            self.signed[7],
+        ])
+        .into()
+    }
+
+    /// Returns true if `timestamp` is within the validity window, inclusive.
+    pub fn is_valid_at(&self, timestamp: Timestamp) -> bool {
+        let time = timestamp.system_time();
+
+        self.valid_not_before().system_time() <= time
+            && time <= self.valid_not_after().system_time()
+    }
+
+    /// Returns the evidence.
+    pub fn evidence(&self) -> &[u8] {
+        &self.signed[8..self.signed_length - Self::DET_LENGTH]
+    }
+
+    /// Returns the DRIP Entity Tag of the UA, see
+    /// [`DroneEntityTag`](crate::basic_id::DroneEntityTag) to decode it.
+    pub fn ua_det(&self) -> [u8; 16] {
+        self.signed[self.signed_length - Self::DET_LENGTH..self.signed_length]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the UA signature.
+    pub fn signature(&self) -> &[u8; 64] {
+        &self.signature
+    }
+
+    /// Returns the bytes covered by the signature, from the valid not before timestamp through the
+    /// UA DET.
+    pub fn signed_bytes(&self) -> &[u8] {
+        &self.signed[..self.signed_length]
+    }
+
+    /// Returns the encoded byte length.
+    pub fn encoding_byte_length(&self) -> usize {
+        self.signed_length + Self::SIGNATURE_LENGTH
+    }
+}
+
+impl TryFrom<&[u8]> for SignedEvidence {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < Self::MIN_LENGTH || value.len() > Self::MAX_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let signed_length = value.len() - Self::SIGNATURE_LENGTH;
+
+        let mut signed = [0u8; Self::MAX_SIGNED_LENGTH];
+        signed[..signed_length].clone_from_slice(&value[..signed_length]);
+
+        Ok(Self {
+            signed,
+            signed_length,
+            signature: value[signed_length..]
+                .try_into()
+                .map_err(|_| Error::Unreachable)
+                .unwrap(),
+        })
+    }
+}
+
+impl TrySerialize for SignedEvidence {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != self.encoding_byte_length() {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer[..self.signed_length].clone_from_slice(self.signed_bytes());
+        buffer[self.signed_length..].clone_from_slice(&self.signature);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::drip::SignedEvidence, error::Error, system::Timestamp,
+        try_serialize::TrySerialize,
+    };
+
+    fn signed_evidence() -> SignedEvidence {
+        SignedEvidence::try_new(
+            Timestamp::new(10),
+            Timestamp::new(20),
+            &[1, 2, 3],
+            [4u8; 16],
+            [5u8; 64],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_try_new() {
+        let signed_evidence = signed_evidence();
+
+        assert_eq!(signed_evidence.valid_not_before(), Timestamp::new(10));
+        assert_eq!(signed_evidence.valid_not_after(), Timestamp::new(20));
+        assert_eq!(signed_evidence.evidence(), &[1, 2, 3]);
+        assert_eq!(signed_evidence.ua_det(), [4u8; 16]);
+        assert_eq!(signed_evidence.signature(), &[5u8; 64]);
+        assert_eq!(signed_evidence.signed_bytes().len(), 27);
+        assert_eq!(signed_evidence.encoding_byte_length(), 91);
+    }
+
+    #[test]
+    fn test_try_new_fails_invalid_length() {
+        assert_eq!(
+            SignedEvidence::try_new(
+                Timestamp::new(10),
+                Timestamp::new(20),
+                &[0u8; 113],
+                [4u8; 16],
+                [5u8; 64],
+            ),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_with_signature() {
+        let signed_evidence = signed_evidence().with_signature([6u8; 64]);
+
+        assert_eq!(signed_evidence.signature(), &[6u8; 64]);
+        assert_eq!(signed_evidence.evidence(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_is_valid_at() {
+        let signed_evidence = signed_evidence();
+
+        assert!(!signed_evidence.is_valid_at(Timestamp::new(9)));
+        assert!(signed_evidence.is_valid_at(Timestamp::new(10)));
+        assert!(signed_evidence.is_valid_at(Timestamp::new(20)));
+        assert!(!signed_evidence.is_valid_at(Timestamp::new(21)));
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut encoded = [0u8; 91];
+        signed_evidence().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[..8], [10, 0, 0, 0, 20, 0, 0, 0]);
+        assert_eq!(encoded[8..11], [1, 2, 3]);
+        assert_eq!(encoded[11..27], [4u8; 16]);
+        assert_eq!(encoded[27..], [5u8; 64]);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 90];
+        let mut too_long = [0u8; 92];
+
+        assert!(signed_evidence().try_serialize(&mut too_short).is_err());
+        assert!(signed_evidence().try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut encoded = [0u8; 91];
+        signed_evidence().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            SignedEvidence::try_from(encoded.as_ref()),
+            Ok(signed_evidence())
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let too_short = [0u8; 87];
+        let too_long = [0u8; 201];
+
+        assert!(SignedEvidence::try_from(too_short.as_ref()).is_err());
+        assert!(SignedEvidence::try_from(too_long.as_ref()).is_err());
+    }
+}
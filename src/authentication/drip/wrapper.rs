@@ -0,0 +1,209 @@
+use crate::authentication::drip::signed_evidence::SignedEvidence;
+use crate::error::Error;
+use crate::messages::Message;
+use crate::pack::Pack;
+use crate::system::Timestamp;
+use crate::try_serialize::TrySerialize;
+
+/// DRIP Wrapper
+///
+/// [`SignedEvidence`] whose evidence is one to four encoded 25 byte F3411 [`Message`]s, which the
+/// UA signs directly. Pack messages cannot be wrapped.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Wrapper(SignedEvidence);
+
+impl Wrapper {
+    /// Maximum number of wrapped messages.
+    pub const MAX_MESSAGES: usize = 4;
+
+    /// Constructs a new wrapper around the given messages.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `messages` is empty or contains more than [`Wrapper::MAX_MESSAGES`] messages.
+    /// - any message is a [`Pack`].
+    pub fn try_new(
+        valid_not_before: Timestamp,
+        valid_not_after: Timestamp,
+        messages: &[Message],
+        ua_det: [u8; 16],
+        signature: [u8; 64],
+    ) -> Result<Self, Error> {
+        if messages.is_empty() || messages.len() > Self::MAX_MESSAGES {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let mut evidence = [0u8; Self::MAX_MESSAGES * Pack::MESSAGES_LENGTH];
+
+        for (message, buffer) in messages
+            .iter()
+            .zip(evidence.chunks_mut(Pack::MESSAGES_LENGTH))
+        {
+            if message.is_pack() {
+                return Err(Error::UnsupportedMessageType);
+            }
+
+            message.try_serialize(buffer)?;
+        }
+
+        let evidence_length = messages.len() * Pack::MESSAGES_LENGTH;
+
+        Ok(Self(SignedEvidence::try_new(
+            valid_not_before,
+            valid_not_after,
+            &evidence[..evidence_length],
+            ua_det,
+            signature,
+        )?))
+    }
+
+    /// Returns the signed evidence.
+    pub fn signed_evidence(&self) -> &SignedEvidence {
+        &self.0
+    }
+
+    /// Returns a copy with the given signature, for signing after construction.
+    pub fn with_signature(self, signature: [u8; 64]) -> Self {
+        Self(self.0.with_signature(signature))
+    }
+
+    /// Returns the number of wrapped messages.
+    pub fn number_of_messages(&self) -> usize {
+        self.0.evidence().len() / Pack::MESSAGES_LENGTH
+    }
+
+    /// Returns an iterator decoding the wrapped messages.
+    pub fn messages(&self) -> impl Iterator<Item = Result<Message, Error>> + '_ {
+        self.0
+            .evidence()
+            .chunks(Pack::MESSAGES_LENGTH)
+            .map(Message::try_from)
+    }
+
+    /// Returns the encoded byte length.
+    pub fn encoding_byte_length(&self) -> usize {
+        self.0.encoding_byte_length()
+    }
+}
+
+impl TryFrom<&[u8]> for Wrapper {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let signed_evidence = SignedEvidence::try_from(value)?;
+
+        let evidence_length = signed_evidence.evidence().len();
+
+        if evidence_length == 0
+            || evidence_length % Pack::MESSAGES_LENGTH != 0
+            || evidence_length > Self::MAX_MESSAGES * Pack::MESSAGES_LENGTH
+        {
+            return Err(Error::InvalidDataLength);
+        }
+
+        Ok(Self(signed_evidence))
+    }
+}
+
+impl TrySerialize for Wrapper {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.try_serialize(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::drip::Wrapper,
+        basic_id::{BasicID, UASID, UAType},
+        error::Error,
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        system::Timestamp,
+        try_serialize::TrySerialize,
+    };
+
+    fn messages() -> [Message; 2] {
+        [
+            Message::from(BasicID::new(UAType::Helicopter, UASID::None)),
+            Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20])),
+        ]
+    }
+
+    fn wrapper() -> Wrapper {
+        Wrapper::try_new(
+            Timestamp::new(10),
+            Timestamp::new(20),
+            &messages(),
+            [4u8; 16],
+            [5u8; 64],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_try_new() {
+        let wrapper = wrapper();
+
+        assert_eq!(wrapper.number_of_messages(), 2);
+        assert!(wrapper.messages().map(Result::unwrap).eq(messages()));
+        assert_eq!(wrapper.signed_evidence().ua_det(), [4u8; 16]);
+        assert_eq!(wrapper.encoding_byte_length(), 88 + 50);
+    }
+
+    #[test]
+    fn test_try_new_fails_invalid_number_of_messages() {
+        let message = messages()[0];
+
+        for messages in [&[][..], &[message; 5][..]] {
+            assert_eq!(
+                Wrapper::try_new(
+                    Timestamp::new(10),
+                    Timestamp::new(20),
+                    messages,
+                    [4u8; 16],
+                    [5u8; 64],
+                ),
+                Err(Error::InvalidDataLength)
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_new_fails_pack() {
+        let pack = Message::from(Pack::try_from(messages()).unwrap());
+
+        assert_eq!(
+            Wrapper::try_new(
+                Timestamp::new(10),
+                Timestamp::new(20),
+                &[pack],
+                [4u8; 16],
+                [5u8; 64],
+            ),
+            Err(Error::UnsupportedMessageType)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let mut encoded = [0u8; 138];
+        wrapper().try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(Wrapper::try_from(encoded.as_ref()), Ok(wrapper()));
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        // evidence of 26 bytes is not a whole number of messages.
+        let encoded = [0u8; 88 + 26];
+
+        assert_eq!(
+            Wrapper::try_from(encoded.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use ed25519_dalek::Signature;
+use ed25519_dalek::VerifyingKey;
+
+use crate::authentication::authentication_type::AuthenticationType;
+use crate::authentication::verifier::Verifier;
+use crate::error::Error;
+
+/// Ed25519 Signature Verifier
+///
+/// Verifies 64 byte Ed25519 signatures for [`AuthenticationType::UASIDSignature`],
+/// [`AuthenticationType::OperatorIDSignature`], and [`AuthenticationType::MessageSetSignature`].
+///
+/// Only available with the `ed25519` feature.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ed25519Verifier {
+    verifying_key: VerifyingKey,
+}
+
+impl Ed25519Verifier {
+    /// Byte length of an Ed25519 signature.
+    pub const SIGNATURE_LENGTH: usize = 64;
+
+    /// Constructs a new verifier from a 32 byte Ed25519 public key.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `public_key` is not a valid curve point.
+    pub fn try_new(public_key: &[u8; 32]) -> Result<Self, Error> {
+        let verifying_key =
+            VerifyingKey::from_bytes(public_key).map_err(|_| Error::InvalidPublicKey)?;
+
+        Ok(Self { verifying_key })
+    }
+
+    /// Returns the 32 byte Ed25519 public key.
+    pub fn public_key(&self) -> &[u8; 32] {
+        self.verifying_key.as_bytes()
+    }
+}
+
+impl Verifier for Ed25519Verifier {
+    type Error = Error;
+
+    fn verify(
+        &self,
+        authentication_type: AuthenticationType,
+        signed_bytes: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Self::Error> {
+        match authentication_type {
+            AuthenticationType::UASIDSignature
+            | AuthenticationType::OperatorIDSignature
+            | AuthenticationType::MessageSetSignature => (),
+            _ => return Err(Error::UnsupportedAuthenticationType),
+        }
+
+        let signature = Signature::from_slice(signature).map_err(|_| Error::InvalidDataLength)?;
+
+        self.verifying_key
+            .verify_strict(signed_bytes, &signature)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::Signer;
+    use ed25519_dalek::SigningKey;
+
+    use crate::{
+        authentication::{
+            Assembler, AuthenticationType, Ed25519Verifier, SignedBytes, Verifier, paginate,
+        },
+        basic_id::{BasicID, UASID, UAType},
+        error::Error,
+        messages::Message,
+        system::Timestamp,
+    };
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    fn signed_bytes() -> SignedBytes {
+        let basic_id = Message::from(BasicID::new(UAType::Helicopter, UASID::None));
+
+        SignedBytes::try_new(
+            AuthenticationType::UASIDSignature,
+            Timestamp::new(1),
+            &[basic_id],
+        )
+        .unwrap()
+    }
+
+    fn verifier() -> Ed25519Verifier {
+        Ed25519Verifier::try_new(signing_key().verifying_key().as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_verify() {
+        let signature = signing_key().sign(signed_bytes().as_bytes()).to_bytes();
+
+        assert_eq!(
+            verifier().verify(
+                AuthenticationType::UASIDSignature,
+                signed_bytes().as_bytes(),
+                &signature
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_paginated() {
+        let signature = signing_key().sign(signed_bytes().as_bytes()).to_bytes();
+
+        let pages = paginate(
+            AuthenticationType::UASIDSignature,
+            Timestamp::new(1),
+            &signature,
+        )
+        .unwrap();
+
+        let mut assembler = Assembler::new();
+
+        for page in pages.pages() {
+            assembler.try_push(page).unwrap();
+        }
+
+        assert_eq!(
+            verifier().verify(
+                assembler.authentication_type().unwrap(),
+                signed_bytes().as_bytes(),
+                assembler.try_assemble().unwrap()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_fails_invalid_signature() {
+        let mut signature = signing_key().sign(signed_bytes().as_bytes()).to_bytes();
+        signature[0] ^= 0xff;
+
+        assert_eq!(
+            verifier().verify(
+                AuthenticationType::UASIDSignature,
+                signed_bytes().as_bytes(),
+                &signature
+            ),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_verify_fails_invalid_length() {
+        let signature = signing_key().sign(signed_bytes().as_bytes()).to_bytes();
+
+        assert_eq!(
+            verifier().verify(
+                AuthenticationType::UASIDSignature,
+                signed_bytes().as_bytes(),
+                &signature[..63]
+            ),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_verify_fails_unsupported_type() {
+        let signature = signing_key().sign(signed_bytes().as_bytes()).to_bytes();
+
+        assert_eq!(
+            verifier().verify(
+                AuthenticationType::SpecificAuthenticationMessage,
+                signed_bytes().as_bytes(),
+                &signature
+            ),
+            Err(Error::UnsupportedAuthenticationType)
+        );
+    }
+}
@@ -12,13 +12,23 @@ use crate::try_serialize::TrySerialize;
 /// The maximum [`Initial::last_page_index`] value is 15 and the maximum [`Initial::total_length`]
 /// value is `255`.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Initial {
     authentication_type: AuthenticationType,
     // max: 15
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_support::deserialize_page_index")
+    )]
     last_page_index: usize,
     // max 255
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_support::deserialize_byte_length")
+    )]
     total_length: usize,
     timestamp: Timestamp,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::hex"))]
     data: [u8; 17],
 }
 
@@ -140,8 +150,8 @@ mod tests {
 
         let initial = Initial::try_new(
             authentication_type,
-            last_page_index as usize,
-            total_length as usize,
+            last_page_index,
+            total_length,
             timestamp,
             data,
         )
@@ -11,13 +11,35 @@
 //! messages.
 //!
 //! Each authentication message comes with a page number, which determines the format.
+//!
+//! Receivers reassemble the pages of one set with an [`Assembler`], while transmitters split their
+//! authentication data into pages with [`paginate`].
+//!
+//! Reassembled signatures are checked by a [`Verifier`] over the [`SignedBytes`] defined for each
+//! signature type. The `ed25519` feature provides an `Ed25519Verifier`.
+//!
+//! The [`drip`] module parses and builds the IETF DRIP formats carried by
+//! [`AuthenticationType::SpecificAuthenticationMessage`].
+mod assembler;
 mod authentication_type;
+pub mod drip;
+#[cfg(feature = "ed25519")]
+mod ed25519;
 mod initial;
+mod paginate;
 mod subsequent;
+mod verifier;
 
+pub use assembler::Assembler;
 pub use authentication_type::AuthenticationType;
+#[cfg(feature = "ed25519")]
+pub use ed25519::Ed25519Verifier;
 pub use initial::Initial;
+pub use paginate::Pages;
+pub use paginate::paginate;
 pub use subsequent::Subsequent;
+pub use verifier::SignedBytes;
+pub use verifier::Verifier;
 
 use crate::error::Error;
 use crate::try_serialize::TrySerialize;
@@ -33,6 +55,7 @@ use crate::try_serialize::TrySerialize;
 /// authentication data. In practice, the maximum total length specified in the initial message is
 /// limited to 255 (due to a bit-size constraint).
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Authentication {
     /// The initial authentication message.
     Initial(Initial),
@@ -40,6 +63,11 @@ pub enum Authentication {
     Subsequent(Subsequent),
 }
 
+impl Authentication {
+    /// Message code for authentication messages is `0x02`.
+    pub const AUTHENTICATION_MESSAGE_CODE: u8 = 0x02;
+}
+
 impl TryFrom<&[u8]> for Authentication {
     type Error = Error;
 
@@ -0,0 +1,177 @@
+use crate::authentication::Assembler;
+use crate::authentication::Authentication;
+use crate::authentication::authentication_type::AuthenticationType;
+use crate::authentication::initial::Initial;
+use crate::authentication::subsequent::Subsequent;
+use crate::error::Error;
+use crate::system::Timestamp;
+
+/// Paginated Authentication Data
+///
+/// Contains the [`Initial`] page followed by the [`Subsequent`] pages produced by [`paginate`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Pages {
+    pages: [Authentication; Assembler::MAX_PAGES],
+    number_of_pages: usize,
+}
+
+impl Pages {
+    /// Returns the pages, in page number order.
+    pub fn pages(&self) -> &[Authentication] {
+        &self.pages[..self.number_of_pages]
+    }
+}
+
+/// Splits authentication data into an [`Initial`] page and as many [`Subsequent`] pages as needed.
+///
+/// Every page carries `authentication_type`, and the [`Initial`] page carries `timestamp` and the
+/// total length of `data`. Unused bytes of the last page are zero.
+///
+/// Up to 16 pages are produced, protocol versions before
+/// [`ProtocolVersion::Version2`](crate::messages::ProtocolVersion::Version2) only carry 5, see
+/// [`ProtocolVersion::max_last_page_index`](crate::messages::ProtocolVersion::max_last_page_index).
+///
+/// Returns an error if:
+///
+/// - `data` is longer than `255` bytes, the maximum [`Initial::total_length`].
+pub fn paginate(
+    authentication_type: AuthenticationType,
+    timestamp: Timestamp,
+    data: &[u8],
+) -> Result<Pages, Error> {
+    if data.len() > 255 {
+        return Err(Error::InvalidDataLength);
+    }
+
+    let initial_length = data.len().min(Assembler::INITIAL_DATA_LENGTH);
+
+    let remaining = &data[initial_length..];
+
+    let last_page_index = remaining.len().div_ceil(Assembler::SUBSEQUENT_DATA_LENGTH);
+
+    let mut initial_data = [0u8; Assembler::INITIAL_DATA_LENGTH];
+    initial_data[..initial_length].clone_from_slice(&data[..initial_length]);
+
+    let initial = Authentication::Initial(Initial::try_new(
+        authentication_type,
+        last_page_index,
+        data.len(),
+        timestamp,
+        initial_data,
+    )?);
+
+    let mut pages = [initial; Assembler::MAX_PAGES];
+
+    for (index, chunk) in remaining
+        .chunks(Assembler::SUBSEQUENT_DATA_LENGTH)
+        .enumerate()
+    {
+        let mut subsequent_data = [0u8; Assembler::SUBSEQUENT_DATA_LENGTH];
+        subsequent_data[..chunk.len()].clone_from_slice(chunk);
+
+        pages[index + 1] = Authentication::Subsequent(Subsequent::try_new(
+            authentication_type,
+            index + 1,
+            subsequent_data,
+        )?);
+    }
+
+    Ok(Pages {
+        pages,
+        number_of_pages: last_page_index + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::{Assembler, Authentication, AuthenticationType, paginate},
+        system::Timestamp,
+    };
+
+    #[test]
+    fn test_paginate_single_page() {
+        let pages = paginate(
+            AuthenticationType::UASIDSignature,
+            Timestamp::new(1),
+            &[3u8; 17],
+        )
+        .unwrap();
+
+        assert_eq!(pages.pages().len(), 1);
+
+        let Authentication::Initial(initial) = pages.pages()[0] else {
+            panic!("first page is not initial");
+        };
+
+        assert_eq!(initial.last_page_index(), 0);
+        assert_eq!(initial.total_length(), 17);
+        assert_eq!(initial.timestamp(), Timestamp::new(1));
+        assert_eq!(initial.data(), &[3u8; 17]);
+    }
+
+    #[test]
+    fn test_paginate_empty() {
+        let pages = paginate(AuthenticationType::None, Timestamp::new(1), &[]).unwrap();
+
+        assert_eq!(pages.pages().len(), 1);
+    }
+
+    #[test]
+    fn test_paginate_pads_last_page() {
+        let pages = paginate(
+            AuthenticationType::UASIDSignature,
+            Timestamp::new(1),
+            &[3u8; 18],
+        )
+        .unwrap();
+
+        assert_eq!(pages.pages().len(), 2);
+
+        let Authentication::Subsequent(subsequent) = pages.pages()[1] else {
+            panic!("second page is not subsequent");
+        };
+
+        assert_eq!(subsequent.page_number(), 1);
+        assert_eq!(subsequent.data()[0], 3);
+        assert_eq!(subsequent.data()[1..], [0u8; 22]);
+    }
+
+    #[test]
+    fn test_paginate_assemble_round_trip() {
+        let mut data = [0u8; 255];
+
+        for (index, byte) in data.iter_mut().enumerate() {
+            *byte = index as u8;
+        }
+
+        for length in [0, 1, 17, 18, 40, 41, 64, 255] {
+            let pages = paginate(
+                AuthenticationType::MessageSetSignature,
+                Timestamp::new(1),
+                &data[..length],
+            )
+            .unwrap();
+
+            let mut assembler = Assembler::new();
+
+            for page in pages.pages().iter().rev() {
+                assembler.try_push(page).unwrap();
+            }
+
+            assert_eq!(assembler.try_assemble(), Ok(&data[..length]));
+        }
+    }
+
+    #[test]
+    fn test_paginate_fails_invalid_length() {
+        assert!(
+            paginate(
+                AuthenticationType::UASIDSignature,
+                Timestamp::new(1),
+                &[0u8; 256]
+            )
+            .is_err()
+        );
+    }
+}
@@ -7,9 +7,15 @@ use crate::try_serialize::TrySerialize;
 /// The subsequent authentication message(s) contain an authentication type, page number, and
 /// respective authentication data.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Subsequent {
     authentication_type: AuthenticationType,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_support::deserialize_page_index")
+    )]
     page_number: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::hex"))]
     data: [u8; 23],
 }
 
@@ -0,0 +1,264 @@
+use crate::authentication::authentication_type::AuthenticationType;
+use crate::error::Error;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::pack::Pack;
+use crate::system::Timestamp;
+use crate::try_serialize::TrySerialize;
+
+/// Signature Verifier
+///
+/// Verifies a signature over the [`SignedBytes`] of an authentication set. The signature is the
+/// authentication data reassembled by an [`Assembler`](crate::authentication::Assembler).
+///
+/// Implementations decide which authentication types they support, returning an error for the
+/// others.
+pub trait Verifier {
+    /// Error returned when verification fails.
+    type Error;
+
+    /// Verifies `signature` over `signed_bytes` for the given authentication type.
+    fn verify(
+        &self,
+        authentication_type: AuthenticationType,
+        signed_bytes: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Self::Error>;
+}
+
+/// Signed Bytes
+///
+/// The exact bytes a transmitter signs and a receiver verifies for an authentication type, so both
+/// sides agree without further coordination. Each is the concatenation of encoded 25 byte
+/// [`Message`]s, including the message header byte, followed by the 4 byte little endian system
+/// [`Timestamp`] of the [`Initial`](crate::authentication::Initial) page, which binds the signature
+/// to the time of signing.
+///
+/// | Authentication Type                         | Signed Messages                              |
+/// | ------------------------------------------- | -------------------------------------------- |
+/// | [`AuthenticationType::UASIDSignature`]      | The first [`BasicID`](crate::basic_id)       |
+/// | [`AuthenticationType::OperatorIDSignature`] | The first [`OperatorID`](crate::operator_id) |
+/// | [`AuthenticationType::MessageSetSignature`] | Every non-authentication message, in order   |
+///
+/// [`Pack`] messages are flattened into the messages they contain.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SignedBytes {
+    bytes: [u8; SignedBytes::MAX_LENGTH],
+    length: usize,
+}
+
+impl SignedBytes {
+    /// Maximum byte length, a full message set of nine messages followed by the timestamp.
+    pub const MAX_LENGTH: usize = Pack::MAX_MESSAGES as usize * Pack::MESSAGES_LENGTH + 4;
+
+    /// Constructs the signed bytes of an authentication type from the given messages.
+    ///
+    /// Returns an error if:
+    ///
+    /// - the authentication type has no defined signed bytes.
+    /// - the message to be signed is not in `messages`.
+    /// - a message set contains more than nine messages.
+    /// - a pack in `messages` contains a message which fails to be decoded.
+    pub fn try_new(
+        authentication_type: AuthenticationType,
+        timestamp: Timestamp,
+        messages: &[Message],
+    ) -> Result<Self, Error> {
+        let mut signed_bytes = Self {
+            bytes: [0u8; Self::MAX_LENGTH],
+            length: 0,
+        };
+
+        let mut number_of_messages = 0;
+
+        for message in messages {
+            if let MessageType::Pack(pack) = message.message_type() {
+                for packed_message in pack {
+                    let packed_message = packed_message?;
+
+                    if signed_bytes.try_push(authentication_type, &packed_message)? {
+                        number_of_messages += 1;
+                    }
+                }
+            } else if signed_bytes.try_push(authentication_type, message)? {
+                number_of_messages += 1;
+            }
+        }
+
+        if number_of_messages == 0 {
+            return Err(Error::MissingMessage);
+        }
+
+        let start = signed_bytes.length;
+
+        signed_bytes.bytes[start..start + 4].clone_from_slice(&u32::from(timestamp).to_le_bytes());
+        signed_bytes.length += 4;
+
+        Ok(signed_bytes)
+    }
+
+    /// Returns the signed bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length]
+    }
+
+    // appends the message if the authentication type signs it, returning whether it was appended.
+    fn try_push(
+        &mut self,
+        authentication_type: AuthenticationType,
+        message: &Message,
+    ) -> Result<bool, Error> {
+        // the single message signatures only sign the first match.
+        let is_signed = match authentication_type {
+            AuthenticationType::UASIDSignature => {
+                self.length == 0 && matches!(message.message_type(), MessageType::BasicID(_))
+            }
+            AuthenticationType::OperatorIDSignature => {
+                self.length == 0 && matches!(message.message_type(), MessageType::OperatorID(_))
+            }
+            AuthenticationType::MessageSetSignature => !matches!(
+                message.message_type(),
+                MessageType::Authentication(_) | MessageType::Pack(_)
+            ),
+            _ => return Err(Error::UnsupportedAuthenticationType),
+        };
+
+        if !is_signed {
+            return Ok(false);
+        }
+
+        // the timestamp is appended last, so it must still fit after this message.
+        let end = self.length + Pack::MESSAGES_LENGTH;
+
+        if end + 4 > Self::MAX_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        message.try_serialize(&mut self.bytes[self.length..end])?;
+
+        self.length = end;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::{Authentication, AuthenticationType, Initial, SignedBytes},
+        basic_id::{BasicID, UASID, UAType},
+        error::Error,
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        system::Timestamp,
+        try_serialize::TrySerialize,
+    };
+
+    fn basic_id() -> Message {
+        Message::from(BasicID::new(UAType::Helicopter, UASID::None))
+    }
+
+    fn operator_id() -> Message {
+        Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]))
+    }
+
+    fn authentication() -> Message {
+        Message::from(Authentication::Initial(
+            Initial::try_new(
+                AuthenticationType::MessageSetSignature,
+                0,
+                17,
+                Timestamp::new(1),
+                [0u8; 17],
+            )
+            .unwrap(),
+        ))
+    }
+
+    fn encode(message: Message) -> [u8; 25] {
+        let mut encoded = [0u8; 25];
+        message.try_serialize(&mut encoded).unwrap();
+
+        encoded
+    }
+
+    #[test]
+    fn test_uas_id_signature() {
+        let signed_bytes = SignedBytes::try_new(
+            AuthenticationType::UASIDSignature,
+            Timestamp::new(0x0403_0201),
+            &[operator_id(), basic_id(), basic_id()],
+        )
+        .unwrap();
+
+        assert_eq!(signed_bytes.as_bytes().len(), 29);
+        assert_eq!(signed_bytes.as_bytes()[..25], encode(basic_id()));
+        assert_eq!(signed_bytes.as_bytes()[25..], [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_operator_id_signature() {
+        let signed_bytes = SignedBytes::try_new(
+            AuthenticationType::OperatorIDSignature,
+            Timestamp::new(1),
+            &[basic_id(), operator_id()],
+        )
+        .unwrap();
+
+        assert_eq!(signed_bytes.as_bytes()[..25], encode(operator_id()));
+        assert_eq!(signed_bytes.as_bytes().len(), 29);
+    }
+
+    #[test]
+    fn test_message_set_signature() {
+        let pack = Message::from(Pack::try_from([basic_id(), authentication()]).unwrap());
+
+        let signed_bytes = SignedBytes::try_new(
+            AuthenticationType::MessageSetSignature,
+            Timestamp::new(1),
+            &[pack, operator_id(), authentication()],
+        )
+        .unwrap();
+
+        assert_eq!(signed_bytes.as_bytes().len(), 54);
+        assert_eq!(signed_bytes.as_bytes()[..25], encode(basic_id()));
+        assert_eq!(signed_bytes.as_bytes()[25..50], encode(operator_id()));
+    }
+
+    #[test]
+    fn test_signed_bytes_fails_missing_message() {
+        assert_eq!(
+            SignedBytes::try_new(
+                AuthenticationType::UASIDSignature,
+                Timestamp::new(1),
+                &[operator_id()]
+            ),
+            Err(Error::MissingMessage)
+        );
+    }
+
+    #[test]
+    fn test_signed_bytes_fails_unsupported_type() {
+        assert_eq!(
+            SignedBytes::try_new(
+                AuthenticationType::NetworkRemoteIDAuthentication,
+                Timestamp::new(1),
+                &[basic_id()]
+            ),
+            Err(Error::UnsupportedAuthenticationType)
+        );
+    }
+
+    #[test]
+    fn test_signed_bytes_fails_too_many_messages() {
+        assert_eq!(
+            SignedBytes::try_new(
+                AuthenticationType::MessageSetSignature,
+                Timestamp::new(1),
+                &[basic_id(); 10]
+            ),
+            Err(Error::InvalidDataLength)
+        );
+    }
+}
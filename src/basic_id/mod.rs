@@ -9,11 +9,15 @@
 //! 1. [`SerialNumber`] contains the manufacturer's code and serial number (ANSI/CTA-2063-A).
 //! 2. [`RegistrationID`] contains the Civil Aviation Authority's registration number.
 //! 3. [`UTMAssignedUUID`] contains a session-level identifier for UAS Traffic Management systems.
-//! 4. [`SessionID`] contains a [`SessionIDType`] and its relevant identifier information.
+//! 4. [`SessionID`] contains a [`SessionIDType`] and its relevant identifier information, such as
+//!    a [`DroneEntityTag`].
 mod ua_type;
 mod uas_id;
 
 pub use ua_type::UAType;
+pub use uas_id::DroneEntityTag;
+#[cfg(feature = "icao")]
+pub use uas_id::NationalityMark;
 pub use uas_id::RegistrationID;
 pub use uas_id::SerialNumber;
 pub use uas_id::SessionID;
@@ -28,6 +32,7 @@ use crate::try_serialize::TrySerialize;
 ///
 /// Encapsulates a unmanned aircraft type and an enumerated, unique identifier.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BasicID {
     /// Unmanned aircraft type.
     ua_type: UAType,
@@ -4,6 +4,7 @@ use crate::error::Error;
 ///
 /// This may be used to infer the flight characteristics of the aircraft.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UAType {
     /// Undeclared.
     NotDeclared,
@@ -77,7 +78,7 @@ mod tests {
     fn test_encode() {
         let ornithopter = UAType::Ornithopter;
 
-        let encoded = ornithopter.try_into().unwrap();
+        let encoded: u8 = ornithopter.into();
 
         assert_eq!(5u8, encoded);
     }
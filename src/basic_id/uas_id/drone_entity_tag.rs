@@ -0,0 +1,292 @@
+use core::fmt;
+
+use crate::error::Error;
+use crate::try_serialize::TrySerialize;
+
+/// DRIP Entity Tag
+///
+/// A Hierarchical Host Identity Tag (HHIT) registered with the Drone Remote ID Protocol (DRIP), as
+/// specified in RFC 9374. The tag is an IPv6 address, carried in network byte order.
+///
+/// | Bits | Field                                  |
+/// | ---- | -------------------------------------- |
+/// | 28   | HHIT prefix, `2001:30::/28`            |
+/// | 14   | Registered Assigning Authority (RAA)   |
+/// | 14   | HHIT Domain Authority (HDA)            |
+/// | 8    | HHIT suite ID                          |
+/// | 64   | ORCHID hash                            |
+///
+/// The RAA and HDA together form the Hierarchy ID (HID). The tag displays in the IPv6 textual form
+/// of RFC 5952, for example `2001:30:280:1405:a3ad:1952:ad0:a69e`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DroneEntityTag([u8; 16]);
+
+impl DroneEntityTag {
+    /// HHIT prefix, the upper 28 bits of every tag.
+    pub const HHIT_PREFIX: u32 = 0x200_1003;
+
+    /// HHIT suite ID of EdDSA/Ed25519 with cSHAKE128, the suite used by DRIP.
+    pub const SUITE_ID_EDDSA_CSHAKE128: u8 = 5;
+
+    /// Maximum value of the RAA and the HDA, which are 14 bits each.
+    pub const MAX_AUTHORITY: u16 = 0x3fff;
+
+    /// Byte length of the encoded tag.
+    pub const ENCODED_LENGTH: usize = 16;
+
+    /// Constructs a new tag.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `raa` or `hda` is greater than [`DroneEntityTag::MAX_AUTHORITY`].
+    pub fn try_new(raa: u16, hda: u16, suite_id: u8, hash: [u8; 8]) -> Result<Self, Error> {
+        if raa > Self::MAX_AUTHORITY || hda > Self::MAX_AUTHORITY {
+            return Err(Error::InvalidInteger);
+        }
+
+        let value = (u128::from(Self::HHIT_PREFIX) << 100)
+            | (u128::from(raa) << 86)
+            | (u128::from(hda) << 72)
+            | (u128::from(suite_id) << 64)
+            | u128::from(u64::from_be_bytes(hash));
+
+        Ok(Self(value.to_be_bytes()))
+    }
+
+    /// Returns the Hierarchy ID, the RAA followed by the HDA.
+    pub fn hid(&self) -> u32 {
+        ((self.value() >> 72) & 0xfff_ffff) as u32
+    }
+
+    /// Returns the Registered Assigning Authority.
+    pub fn raa(&self) -> u16 {
+        (self.hid() >> 14) as u16
+    }
+
+    /// Returns the HHIT Domain Authority.
+    pub fn hda(&self) -> u16 {
+        (self.hid() & u32::from(Self::MAX_AUTHORITY)) as u16
+    }
+
+    /// Returns the HHIT suite ID.
+    pub fn suite_id(&self) -> u8 {
+        self.0[7]
+    }
+
+    /// Returns the ORCHID hash.
+    pub fn hash(&self) -> [u8; 8] {
+        self.0[8..16]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the tag in network byte order.
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    // returns the tag as a 128 bit integer.
+    fn value(&self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+}
+
+impl TryFrom<[u8; 16]> for DroneEntityTag {
+    type Error = Error;
+
+    fn try_from(value: [u8; 16]) -> Result<Self, Self::Error> {
+        if (u128::from_be_bytes(value) >> 100) as u32 != Self::HHIT_PREFIX {
+            return Err(Error::InvalidDroneEntityTag);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&[u8]> for DroneEntityTag {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 16] = value.try_into().map_err(|_| Error::InvalidDataLength)?;
+
+        bytes.try_into()
+    }
+}
+
+impl From<DroneEntityTag> for [u8; 16] {
+    fn from(value: DroneEntityTag) -> Self {
+        value.0
+    }
+}
+
+impl TrySerialize for DroneEntityTag {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != Self::ENCODED_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer.clone_from_slice(&self.0);
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for DroneEntityTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut groups = [0u16; 8];
+
+        for (group, bytes) in groups.iter_mut().zip(self.0.chunks(2)) {
+            *group = u16::from_be_bytes([bytes[0], bytes[1]]);
+        }
+
+        // the longest run of at least two zero groups, the first one on ties, is compressed.
+        let mut longest = (0, 0);
+        let mut start = 0;
+
+        for (index, group) in groups.iter().enumerate() {
+            if *group != 0 {
+                start = index + 1;
+            } else if index + 1 - start > longest.1 {
+                longest = (start, index + 1 - start);
+            }
+        }
+
+        if longest.1 < 2 {
+            longest = (8, 0);
+        }
+
+        for (index, group) in groups.iter().enumerate() {
+            if index == longest.0 {
+                f.write_str("::")?;
+            } else if index > longest.0 && index < longest.0 + longest.1 {
+                continue;
+            } else {
+                if index != 0 && index != longest.0 + longest.1 {
+                    f.write_str(":")?;
+                }
+
+                write!(f, "{:x}", group)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::{basic_id::DroneEntityTag, error::Error, try_serialize::TrySerialize};
+
+    // 2001:30:280:1405:a3ad:1952:ad0:a69e, the example DET of RFC 9374.
+    const ENCODED: [u8; 16] = [
+        0x20, 0x01, 0x00, 0x30, 0x02, 0x80, 0x14, 0x05, 0xa3, 0xad, 0x19, 0x52, 0x0a, 0xd0, 0xa6,
+        0x9e,
+    ];
+
+    #[test]
+    fn test_try_new() {
+        let drone_entity_tag =
+            DroneEntityTag::try_new(10, 20, 5, [0xa3, 0xad, 0x19, 0x52, 0x0a, 0xd0, 0xa6, 0x9e])
+                .unwrap();
+
+        assert_eq!(drone_entity_tag.raa(), 10);
+        assert_eq!(drone_entity_tag.hda(), 20);
+        assert_eq!(drone_entity_tag.hid(), (10 << 14) | 20);
+        assert_eq!(drone_entity_tag.suite_id(), 5);
+        assert_eq!(
+            drone_entity_tag.hash(),
+            [0xa3, 0xad, 0x19, 0x52, 0x0a, 0xd0, 0xa6, 0x9e]
+        );
+        assert_eq!(drone_entity_tag.as_bytes(), &ENCODED);
+    }
+
+    #[test]
+    fn test_try_new_fails_invalid_authority() {
+        assert_eq!(
+            DroneEntityTag::try_new(0x4000, 0, 5, [0u8; 8]),
+            Err(Error::InvalidInteger)
+        );
+        assert_eq!(
+            DroneEntityTag::try_new(0, 0x4000, 5, [0u8; 8]),
+            Err(Error::InvalidInteger)
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let drone_entity_tag = DroneEntityTag::try_from(ENCODED).unwrap();
+
+        let mut encoded = [0u8; 16];
+        drone_entity_tag.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded, ENCODED);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let drone_entity_tag = DroneEntityTag::try_from(ENCODED).unwrap();
+
+        let mut too_short = [0u8; 15];
+        let mut too_long = [0u8; 17];
+
+        assert!(drone_entity_tag.try_serialize(&mut too_short).is_err());
+        assert!(drone_entity_tag.try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let drone_entity_tag = DroneEntityTag::try_from(ENCODED.as_ref()).unwrap();
+
+        assert_eq!(drone_entity_tag.raa(), 10);
+        assert_eq!(drone_entity_tag.hda(), 20);
+        assert_eq!(<[u8; 16]>::from(drone_entity_tag), ENCODED);
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let too_short = [0u8; 15];
+        let too_long = [0u8; 17];
+
+        assert!(DroneEntityTag::try_from(too_short.as_ref()).is_err());
+        assert!(DroneEntityTag::try_from(too_long.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_prefix() {
+        let mut encoded = ENCODED;
+        encoded[3] = 0x40;
+
+        assert_eq!(
+            DroneEntityTag::try_from(encoded),
+            Err(Error::InvalidDroneEntityTag)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let drone_entity_tag = DroneEntityTag::try_from(ENCODED).unwrap();
+
+        assert_eq!(
+            drone_entity_tag.to_string(),
+            "2001:30:280:1405:a3ad:1952:ad0:a69e"
+        );
+    }
+
+    #[test]
+    fn test_display_compresses_zeros() {
+        let trailing = DroneEntityTag::try_new(0, 0, 0, [0u8; 8]).unwrap();
+        let middle = DroneEntityTag::try_new(0, 0, 0, [0, 0, 0, 0, 0, 0, 0, 1]).unwrap();
+        let single = DroneEntityTag::try_new(10, 20, 5, [1, 0, 0, 0, 1, 0, 0, 1]).unwrap();
+
+        assert_eq!(trailing.to_string(), "2001:30::");
+        assert_eq!(middle.to_string(), "2001:30::1");
+        assert_eq!(single.to_string(), "2001:30:280:1405:100:0:100:1");
+    }
+}
@@ -2,11 +2,17 @@
 //!
 //! Unmanned Aerial Systems
 
+mod drone_entity_tag;
+#[cfg(feature = "icao")]
+mod nationality_mark;
 mod registration_id;
 mod serial_number;
 mod session_id;
 mod utm_assigned_uuid;
 
+pub use drone_entity_tag::DroneEntityTag;
+#[cfg(feature = "icao")]
+pub use nationality_mark::NationalityMark;
 pub use registration_id::RegistrationID;
 pub use serial_number::SerialNumber;
 pub use session_id::SessionID;
@@ -20,6 +26,7 @@ use crate::try_serialize::TrySerialize;
 ///
 /// Enumerates one of several possible identifiers.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UASID {
     /// No identifier provided.
     None,
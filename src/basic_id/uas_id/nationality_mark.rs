@@ -0,0 +1,305 @@
+/// ICAO Nationality Mark
+///
+/// Prefix of the registration of an aircraft identifying its State of Registry, as allocated by
+/// the International Civil Aviation Organization, along with the ISO 3166 alpha-3 code and the
+/// name of that State, see [`NationalityMark::lookup`].
+///
+/// Some States are allocated several marks, for example `PP` through `PU` for Brazil, or `ZK`
+/// through `ZM` for New Zealand. Marks which carry a dash, such as those of the British Overseas
+/// Territories, cannot be the nationality mark of a
+/// [`RegistrationID`](crate::basic_id::RegistrationID) and are not listed.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NationalityMark {
+    mark: &'static str,
+    country_code: &'static str,
+    state: &'static str,
+}
+
+impl NationalityMark {
+    /// Nationality marks known to this library, ordered by mark.
+    ///
+    /// Marks allocated to a State after this table was compiled are missing, so
+    /// [`RegistrationID::try_new_strict`](crate::basic_id::RegistrationID::try_new_strict) rejects
+    /// them.
+    pub const ALL: &'static [NationalityMark] = &[
+        NationalityMark::new("AP", "PAK", "Pakistan"),
+        NationalityMark::new("A2", "BWA", "Botswana"),
+        NationalityMark::new("A3", "TON", "Tonga"),
+        NationalityMark::new("A4O", "OMN", "Oman"),
+        NationalityMark::new("A5", "BTN", "Bhutan"),
+        NationalityMark::new("A6", "ARE", "United Arab Emirates"),
+        NationalityMark::new("A7", "QAT", "Qatar"),
+        NationalityMark::new("A8", "LBR", "Liberia"),
+        NationalityMark::new("A9C", "BHR", "Bahrain"),
+        NationalityMark::new("B", "CHN", "China"),
+        NationalityMark::new("C", "CAN", "Canada"),
+        NationalityMark::new("CC", "CHL", "Chile"),
+        NationalityMark::new("CF", "CAN", "Canada"),
+        NationalityMark::new("CG", "CAN", "Canada"),
+        NationalityMark::new("CN", "MAR", "Morocco"),
+        NationalityMark::new("CP", "BOL", "Bolivia"),
+        NationalityMark::new("CR", "PRT", "Portugal"),
+        NationalityMark::new("CS", "PRT", "Portugal"),
+        NationalityMark::new("CU", "CUB", "Cuba"),
+        NationalityMark::new("CX", "URY", "Uruguay"),
+        NationalityMark::new("C2", "NRU", "Nauru"),
+        NationalityMark::new("C3", "AND", "Andorra"),
+        NationalityMark::new("C5", "GMB", "Gambia"),
+        NationalityMark::new("C6", "BHS", "Bahamas"),
+        NationalityMark::new("C9", "MOZ", "Mozambique"),
+        NationalityMark::new("D", "DEU", "Germany"),
+        NationalityMark::new("DQ", "FJI", "Fiji"),
+        NationalityMark::new("D2", "AGO", "Angola"),
+        NationalityMark::new("D4", "CPV", "Cabo Verde"),
+        NationalityMark::new("D6", "COM", "Comoros"),
+        NationalityMark::new("EC", "ESP", "Spain"),
+        NationalityMark::new("EI", "IRL", "Ireland"),
+        NationalityMark::new("EK", "ARM", "Armenia"),
+        NationalityMark::new("EP", "IRN", "Iran"),
+        NationalityMark::new("ER", "MDA", "Moldova"),
+        NationalityMark::new("ES", "EST", "Estonia"),
+        NationalityMark::new("ET", "ETH", "Ethiopia"),
+        NationalityMark::new("EW", "BLR", "Belarus"),
+        NationalityMark::new("EX", "KGZ", "Kyrgyzstan"),
+        NationalityMark::new("EY", "TJK", "Tajikistan"),
+        NationalityMark::new("EZ", "TKM", "Turkmenistan"),
+        NationalityMark::new("E3", "ERI", "Eritrea"),
+        NationalityMark::new("E5", "COK", "Cook Islands"),
+        NationalityMark::new("E7", "BIH", "Bosnia and Herzegovina"),
+        NationalityMark::new("F", "FRA", "France"),
+        NationalityMark::new("G", "GBR", "United Kingdom"),
+        NationalityMark::new("HA", "HUN", "Hungary"),
+        NationalityMark::new("HB", "CHE", "Switzerland"),
+        NationalityMark::new("HC", "ECU", "Ecuador"),
+        NationalityMark::new("HH", "HTI", "Haiti"),
+        NationalityMark::new("HI", "DOM", "Dominican Republic"),
+        NationalityMark::new("HK", "COL", "Colombia"),
+        NationalityMark::new("HL", "KOR", "Republic of Korea"),
+        NationalityMark::new("HP", "PAN", "Panama"),
+        NationalityMark::new("HR", "HND", "Honduras"),
+        NationalityMark::new("HS", "THA", "Thailand"),
+        NationalityMark::new("HZ", "SAU", "Saudi Arabia"),
+        NationalityMark::new("H4", "SLB", "Solomon Islands"),
+        NationalityMark::new("I", "ITA", "Italy"),
+        NationalityMark::new("JA", "JPN", "Japan"),
+        NationalityMark::new("JU", "MNG", "Mongolia"),
+        NationalityMark::new("JY", "JOR", "Jordan"),
+        NationalityMark::new("J2", "DJI", "Djibouti"),
+        NationalityMark::new("J3", "GRD", "Grenada"),
+        NationalityMark::new("J5", "GNB", "Guinea-Bissau"),
+        NationalityMark::new("J6", "LCA", "Saint Lucia"),
+        NationalityMark::new("J7", "DMA", "Dominica"),
+        NationalityMark::new("J8", "VCT", "Saint Vincent and the Grenadines"),
+        NationalityMark::new("LN", "NOR", "Norway"),
+        NationalityMark::new("LV", "ARG", "Argentina"),
+        NationalityMark::new("LX", "LUX", "Luxembourg"),
+        NationalityMark::new("LY", "LTU", "Lithuania"),
+        NationalityMark::new("LZ", "BGR", "Bulgaria"),
+        NationalityMark::new("N", "USA", "United States"),
+        NationalityMark::new("OB", "PER", "Peru"),
+        NationalityMark::new("OD", "LBN", "Lebanon"),
+        NationalityMark::new("OE", "AUT", "Austria"),
+        NationalityMark::new("OH", "FIN", "Finland"),
+        NationalityMark::new("OK", "CZE", "Czechia"),
+        NationalityMark::new("OM", "SVK", "Slovakia"),
+        NationalityMark::new("OO", "BEL", "Belgium"),
+        NationalityMark::new("OY", "DNK", "Denmark"),
+        NationalityMark::new("P", "PRK", "Democratic People's Republic of Korea"),
+        NationalityMark::new("PH", "NLD", "Netherlands"),
+        NationalityMark::new("PK", "IDN", "Indonesia"),
+        NationalityMark::new("PP", "BRA", "Brazil"),
+        NationalityMark::new("PR", "BRA", "Brazil"),
+        NationalityMark::new("PS", "BRA", "Brazil"),
+        NationalityMark::new("PT", "BRA", "Brazil"),
+        NationalityMark::new("PU", "BRA", "Brazil"),
+        NationalityMark::new("PZ", "SUR", "Suriname"),
+        NationalityMark::new("P2", "PNG", "Papua New Guinea"),
+        NationalityMark::new("P4", "ABW", "Aruba"),
+        NationalityMark::new("RA", "RUS", "Russian Federation"),
+        NationalityMark::new("RDPL", "LAO", "Lao People's Democratic Republic"),
+        NationalityMark::new("RP", "PHL", "Philippines"),
+        NationalityMark::new("SE", "SWE", "Sweden"),
+        NationalityMark::new("SP", "POL", "Poland"),
+        NationalityMark::new("ST", "SDN", "Sudan"),
+        NationalityMark::new("SU", "EGY", "Egypt"),
+        NationalityMark::new("SX", "GRC", "Greece"),
+        NationalityMark::new("S2", "BGD", "Bangladesh"),
+        NationalityMark::new("S5", "SVN", "Slovenia"),
+        NationalityMark::new("S7", "SYC", "Seychelles"),
+        NationalityMark::new("S9", "STP", "Sao Tome and Principe"),
+        NationalityMark::new("TC", "TUR", "Turkey"),
+        NationalityMark::new("TF", "ISL", "Iceland"),
+        NationalityMark::new("TG", "GTM", "Guatemala"),
+        NationalityMark::new("TI", "CRI", "Costa Rica"),
+        NationalityMark::new("TJ", "CMR", "Cameroon"),
+        NationalityMark::new("TL", "CAF", "Central African Republic"),
+        NationalityMark::new("TN", "COG", "Congo"),
+        NationalityMark::new("TR", "GAB", "Gabon"),
+        NationalityMark::new("TS", "TUN", "Tunisia"),
+        NationalityMark::new("TT", "TCD", "Chad"),
+        NationalityMark::new("TU", "CIV", "Cote d'Ivoire"),
+        NationalityMark::new("TY", "BEN", "Benin"),
+        NationalityMark::new("TZ", "MLI", "Mali"),
+        NationalityMark::new("T2", "TUV", "Tuvalu"),
+        NationalityMark::new("T3", "KIR", "Kiribati"),
+        NationalityMark::new("T7", "SMR", "San Marino"),
+        NationalityMark::new("T8A", "PLW", "Palau"),
+        NationalityMark::new("UK", "UZB", "Uzbekistan"),
+        NationalityMark::new("UP", "KAZ", "Kazakhstan"),
+        NationalityMark::new("UR", "UKR", "Ukraine"),
+        NationalityMark::new("VH", "AUS", "Australia"),
+        NationalityMark::new("VN", "VNM", "Viet Nam"),
+        NationalityMark::new("VT", "IND", "India"),
+        NationalityMark::new("V2", "ATG", "Antigua and Barbuda"),
+        NationalityMark::new("V3", "BLZ", "Belize"),
+        NationalityMark::new("V4", "KNA", "Saint Kitts and Nevis"),
+        NationalityMark::new("V5", "NAM", "Namibia"),
+        NationalityMark::new("V6", "FSM", "Micronesia"),
+        NationalityMark::new("V7", "MHL", "Marshall Islands"),
+        NationalityMark::new("V8", "BRN", "Brunei Darussalam"),
+        NationalityMark::new("XA", "MEX", "Mexico"),
+        NationalityMark::new("XB", "MEX", "Mexico"),
+        NationalityMark::new("XC", "MEX", "Mexico"),
+        NationalityMark::new("XT", "BFA", "Burkina Faso"),
+        NationalityMark::new("XU", "KHM", "Cambodia"),
+        NationalityMark::new("XY", "MMR", "Myanmar"),
+        NationalityMark::new("XZ", "MMR", "Myanmar"),
+        NationalityMark::new("YA", "AFG", "Afghanistan"),
+        NationalityMark::new("YI", "IRQ", "Iraq"),
+        NationalityMark::new("YJ", "VUT", "Vanuatu"),
+        NationalityMark::new("YK", "SYR", "Syrian Arab Republic"),
+        NationalityMark::new("YL", "LVA", "Latvia"),
+        NationalityMark::new("YN", "NIC", "Nicaragua"),
+        NationalityMark::new("YR", "ROU", "Romania"),
+        NationalityMark::new("YS", "SLV", "El Salvador"),
+        NationalityMark::new("YU", "SRB", "Serbia"),
+        NationalityMark::new("YV", "VEN", "Venezuela"),
+        NationalityMark::new("Z", "ZWE", "Zimbabwe"),
+        NationalityMark::new("ZA", "ALB", "Albania"),
+        NationalityMark::new("ZK", "NZL", "New Zealand"),
+        NationalityMark::new("ZL", "NZL", "New Zealand"),
+        NationalityMark::new("ZM", "NZL", "New Zealand"),
+        NationalityMark::new("ZP", "PRY", "Paraguay"),
+        NationalityMark::new("ZS", "ZAF", "South Africa"),
+        NationalityMark::new("ZT", "ZAF", "South Africa"),
+        NationalityMark::new("ZU", "ZAF", "South Africa"),
+        NationalityMark::new("Z3", "MKD", "North Macedonia"),
+        NationalityMark::new("Z8", "SSD", "South Sudan"),
+        NationalityMark::new("3A", "MCO", "Monaco"),
+        NationalityMark::new("3B", "MUS", "Mauritius"),
+        NationalityMark::new("3C", "GNQ", "Equatorial Guinea"),
+        NationalityMark::new("3D", "SWZ", "Eswatini"),
+        NationalityMark::new("3X", "GIN", "Guinea"),
+        NationalityMark::new("4K", "AZE", "Azerbaijan"),
+        NationalityMark::new("4L", "GEO", "Georgia"),
+        NationalityMark::new("4O", "MNE", "Montenegro"),
+        NationalityMark::new("4R", "LKA", "Sri Lanka"),
+        NationalityMark::new("4W", "TLS", "Timor-Leste"),
+        NationalityMark::new("4X", "ISR", "Israel"),
+        NationalityMark::new("5A", "LBY", "Libya"),
+        NationalityMark::new("5B", "CYP", "Cyprus"),
+        NationalityMark::new("5H", "TZA", "United Republic of Tanzania"),
+        NationalityMark::new("5N", "NGA", "Nigeria"),
+        NationalityMark::new("5R", "MDG", "Madagascar"),
+        NationalityMark::new("5T", "MRT", "Mauritania"),
+        NationalityMark::new("5U", "NER", "Niger"),
+        NationalityMark::new("5V", "TGO", "Togo"),
+        NationalityMark::new("5W", "WSM", "Samoa"),
+        NationalityMark::new("5X", "UGA", "Uganda"),
+        NationalityMark::new("5Y", "KEN", "Kenya"),
+        NationalityMark::new("6O", "SOM", "Somalia"),
+        NationalityMark::new("6V", "SEN", "Senegal"),
+        NationalityMark::new("6W", "SEN", "Senegal"),
+        NationalityMark::new("6Y", "JAM", "Jamaica"),
+        NationalityMark::new("7O", "YEM", "Yemen"),
+        NationalityMark::new("7P", "LSO", "Lesotho"),
+        NationalityMark::new("7Q", "MWI", "Malawi"),
+        NationalityMark::new("7T", "DZA", "Algeria"),
+        NationalityMark::new("8P", "BRB", "Barbados"),
+        NationalityMark::new("8Q", "MDV", "Maldives"),
+        NationalityMark::new("8R", "GUY", "Guyana"),
+        NationalityMark::new("9A", "HRV", "Croatia"),
+        NationalityMark::new("9G", "GHA", "Ghana"),
+        NationalityMark::new("9H", "MLT", "Malta"),
+        NationalityMark::new("9J", "ZMB", "Zambia"),
+        NationalityMark::new("9K", "KWT", "Kuwait"),
+        NationalityMark::new("9L", "SLE", "Sierra Leone"),
+        NationalityMark::new("9M", "MYS", "Malaysia"),
+        NationalityMark::new("9N", "NPL", "Nepal"),
+        NationalityMark::new("9Q", "COD", "Democratic Republic of the Congo"),
+        NationalityMark::new("9U", "BDI", "Burundi"),
+        NationalityMark::new("9V", "SGP", "Singapore"),
+        NationalityMark::new("9XR", "RWA", "Rwanda"),
+        NationalityMark::new("9Y", "TTO", "Trinidad and Tobago"),
+    ];
+
+    /// Returns the nationality mark equal to `mark`, `None` if it is not known.
+    pub fn lookup(mark: &str) -> Option<Self> {
+        Self::ALL.iter().find(|known| known.mark == mark).copied()
+    }
+
+    /// Returns the nationality mark, for example `N`.
+    pub fn mark(&self) -> &'static str {
+        self.mark
+    }
+
+    /// Returns the ISO 3166 alpha-3 code of the State of Registry, for example `USA`.
+    pub fn country_code(&self) -> &'static str {
+        self.country_code
+    }
+
+    /// Returns the name of the State of Registry, for example `United States`.
+    pub fn state(&self) -> &'static str {
+        self.state
+    }
+
+    // entry of the table.
+    const fn new(mark: &'static str, country_code: &'static str, state: &'static str) -> Self {
+        Self {
+            mark,
+            country_code,
+            state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_id::{NationalityMark, RegistrationID};
+
+    #[test]
+    fn test_lookup() {
+        let nationality_mark = NationalityMark::lookup("HB").unwrap();
+
+        assert_eq!(nationality_mark.mark(), "HB");
+        assert_eq!(nationality_mark.country_code(), "CHE");
+        assert_eq!(nationality_mark.state(), "Switzerland");
+        assert_eq!(NationalityMark::lookup("PT").unwrap().country_code(), "BRA");
+        assert_eq!(NationalityMark::lookup("9XR").unwrap().state(), "Rwanda");
+        assert_eq!(NationalityMark::lookup("ZM").unwrap().country_code(), "NZL");
+        assert_eq!(
+            NationalityMark::lookup("4W").unwrap().state(),
+            "Timor-Leste"
+        );
+    }
+
+    #[test]
+    fn test_lookup_unknown() {
+        assert_eq!(NationalityMark::lookup("QQ"), None);
+        assert_eq!(NationalityMark::lookup("n"), None);
+        assert_eq!(NationalityMark::lookup(""), None);
+    }
+
+    #[test]
+    fn test_all() {
+        for (index, nationality_mark) in NationalityMark::ALL.iter().enumerate() {
+            assert!(RegistrationID::try_new(nationality_mark.mark(), "1").is_ok());
+            assert_eq!(nationality_mark.country_code().len(), 3);
+            assert!(
+                NationalityMark::ALL[index + 1..]
+                    .iter()
+                    .all(|other| other.mark() != nationality_mark.mark())
+            );
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "icao")]
+use crate::basic_id::NationalityMark;
 use crate::error::Error;
 use crate::try_serialize::TrySerialize;
 
@@ -6,7 +11,8 @@ use crate::try_serialize::TrySerialize;
 /// Contains both a nationality mark issued by the International Civil Aviation Organization and a
 /// unique identifier issued by the respective Civil Aviation Authority.
 ///
-/// The string given must be ASCII upper case, digits, or a dot character ".".
+/// The string given must be ASCII upper case, digits, or a dot character ".". The registration ID is
+/// written as the nationality mark and the CAA ID joined by the dot, for example `N.123ABC`.
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct RegistrationID([u8; 20]);
 
@@ -25,11 +31,11 @@ impl RegistrationID {
 
         let valid_nationality_mark = nationality_mark
             .chars()
-            .all(|c| c.is_ascii_uppercase() || c.is_digit(10) || c == '\x00');
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '\x00');
 
         let valid_caa_id = caa_id
             .chars()
-            .all(|c| c.is_ascii_uppercase() || c.is_digit(10) || c == '\x00');
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '\x00');
 
         if !valid_nationality_mark || !valid_caa_id {
             return Err(Error::InvalidRegistrationID);
@@ -40,13 +46,26 @@ impl RegistrationID {
         let caa_stop = caa_start + caa_id.len();
 
         let mut id = [0u8; 20];
-        id[..dot_index].clone_from_slice(&nationality_mark.as_bytes());
+        id[..dot_index].clone_from_slice(nationality_mark.as_bytes());
         id[dot_index] = b'.';
-        id[caa_start..caa_stop].clone_from_slice(&caa_id.as_bytes());
+        id[caa_start..caa_stop].clone_from_slice(caa_id.as_bytes());
 
         Ok(Self(id))
     }
 
+    /// Tries to construct a new Registration ID, with a nationality mark allocated by ICAO.
+    ///
+    /// Returns error if:
+    ///
+    /// - `nationality_mark` is not a known [`NationalityMark`].
+    /// - [`RegistrationID::try_new`] fails.
+    #[cfg(feature = "icao")]
+    pub fn try_new_strict(nationality_mark: &str, caa_id: &str) -> Result<Self, Error> {
+        NationalityMark::lookup(nationality_mark).ok_or(Error::UnknownNationalityMark)?;
+
+        Self::try_new(nationality_mark, caa_id)
+    }
+
     /// Returns the nationality mark.
     pub fn nationality_mark(&self) -> &str {
         let mark = self
@@ -88,6 +107,12 @@ impl RegistrationID {
             .map_err(|_| Error::Unreachable)
             .unwrap()
     }
+
+    /// Returns the nationality mark with its State of Registry, `None` if it is not known.
+    #[cfg(feature = "icao")]
+    pub fn nationality(&self) -> Option<NationalityMark> {
+        NationalityMark::lookup(self.nationality_mark())
+    }
 }
 
 impl TryFrom<&[u8]> for RegistrationID {
@@ -110,7 +135,7 @@ impl TryFrom<&[u8]> for RegistrationID {
                 continue;
             }
 
-            if !(c.is_ascii_uppercase() || c.is_digit(10) || c == '\x00') {
+            if !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '\x00') {
                 return Err(Error::InvalidRegistrationID);
             }
         }
@@ -123,6 +148,34 @@ impl TryFrom<&[u8]> for RegistrationID {
     }
 }
 
+impl FromStr for RegistrationID {
+    type Err = Error;
+
+    /// Parses a registration ID written as the nationality mark and the CAA ID joined by a dot, for
+    /// example `N.123ABC`.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `s` does not contain exactly one dot.
+    /// - the nationality mark or the CAA ID is empty.
+    /// - [`RegistrationID::try_new`] fails.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (nationality_mark, caa_id) = s.split_once('.').ok_or(Error::InvalidRegistrationID)?;
+
+        if nationality_mark.is_empty() || caa_id.is_empty() || caa_id.contains('.') {
+            return Err(Error::InvalidRegistrationID);
+        }
+
+        Self::try_new(nationality_mark, caa_id)
+    }
+}
+
+impl fmt::Display for RegistrationID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.nationality_mark(), self.caa_id())
+    }
+}
+
 impl TrySerialize for RegistrationID {
     type Error = Error;
 
@@ -137,9 +190,30 @@ impl TrySerialize for RegistrationID {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for RegistrationID {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde_support::ascii::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RegistrationID {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: [u8; 20] = crate::serde_support::ascii::deserialize(deserializer)?;
+
+        Self::try_from(bytes.as_ref()).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
+    use std::string::ToString;
+
     use crate::basic_id::RegistrationID;
+    use crate::error::Error;
     use crate::try_serialize::TrySerialize;
 
     fn str_to_fixed_bytes(s: &str) -> [u8; 20] {
@@ -252,4 +326,81 @@ mod tests {
 
         assert!(RegistrationID::try_from(id.as_ref()).is_err());
     }
+
+    #[test]
+    fn test_from_str() {
+        let registration_id: RegistrationID = "N.123ABC".parse().unwrap();
+
+        assert_eq!(
+            registration_id,
+            RegistrationID::try_new("N", "123ABC").unwrap()
+        );
+        assert_eq!(registration_id.nationality_mark(), "N");
+        assert_eq!(registration_id.caa_id(), "123ABC");
+    }
+
+    #[test]
+    fn test_from_str_fails() {
+        assert_eq!(
+            "N123ABC".parse::<RegistrationID>(),
+            Err(Error::InvalidRegistrationID)
+        );
+        assert_eq!(
+            "N.123.ABC".parse::<RegistrationID>(),
+            Err(Error::InvalidRegistrationID)
+        );
+        assert_eq!(
+            "N.".parse::<RegistrationID>(),
+            Err(Error::InvalidRegistrationID)
+        );
+        assert_eq!(
+            ".ABC".parse::<RegistrationID>(),
+            Err(Error::InvalidRegistrationID)
+        );
+        assert_eq!(
+            "n.123abc".parse::<RegistrationID>(),
+            Err(Error::InvalidRegistrationID)
+        );
+        assert_eq!(
+            "N.1234567890123456789".parse::<RegistrationID>(),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let registration_id = RegistrationID::try_new("HB", "ABC12").unwrap();
+
+        assert_eq!(registration_id.to_string(), "HB.ABC12");
+        assert_eq!(
+            registration_id.to_string().parse::<RegistrationID>(),
+            Ok(registration_id)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "icao")]
+    fn test_try_new_strict() {
+        let registration_id = RegistrationID::try_new_strict("D", "ABCD").unwrap();
+
+        assert_eq!(registration_id.nationality().unwrap().country_code(), "DEU");
+        assert_eq!(
+            RegistrationID::try_new_strict("QQ", "ABCD"),
+            Err(Error::UnknownNationalityMark)
+        );
+        assert_eq!(
+            RegistrationID::try_new_strict("N", "abcd"),
+            Err(Error::InvalidRegistrationID)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "icao")]
+    fn test_nationality() {
+        let known: RegistrationID = "VH.ABC".parse().unwrap();
+        let unknown: RegistrationID = "QQ.ABC".parse().unwrap();
+
+        assert_eq!(known.nationality().unwrap().state(), "Australia");
+        assert_eq!(unknown.nationality(), None);
+    }
 }
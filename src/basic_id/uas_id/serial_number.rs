@@ -1,3 +1,6 @@
+use core::fmt;
+use core::str::FromStr;
+
 use crate::error::Error;
 use crate::try_serialize::TrySerialize;
 
@@ -8,6 +11,10 @@ use crate::try_serialize::TrySerialize;
 /// Per the specification, the manufacturer's code MUST be exactly 4 characters of uppercase
 /// ASCII (except "O" or "I") or digits. The manufacturer's serial MUST be 1-15 characters of
 /// uppercase ASCII (except "O" or "I") or digits.
+///
+/// The serial number is written as a single string, the manufacturer's code, followed by the
+/// length of the manufacturer's serial as one hexadecimal digit, followed by the manufacturer's
+/// serial, for example `ABCD7XYZ1234`.
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct SerialNumber([u8; 20]);
 
@@ -21,7 +28,7 @@ impl SerialNumber {
     /// - `mfr_code` is not ASCII, decimal digits, or nulls.
     /// - `mfr_serial` is not ASCII, decimal digits, or nulls.
     pub fn try_new(mfr_code: &str, mfr_serial: &str) -> Result<Self, Error> {
-        if mfr_code.len() != 4 || mfr_serial.len() < 1 || mfr_serial.len() > 15 {
+        if mfr_code.len() != 4 || mfr_serial.is_empty() || mfr_serial.len() > 15 {
             return Err(Error::InvalidDataLength);
         }
 
@@ -32,19 +39,10 @@ impl SerialNumber {
             return Err(Error::InvalidSerialNumber);
         }
 
-        // we send the length values to uppercase ASCII hex values as follows:
-        //
-        // - 1-9 to ASCII decimal values 49-57 ("1" to "9")
-        // - 10-15 to ASCII decimal values 65-70 ("A" to "F")
-        let hex_length_ascii = match mfr_serial.len() <= 10 {
-            true => mfr_serial.len() + 48,
-            false => mfr_serial.len() + 55,
-        } as u8;
-
         let mut serial_number = [0u8; 20];
-        serial_number[..4].clone_from_slice(&mfr_code.as_bytes());
-        serial_number[4] = hex_length_ascii;
-        serial_number[5..5 + mfr_serial.len()].clone_from_slice(&mfr_serial.as_bytes());
+        serial_number[..4].clone_from_slice(mfr_code.as_bytes());
+        serial_number[4] = Self::length_code(mfr_serial.len());
+        serial_number[5..5 + mfr_serial.len()].clone_from_slice(mfr_serial.as_bytes());
 
         Ok(Self(serial_number))
     }
@@ -54,7 +52,7 @@ impl SerialNumber {
     /// Code is issued by International Civil Aviation Organization to UAS manufacturers globally.
     pub fn mfr_code(&self) -> &str {
         // INVARIANT: bytes are valid utf8 chars
-        &str::from_utf8(&self.0[..4]).unwrap()
+        str::from_utf8(&self.0[..4]).unwrap()
     }
 
     /// Returns the manufacturer's serial number.
@@ -65,13 +63,35 @@ impl SerialNumber {
             .ok_or(Error::Unreachable)
             .unwrap();
 
-        &str::from_utf8(mfr_serial)
+        str::from_utf8(mfr_serial)
             .map_err(|_| Error::Unreachable)
             .unwrap()
     }
 
+    // we send the length values to uppercase ASCII hex values as follows:
+    //
+    // - 1-9 to ASCII decimal values 49-57 ("1" to "9")
+    // - 10-15 to ASCII decimal values 65-70 ("A" to "F")
+    fn length_code(length: usize) -> u8 {
+        // INVARIANT: length is checked to be between 1 and 15 by the caller.
+        char::from_digit(length as u32, 16)
+            .ok_or(Error::Unreachable)
+            .unwrap()
+            .to_ascii_uppercase() as u8
+    }
+
+    // inverse of `length_code`, `None` unless the code is an uppercase hex digit other than "0".
+    fn serial_length(code: u8) -> Option<usize> {
+        match code {
+            b'1'..=b'9' | b'A'..=b'F' => {
+                char::from(code).to_digit(16).map(|length| length as usize)
+            }
+            _ => None,
+        }
+    }
+
     fn is_valid_character(c: char) -> bool {
-        let is_ascii_digit_or_null = c.is_ascii_uppercase() || c.is_digit(10) || c == '\x00';
+        let is_ascii_digit_or_null = c.is_ascii_uppercase() || c.is_ascii_digit() || c == '\x00';
 
         is_ascii_digit_or_null && c != 'O' && c != 'I'
     }
@@ -94,9 +114,12 @@ impl TryFrom<&[u8]> for SerialNumber {
             return Err(Error::InvalidSerialNumber);
         }
 
-        let serial_length = value[4] as char;
+        let serial_length = Self::serial_length(value[4]).ok_or(Error::InvalidSerialNumber)?;
 
-        if !serial_length.is_ascii_hexdigit() {
+        // the serial fills exactly the declared length, the rest is null padding.
+        let (mfr_serial, padding) = value[5..].split_at(serial_length);
+
+        if mfr_serial.contains(&0) || padding.iter().any(|&byte| byte != 0) {
             return Err(Error::InvalidSerialNumber);
         }
 
@@ -104,6 +127,49 @@ impl TryFrom<&[u8]> for SerialNumber {
     }
 }
 
+impl FromStr for SerialNumber {
+    type Err = Error;
+
+    /// Parses a serial number written as a single string, for example `ABCD7XYZ1234`.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `s` is shorter than 6 or longer than 20 characters.
+    /// - the length code is not a hexadecimal digit matching the manufacturer's serial length.
+    /// - `s` contains a character [`SerialNumber::try_new`] rejects.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() < 6 || s.len() > 20 {
+            return Err(Error::InvalidDataLength);
+        }
+
+        if !s.is_ascii() {
+            return Err(Error::InvalidSerialNumber);
+        }
+
+        let (mfr_code, rest) = s.split_at(4);
+
+        let (length_code, mfr_serial) = rest.split_at(1);
+
+        if Self::serial_length(length_code.as_bytes()[0]) != Some(mfr_serial.len()) {
+            return Err(Error::InvalidSerialNumber);
+        }
+
+        Self::try_new(mfr_code, mfr_serial)
+    }
+}
+
+impl fmt::Display for SerialNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.mfr_code(),
+            char::from(self.0[4]),
+            self.mfr_serial()
+        )
+    }
+}
+
 impl TrySerialize for SerialNumber {
     type Error = Error;
 
@@ -118,9 +184,29 @@ impl TrySerialize for SerialNumber {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for SerialNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde_support::ascii::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SerialNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: [u8; 20] = crate::serde_support::ascii::deserialize(deserializer)?;
+
+        Self::try_from(bytes.as_ref()).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{basic_id::SerialNumber, try_serialize::TrySerialize};
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::{basic_id::SerialNumber, error::Error, try_serialize::TrySerialize};
 
     fn str_to_fixed_bytes(s: &str) -> [u8; 20] {
         assert!(s.len() < 20);
@@ -232,4 +318,77 @@ mod tests {
 
         assert!(SerialNumber::try_from(invalid.as_ref()).is_err());
     }
+
+    #[test]
+    fn test_decode_fails_mismatched_len_character() {
+        let too_long = str_to_fixed_bytes("ASDF312345");
+        let too_short = str_to_fixed_bytes("ASDF5123");
+        let zero = str_to_fixed_bytes("ASDF0");
+
+        assert!(SerialNumber::try_from(too_long.as_ref()).is_err());
+        assert!(SerialNumber::try_from(too_short.as_ref()).is_err());
+        assert!(SerialNumber::try_from(zero.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_encode_hex_len_character() {
+        let serial_number = SerialNumber::try_new("ASDF", "1234567890").unwrap();
+
+        let mut encoded = [0u8; 20];
+        serial_number.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded, str_to_fixed_bytes("ASDFA1234567890"));
+        assert_eq!(SerialNumber::try_from(encoded.as_ref()), Ok(serial_number));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let serial_number: SerialNumber = "ABCD7XYZ1234".parse().unwrap();
+
+        assert_eq!(serial_number.mfr_code(), "ABCD");
+        assert_eq!(serial_number.mfr_serial(), "XYZ1234");
+        assert_eq!(
+            "ASDFF123456789ABCDEF"
+                .parse::<SerialNumber>()
+                .unwrap()
+                .mfr_serial()
+                .len(),
+            15
+        );
+    }
+
+    #[test]
+    fn test_from_str_fails() {
+        assert_eq!(
+            "ABCD7".parse::<SerialNumber>(),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            "ABCD8XYZ1234".parse::<SerialNumber>(),
+            Err(Error::InvalidSerialNumber)
+        );
+        assert_eq!(
+            "ABCDGXYZ1234".parse::<SerialNumber>(),
+            Err(Error::InvalidSerialNumber)
+        );
+        assert_eq!(
+            "ABCD7XYZ12O4".parse::<SerialNumber>(),
+            Err(Error::InvalidSerialNumber)
+        );
+        assert_eq!(
+            "ABCD7XYZ12λ".parse::<SerialNumber>(),
+            Err(Error::InvalidSerialNumber)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let serial_number = SerialNumber::try_new("ABCD", "XYZ1234").unwrap();
+
+        assert_eq!(serial_number.to_string(), "ABCD7XYZ1234");
+        assert_eq!(
+            serial_number.to_string().parse::<SerialNumber>(),
+            Ok(serial_number)
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::basic_id::DroneEntityTag;
 use crate::error::Error;
 use crate::try_serialize::TrySerialize;
 
@@ -5,6 +6,7 @@ use crate::try_serialize::TrySerialize;
 ///
 /// First byte of the [`SessionID`], signals which format it is using.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SessionIDType {
     /// Reserved.
     Reserved,
@@ -37,9 +39,14 @@ impl From<SessionIDType> for u8 {
 ///
 /// Consists of one byte indicating the [`SessionIDType`](SessionIDType) followed by 19 bytes of the unique session
 /// ID.
+///
+/// A [`DroneEntityTag`] occupies the first 16 bytes of the ID and a HashedID8 the first 8 bytes,
+/// the remaining bytes are zero padding.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SessionID {
     session_id_type: SessionIDType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::hex"))]
     id: [u8; 19],
 }
 
@@ -52,6 +59,14 @@ impl SessionID {
         }
     }
 
+    /// Constructs a new session ID from an IEEE 1609.2-2016 HashedID8.
+    pub fn from_hashed_id8(hashed_id8: [u8; 8]) -> Self {
+        let mut id = [0u8; 19];
+        id[..8].clone_from_slice(&hashed_id8);
+
+        Self::new(SessionIDType::IEEE16092HashedID8, id)
+    }
+
     /// Returns the session ID type.
     pub fn session_id_type(&self) -> SessionIDType {
         self.session_id_type
@@ -61,6 +76,53 @@ impl SessionID {
     pub fn id(&self) -> &[u8; 19] {
         &self.id
     }
+
+    /// Returns the DRIP Entity Tag.
+    ///
+    /// Returns an error if:
+    ///
+    /// - The session ID type is not [`SessionIDType::IETFDroneRemoteIDProtocol`].
+    /// - The padding after the tag is not zero.
+    /// - The tag does not start with the HHIT prefix.
+    pub fn drone_entity_tag(&self) -> Result<DroneEntityTag, Error> {
+        if self.session_id_type != SessionIDType::IETFDroneRemoteIDProtocol
+            || self.id[DroneEntityTag::ENCODED_LENGTH..]
+                .iter()
+                .any(|byte| *byte != 0)
+        {
+            return Err(Error::InvalidSessionID);
+        }
+
+        DroneEntityTag::try_from(&self.id[..DroneEntityTag::ENCODED_LENGTH])
+    }
+
+    /// Returns the IEEE 1609.2-2016 HashedID8.
+    ///
+    /// Returns an error if:
+    ///
+    /// - The session ID type is not [`SessionIDType::IEEE16092HashedID8`].
+    /// - The padding after the hash is not zero.
+    pub fn hashed_id8(&self) -> Result<[u8; 8], Error> {
+        if self.session_id_type != SessionIDType::IEEE16092HashedID8
+            || self.id[8..].iter().any(|byte| *byte != 0)
+        {
+            return Err(Error::InvalidSessionID);
+        }
+
+        Ok(self.id[..8]
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap())
+    }
+}
+
+impl From<DroneEntityTag> for SessionID {
+    fn from(value: DroneEntityTag) -> Self {
+        let mut id = [0u8; 19];
+        id[..DroneEntityTag::ENCODED_LENGTH].clone_from_slice(value.as_bytes());
+
+        Self::new(SessionIDType::IETFDroneRemoteIDProtocol, id)
+    }
 }
 
 impl TryFrom<&[u8]> for SessionID {
@@ -103,7 +165,8 @@ impl TrySerialize for SessionID {
 #[cfg(test)]
 mod tests {
     use crate::{
-        basic_id::{SessionID, SessionIDType},
+        basic_id::{DroneEntityTag, SessionID, SessionIDType},
+        error::Error,
         try_serialize::TrySerialize,
     };
 
@@ -146,7 +209,7 @@ mod tests {
         let mut encoded = [0u8; 20];
         session_id.try_serialize(&mut encoded).unwrap();
 
-        assert_eq!(encoded[0], ietf_drip.into());
+        assert_eq!(encoded[0], u8::from(ietf_drip));
         assert_eq!(&encoded[1..], id.as_ref());
     }
 
@@ -187,4 +250,66 @@ mod tests {
         assert!(SessionID::try_from(too_short.as_ref()).is_err());
         assert!(SessionID::try_from(too_long.as_ref()).is_err());
     }
+
+    #[test]
+    fn test_session_id_drone_entity_tag() {
+        let drone_entity_tag = DroneEntityTag::try_new(10, 20, 5, [7u8; 8]).unwrap();
+
+        let session_id = SessionID::from(drone_entity_tag);
+
+        assert_eq!(
+            session_id.session_id_type(),
+            SessionIDType::IETFDroneRemoteIDProtocol
+        );
+        assert_eq!(&session_id.id()[16..], &[0u8; 3]);
+        assert_eq!(session_id.drone_entity_tag(), Ok(drone_entity_tag));
+        assert_eq!(session_id.hashed_id8(), Err(Error::InvalidSessionID));
+    }
+
+    #[test]
+    fn test_session_id_drone_entity_tag_fails_invalid() {
+        let drone_entity_tag = DroneEntityTag::try_new(10, 20, 5, [7u8; 8]).unwrap();
+
+        let mut padded = *SessionID::from(drone_entity_tag).id();
+        padded[18] = 1;
+
+        let mut unprefixed = *SessionID::from(drone_entity_tag).id();
+        unprefixed[0] = 0;
+
+        let ietf_drip = SessionIDType::IETFDroneRemoteIDProtocol;
+
+        assert_eq!(
+            SessionID::new(ietf_drip, padded).drone_entity_tag(),
+            Err(Error::InvalidSessionID)
+        );
+        assert_eq!(
+            SessionID::new(ietf_drip, unprefixed).drone_entity_tag(),
+            Err(Error::InvalidDroneEntityTag)
+        );
+    }
+
+    #[test]
+    fn test_session_id_hashed_id8() {
+        let hashed_id8 = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        let session_id = SessionID::from_hashed_id8(hashed_id8);
+
+        assert_eq!(
+            session_id.session_id_type(),
+            SessionIDType::IEEE16092HashedID8
+        );
+        assert_eq!(&session_id.id()[8..], &[0u8; 11]);
+        assert_eq!(session_id.hashed_id8(), Ok(hashed_id8));
+        assert_eq!(session_id.drone_entity_tag(), Err(Error::InvalidSessionID));
+    }
+
+    #[test]
+    fn test_session_id_hashed_id8_fails_invalid_padding() {
+        let mut id = [0u8; 19];
+        id[8] = 1;
+
+        let session_id = SessionID::new(SessionIDType::IEEE16092HashedID8, id);
+
+        assert_eq!(session_id.hashed_id8(), Err(Error::InvalidSessionID));
+    }
 }
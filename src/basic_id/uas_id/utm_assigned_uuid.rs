@@ -6,7 +6,14 @@ use crate::try_serialize::TrySerialize;
 /// The format appears to be unspecified, though [`crate::basic_id::BasicID`] message payloads are
 /// always limited to 20 bytes.
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct UTMAssignedUUID([u8; 20]);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct UTMAssignedUUID(
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::hex"))] [u8; 20],
+);
 
 impl UTMAssignedUUID {
     /// Constructs a new UTM Assigned UUID
@@ -0,0 +1,527 @@
+//! # Drone ID Command Line
+//!
+//! Decodes, encodes, and validates Drone ID messages, enabled by the `cli` feature.
+//!
+//! ```text
+//! drone-id decode 0200...              # TOML dump of one hex frame
+//! drone-id decode --json frames.txt    # JSON dump of every frame in a file
+//! drone-id encode message.json         # hex frame from a JSON description
+//! drone-id encode --toml message.toml  # hex frame from a TOML description
+//! drone-id validate capture.bin -b     # report every frame which fails to decode
+//! ```
+//!
+//! Hex input holds one frame per line, whitespace and `:` separators are ignored, as are empty lines
+//! and lines starting with `#`. Binary input holds frames back to back, each frame's length follows
+//! from its header. A frame is one encoded [`Message`], 25 bytes or a pack of `3 + 25 * n` bytes.
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use clap::Subcommand;
+
+use drone_id::error::Error;
+use drone_id::location::Location;
+use drone_id::messages::Message;
+use drone_id::messages::MessageType;
+use drone_id::pack::Pack;
+use drone_id::try_serialize::TrySerialize;
+
+/// Decodes, encodes, and validates ASTM F3411 Drone ID messages.
+#[derive(Parser)]
+#[command(name = "drone-id", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Decodes frames into a TOML, or JSON, dump of the message tree.
+    Decode {
+        /// Hex frame, or path to a file of frames. Reads stdin if omitted.
+        input: Option<String>,
+        /// Input is binary rather than hex.
+        #[arg(short, long)]
+        binary: bool,
+        /// Prints one JSON object per frame.
+        #[arg(short, long)]
+        json: bool,
+    },
+    /// Encodes a JSON or TOML message description into a frame.
+    Encode {
+        /// Path to the description. Reads stdin if omitted.
+        input: Option<PathBuf>,
+        /// Description is TOML rather than JSON.
+        #[arg(short, long)]
+        toml: bool,
+        /// Writes the binary frame rather than hex.
+        #[arg(short, long)]
+        binary: bool,
+    },
+    /// Validates a file of captured frames, reporting each one which fails.
+    Validate {
+        /// Path to the capture. Reads stdin if omitted.
+        input: Option<PathBuf>,
+        /// Capture is binary rather than hex.
+        #[arg(short, long)]
+        binary: bool,
+    },
+}
+
+// frame of a capture along with where it was found, for reporting.
+struct Frame {
+    // one-based line of hex input, or byte offset of binary input.
+    position: usize,
+    bytes: Vec<u8>,
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Decode {
+            input,
+            binary,
+            json,
+        } => decode(input, binary, json),
+        Command::Encode {
+            input,
+            toml,
+            binary,
+        } => encode(input, toml, binary),
+        Command::Validate { input, binary } => validate(input, binary),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}", message);
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn decode(input: Option<String>, binary: bool, json: bool) -> Result<ExitCode, String> {
+    // a lone argument which is not a file is taken as the hex frame itself.
+    let frames = match input {
+        Some(input) if !binary && fs::metadata(&input).is_err() => {
+            vec![Ok(Frame {
+                position: 1,
+                bytes: parse_hex(&input)?,
+            })]
+        }
+        input => read_frames(input.map(PathBuf::from), binary)?,
+    };
+
+    let mut code = ExitCode::SUCCESS;
+
+    for frame in frames {
+        let frame = match frame {
+            Ok(frame) => frame,
+            Err(error) => {
+                eprintln!("{}", error);
+
+                code = ExitCode::FAILURE;
+
+                continue;
+            }
+        };
+
+        let message = match Message::try_from(frame.bytes.as_slice()) {
+            Ok(message) => message,
+            Err(error) => {
+                eprintln!("{}: {}", describe_position(&frame, binary), error);
+
+                code = ExitCode::FAILURE;
+
+                continue;
+            }
+        };
+
+        if json {
+            let dump = serde_json::to_string(&message).map_err(|error| error.to_string())?;
+
+            println!("{}", dump);
+        } else {
+            println!("{}", dump_message(&message)?);
+        }
+    }
+
+    Ok(code)
+}
+
+fn encode(input: Option<PathBuf>, toml: bool, binary: bool) -> Result<ExitCode, String> {
+    let description = String::from_utf8(read_input(input)?).map_err(|error| error.to_string())?;
+
+    let buffer = encode_description(&description, toml)?;
+
+    if binary {
+        io::stdout()
+            .write_all(&buffer)
+            .map_err(|error| error.to_string())?;
+    } else {
+        println!("{}", format_hex(&buffer));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+// encodes a JSON or TOML message description into a frame. a description is written by hand, so
+// values which the encoding would silently clamp, such as a negative speed, are rejected instead.
+fn encode_description(description: &str, toml: bool) -> Result<Vec<u8>, String> {
+    let message: Message = match toml {
+        true => toml::from_str(description).map_err(|error| error.to_string())?,
+        false => serde_json::from_str(description).map_err(|error| error.to_string())?,
+    };
+
+    match message.message_type() {
+        MessageType::Location(location) => check_location(location)?,
+        MessageType::Pack(pack) => {
+            for packed_message in pack {
+                if let MessageType::Location(location) = packed_message
+                    .map_err(|error| error.to_string())?
+                    .message_type()
+                {
+                    check_location(location)?;
+                }
+            }
+        }
+        _ => (),
+    }
+
+    let mut buffer = vec![0u8; message.encoding_byte_length()];
+
+    message
+        .try_serialize(&mut buffer)
+        .map_err(|error| error.to_string())?;
+
+    Ok(buffer)
+}
+
+// rejects a location which cannot be encoded as written.
+fn check_location(location: &Location) -> Result<(), String> {
+    location
+        .validate()
+        .map_err(|error| format!("location: {}", error))
+}
+
+fn validate(input: Option<PathBuf>, binary: bool) -> Result<ExitCode, String> {
+    let frames = read_frames(input, binary)?;
+
+    let mut failures = 0;
+
+    for frame in &frames {
+        let frame = match frame {
+            Ok(frame) => frame,
+            Err(error) => {
+                println!("{}", error);
+
+                failures += 1;
+
+                continue;
+            }
+        };
+
+        let errors = validate_frame(&frame.bytes);
+
+        for (range, error) in &errors {
+            println!(
+                "{}: bytes {}..{} [{}]: {}",
+                describe_position(frame, binary),
+                range.start,
+                range.end,
+                format_hex(&frame.bytes[range.clone()]),
+                error
+            );
+        }
+
+        if !errors.is_empty() {
+            failures += 1;
+        }
+    }
+
+    println!("{} frames, {} failed", frames.len(), failures);
+
+    match failures {
+        0 => Ok(ExitCode::SUCCESS),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
+
+// returns the byte range and error of every part of the frame which fails to decode, that is the
+// whole frame, or each failing message of a pack.
+fn validate_frame(bytes: &[u8]) -> Vec<(std::ops::Range<usize>, Error)> {
+    let message = match Message::try_from(bytes) {
+        Ok(message) => message,
+        Err(error) => return vec![(0..bytes.len(), error)],
+    };
+
+    let MessageType::Pack(pack) = message.message_type() else {
+        return Vec::new();
+    };
+
+    pack.iter()
+        .enumerate()
+        .filter_map(|(index, message)| match message {
+            Ok(_) => None,
+            Err(error) => {
+                let start = 1 + Pack::HEADER_LENGTH + index * Pack::MESSAGES_LENGTH;
+
+                Some((start..start + Pack::MESSAGES_LENGTH, error))
+            }
+        })
+        .collect()
+}
+
+// renders the message tree as TOML, which reads well and encodes back with `encode --toml`.
+fn dump_message(message: &Message) -> Result<String, String> {
+    toml::to_string_pretty(message).map_err(|error| error.to_string())
+}
+
+fn describe_position(frame: &Frame, binary: bool) -> String {
+    match binary {
+        true => format!("offset {}", frame.position),
+        false => format!("line {}", frame.position),
+    }
+}
+
+fn read_input(input: Option<PathBuf>) -> Result<Vec<u8>, String> {
+    match input {
+        Some(path) => fs::read(&path).map_err(|error| format!("{}: {}", path.display(), error)),
+        None => {
+            let mut buffer = Vec::new();
+
+            io::stdin()
+                .read_to_end(&mut buffer)
+                .map_err(|error| error.to_string())?;
+
+            Ok(buffer)
+        }
+    }
+}
+
+// reads every frame of the input, a frame which fails to be read is kept in place as its error.
+fn read_frames(input: Option<PathBuf>, binary: bool) -> Result<Vec<Result<Frame, String>>, String> {
+    let contents = read_input(input)?;
+
+    match binary {
+        true => Ok(split_binary(&contents).into_iter().map(Ok).collect()),
+        false => {
+            let text = String::from_utf8(contents).map_err(|error| error.to_string())?;
+
+            Ok(split_hex(&text))
+        }
+    }
+}
+
+// splits hex text into frames, one per line. a line which is not hex is an error in its place, so
+// the following lines are still read.
+fn split_hex(text: &str) -> Vec<Result<Frame, String>> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(position, line)| {
+            let bytes = parse_hex(line).map_err(|error| format!("line {}: {}", position, error))?;
+
+            Ok(Frame { position, bytes })
+        })
+        .collect()
+}
+
+// splits back to back binary frames by the length their headers imply. a truncated last frame is
+// kept as is, so it is reported rather than dropped.
+fn split_binary(bytes: &[u8]) -> Vec<Frame> {
+    let mut frames = Vec::new();
+
+    let mut position = 0;
+
+    while position < bytes.len() {
+        let remaining = &bytes[position..];
+
+        let length = match remaining[0] >> 4 == Pack::PACK_MESSAGE_CODE {
+            true => match remaining.get(2) {
+                Some(&number_of_messages) => {
+                    1 + Pack::HEADER_LENGTH + number_of_messages as usize * Pack::MESSAGES_LENGTH
+                }
+                None => remaining.len(),
+            },
+            false => Pack::MESSAGES_LENGTH,
+        };
+
+        let end = (position + length).min(bytes.len());
+
+        frames.push(Frame {
+            position,
+            bytes: bytes[position..end].to_vec(),
+        });
+
+        position = end;
+    }
+
+    frames
+}
+
+fn parse_hex(text: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = text
+        .bytes()
+        .filter(|byte| !byte.is_ascii_whitespace() && *byte != b':')
+        .collect();
+
+    let digits = digits.strip_prefix(b"0x").unwrap_or(digits.as_slice());
+
+    if !digits.len().is_multiple_of(2) {
+        return Err("odd number of hex digits".to_string());
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| format!("invalid hex digits {:?}", String::from_utf8_lossy(pair)))
+        })
+        .collect()
+}
+
+fn format_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{:02x}", byte)
+            .map_err(|_| Error::Unreachable)
+            .unwrap();
+
+        hex
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use drone_id::{
+        error::Error,
+        location::{GroundSpeed, Location, VerticalSpeed},
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        try_serialize::TrySerialize,
+    };
+
+    use crate::{
+        encode_description, format_hex, parse_hex, split_binary, split_hex, validate_frame,
+    };
+
+    fn encode(message: &Message) -> Vec<u8> {
+        let mut buffer = vec![0u8; message.encoding_byte_length()];
+        message.try_serialize(&mut buffer).unwrap();
+
+        buffer
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("0x0a ff:10").unwrap(), vec![0x0a, 0xff, 0x10]);
+        assert_eq!(format_hex(&[0x0a, 0xff, 0x10]), "0aff10");
+        assert!(parse_hex("abc").is_err());
+        assert!(parse_hex("zz").is_err());
+    }
+
+    #[test]
+    fn test_split_hex() {
+        let frames = split_hex("# capture\n\n0102\n  0304  \nzz\n0506\n");
+
+        assert_eq!(frames.len(), 4);
+
+        let frame = frames[0].as_ref().unwrap();
+        assert_eq!(frame.position, 3);
+        assert_eq!(frame.bytes, vec![1, 2]);
+
+        let frame = frames[1].as_ref().unwrap();
+        assert_eq!(frame.position, 4);
+        assert_eq!(frame.bytes, vec![3, 4]);
+
+        assert!(matches!(&frames[2], Err(error) if error.starts_with("line 5: ")));
+
+        let frame = frames[3].as_ref().unwrap();
+        assert_eq!(frame.position, 6);
+        assert_eq!(frame.bytes, vec![5, 6]);
+    }
+
+    #[test]
+    fn test_split_binary() {
+        let operator_id = Message::from(OperatorID::new(OperatorIDType::OperatorID, [b'A'; 20]));
+
+        let message = encode(&operator_id);
+        let pack = encode(&Message::from(
+            Pack::try_from([operator_id, operator_id]).unwrap(),
+        ));
+
+        let mut capture = Vec::new();
+        capture.extend_from_slice(&message);
+        capture.extend_from_slice(&pack);
+        capture.extend_from_slice(&message[..10]);
+
+        let frames = split_binary(&capture);
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].bytes, message);
+        assert_eq!(frames[1].position, 25);
+        assert_eq!(frames[1].bytes, pack);
+        assert_eq!(frames[2].bytes.len(), 10);
+    }
+
+    #[test]
+    fn test_validate_frame() {
+        let operator_id = Message::from(OperatorID::new(OperatorIDType::OperatorID, [b'A'; 20]));
+
+        let message = encode(&operator_id);
+
+        assert!(validate_frame(&message).is_empty());
+        assert_eq!(
+            validate_frame(&message[..24]),
+            vec![(0..24, Error::InvalidDataLength)]
+        );
+
+        let mut pack = encode(&Message::from(
+            Pack::try_from([operator_id, operator_id]).unwrap(),
+        ));
+
+        // second packed message claims an unsupported protocol version.
+        pack[28] = 0x53;
+
+        assert_eq!(
+            validate_frame(&pack),
+            vec![(28..53, Error::InvalidProtocolVersion)]
+        );
+    }
+
+    #[test]
+    fn test_encode_description_rejects_out_of_range() {
+        let location = |speed, vertical_speed| {
+            let location = Location::builder().build().unwrap();
+
+            let mut description = serde_json::to_value(Message::from(location)).unwrap();
+
+            let fields = &mut description["message_type"]["Location"];
+            fields["speed"] = serde_json::to_value(GroundSpeed::Known(speed)).unwrap();
+            fields["vertical_speed"] =
+                serde_json::to_value(VerticalSpeed::Known(vertical_speed)).unwrap();
+
+            description.to_string()
+        };
+
+        assert_eq!(
+            encode_description(&location(-5.0, -3.0), false),
+            Err(String::from("location: value out of range"))
+        );
+        assert_eq!(
+            encode_description(&location(5.0, -3.0), false)
+                .unwrap()
+                .len(),
+            25
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::error::Error;
+use crate::location::Latitude;
+use crate::location::Location;
+use crate::location::Longitude;
+use crate::location::OperationalStatus;
+use crate::location::Timestamp;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::system::System;
+
+// elements required by a regulation, recorded from each message of a capture.
+pub(crate) trait Record: Copy {
+    // records a message received at `time`, in a capture which started at `start`.
+    fn record(&mut self, message_type: &MessageType, start: u64, time: u64);
+
+    // closes the rates of the capture at `end`.
+    fn finish(self, end: u64) -> Self;
+}
+
+// span of a capture, from the first message of any type to the last one, shared by the validators
+// of every regulation, which only record the elements they require.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct Capture<R> {
+    start: Option<u64>,
+    end: u64,
+    record: R,
+}
+
+impl<R: Record> Capture<R> {
+    pub(crate) fn new(record: R) -> Self {
+        Self {
+            start: None,
+            end: 0,
+            record,
+        }
+    }
+
+    // records a message received at `time`, unpacking a pack into the messages it carries. a
+    // malformed pack is not partially recorded.
+    pub(crate) fn update(&mut self, message: &Message, time: u64) -> Result<(), Error> {
+        let packed_messages = match message.message_type() {
+            MessageType::Pack(pack) => Some(pack.try_decode_all()?),
+            _ => None,
+        };
+
+        let start = *self.start.get_or_insert(time);
+
+        self.end = self.end.max(time);
+
+        match packed_messages {
+            Some(packed_messages) => {
+                for packed_message in packed_messages {
+                    self.record
+                        .record(packed_message.message_type(), start, time);
+                }
+            }
+            None => self.record.record(message.message_type(), start, time),
+        }
+
+        Ok(())
+    }
+
+    // the record so far, its rates closed at the last message of the capture.
+    pub(crate) fn finish(&self) -> R {
+        self.record.finish(self.end)
+    }
+}
+
+// true if the UA latitude and longitude are both known.
+pub(crate) fn has_ua_position(location: &Location) -> bool {
+    matches!(
+        (location.latitude(), location.longitude()),
+        (Latitude::Known(_), Longitude::Known(_))
+    )
+}
+
+// true if the location carries a known timestamp.
+pub(crate) fn has_time_mark(location: &Location) -> bool {
+    matches!(location.timestamp(), Timestamp::Known(_))
+}
+
+// true if the operational status is declared, an emergency or not.
+pub(crate) fn has_emergency_status(location: &Location) -> bool {
+    !matches!(
+        location.operational_status(),
+        OperationalStatus::Undeclared | OperationalStatus::Reserved(_)
+    )
+}
+
+// true if the operator latitude and longitude are both known.
+pub(crate) fn has_operator_position(system: &System) -> bool {
+    matches!(
+        (system.operator_latitude(), system.operator_longitude()),
+        (Latitude::Known(_), Longitude::Known(_))
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::{
+        basic_id::{BasicID, SerialNumber, UASID, UAType},
+        location::{
+            Altitude, GroundSpeed, HeightType, Latitude, Location, Longitude, OperationalStatus,
+            Timestamp, TrackDirection, VerticalSpeed,
+        },
+        messages::Message,
+        system::{ClassificationType, System, UAClassification},
+    };
+
+    // a basic id carrying a serial number, which identifies the UA under every regulation.
+    pub(crate) fn basic_id() -> Message {
+        let serial_number = SerialNumber::try_new("MFR1", "12345").unwrap();
+
+        Message::from(BasicID::new(
+            UAType::Helicopter,
+            UASID::SerialNumber(serial_number),
+        ))
+    }
+
+    // a location carrying every element required by any regulation.
+    pub(crate) fn location() -> Location {
+        Location::builder()
+            .operational_status(OperationalStatus::Airborne)
+            .track_direction(TrackDirection::Known(90))
+            .speed(GroundSpeed::Known(5.0))
+            .vertical_speed(VerticalSpeed::Known(0.0))
+            .latitude(Latitude::Known(60.17))
+            .longitude(Longitude::Known(24.94))
+            .geodetic_altitude(Altitude::Known(100.0))
+            .height(Altitude::Known(40.0))
+            .height_type(HeightType::TakeOff)
+            .timestamp(Timestamp::Known(100))
+            .build()
+            .unwrap()
+    }
+
+    // a system carrying every element required by any regulation, declaring the given
+    // classification in the european union.
+    pub(crate) fn system(ua_classification: UAClassification) -> System {
+        System::builder()
+            .classification_type(ClassificationType::EuropeanUnion)
+            .ua_classification(ua_classification)
+            .operator_latitude(Latitude::Known(60.17))
+            .operator_longitude(Longitude::Known(24.941))
+            .operator_altitude(Altitude::Known(20.0))
+            .timestamp(1_000.into())
+            .build()
+            .unwrap()
+    }
+
+    // broadcasts `every_second` once per second over `seconds` seconds, and `every_third_second`
+    // every third second from the first one.
+    pub(crate) fn broadcast(
+        seconds: u64,
+        every_second: &[Message],
+        every_third_second: &[Message],
+        mut update: impl FnMut(&Message, u64),
+    ) {
+        for second in 0..seconds {
+            let time = second * 1_000;
+
+            if second % 3 == 0 {
+                for message in every_third_second {
+                    update(message, time);
+                }
+            }
+
+            for message in every_second {
+                update(message, time);
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! ### EU Direct Remote Identification
+//!
+//! Checks a capture against the message elements of a direct remote identification UA under
+//! Delegated Regulation (EU) 2019/945, as broadcast through the EN 4709-002 means of compliance.
+//!
+//! - the UA serial number, a [`BasicID`](crate::basic_id::BasicID) carrying a
+//!   [`SerialNumber`](crate::basic_id::SerialNumber).
+//! - the operator registration number, an [`OperatorID`](crate::operator_id::OperatorID) carrying
+//!   the public part of an EU operator registration number, for example `FIN87astrdge12k8`.
+//! - the UA latitude and longitude, and its height above the take-off point, from the
+//!   [`Location`](crate::location::Location).
+//! - the UA route course and ground speed.
+//! - the time mark, the [`Location`](crate::location::Location) timestamp.
+//! - the emergency status, a declared
+//!   [`OperationalStatus`](crate::location::OperationalStatus).
+//! - the operator latitude and longitude, from the [`System`](crate::system::System).
+//! - the UA category and class, a [`System`](crate::system::System) declaring
+//!   [`ClassificationType::EuropeanUnion`](crate::system::ClassificationType::EuropeanUnion).
+//!
+//! The Location message must be received at least once per second, see
+//! [`Validator::LOCATION_INTERVAL`], and the Basic ID, Operator ID, and System messages at least
+//! every three seconds.
+//!
+//! The check character of a registration number covers its three secret characters, which are never
+//! broadcast, so the checksum is only verified by a validator given them, see
+//! [`Validator::with_secret`].
+//!
+//! ```rust
+//! use drone_id::compliance::eu;
+//! # use drone_id::messages::Message;
+//! # let capture: [(u64, Message); 0] = [];
+//!
+//! let report = eu::validate(capture.iter().map(|(time, message)| (message, *time))).unwrap();
+//!
+//! if !report.is_compliant() {
+//!     let operator_id = report.operator_id();
+//! }
+//! ```
+mod validator;
+
+pub use validator::Validator;
+
+use crate::compliance::Rate;
+use crate::compliance::Status;
+use crate::error::Error;
+use crate::messages::Message;
+
+/// EU Direct Remote Identification Report
+///
+/// The [`Status`] of each required message element, and the [`Rate`] of each required message type
+/// over the capture, see [`Validator::report`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Report {
+    uas_id: Status,
+    operator_id: Status,
+    operator_id_checksum: Option<bool>,
+    ua_position: Status,
+    ua_height: Status,
+    ua_velocity: Status,
+    time_mark: Status,
+    emergency_status: Status,
+    operator_position: Status,
+    classification: Status,
+    classification_region_matches: Option<bool>,
+    basic_id_rate: Rate,
+    operator_id_rate: Rate,
+    location_rate: Rate,
+    system_rate: Rate,
+}
+
+impl Report {
+    /// Returns the status of the UA serial number.
+    pub fn uas_id(&self) -> Status {
+        self.uas_id
+    }
+
+    /// Returns the status of the operator registration number, unknown while it is not shaped as
+    /// the public part of an EU operator registration number.
+    pub fn operator_id(&self) -> Status {
+        self.operator_id
+    }
+
+    /// Returns true if every registration number matches its check character, false if one does
+    /// not.
+    ///
+    /// Returns `None` if the validator was not given the secret characters, or if no well formed
+    /// registration number was received.
+    pub fn operator_id_checksum(&self) -> Option<bool> {
+        self.operator_id_checksum
+    }
+
+    /// Returns the status of the UA latitude and longitude.
+    pub fn ua_position(&self) -> Status {
+        self.ua_position
+    }
+
+    /// Returns the status of the UA height, unknown while it is not measured above the take-off
+    /// point, see [`HeightType::TakeOff`](crate::location::HeightType::TakeOff).
+    pub fn ua_height(&self) -> Status {
+        self.ua_height
+    }
+
+    /// Returns the status of the UA ground speed and track direction.
+    pub fn ua_velocity(&self) -> Status {
+        self.ua_velocity
+    }
+
+    /// Returns the status of the time mark.
+    pub fn time_mark(&self) -> Status {
+        self.time_mark
+    }
+
+    /// Returns the status of the emergency status, unknown while it is undeclared.
+    pub fn emergency_status(&self) -> Status {
+        self.emergency_status
+    }
+
+    /// Returns the status of the operator latitude and longitude.
+    pub fn operator_position(&self) -> Status {
+        self.operator_position
+    }
+
+    /// Returns the status of the UA category and class, unknown while the classification is not
+    /// declared as [`ClassificationType::EuropeanUnion`](crate::system::ClassificationType) with a
+    /// category, or with a class from C0 to C6 in the open category.
+    pub fn classification(&self) -> Status {
+        self.classification
+    }
+
+    /// Returns true if every UA category or class is declared as
+    /// [`ClassificationType::EuropeanUnion`](crate::system::ClassificationType), false if one is
+    /// declared under another classification type, for example undeclared.
+    ///
+    /// Returns `None` if no System message declared a category or class.
+    pub fn classification_region_matches(&self) -> Option<bool> {
+        self.classification_region_matches
+    }
+
+    /// Returns the rate of the Basic ID messages carrying the UA serial number.
+    pub fn basic_id_rate(&self) -> Rate {
+        self.basic_id_rate
+    }
+
+    /// Returns the rate of the Operator ID messages.
+    pub fn operator_id_rate(&self) -> Rate {
+        self.operator_id_rate
+    }
+
+    /// Returns the rate of the Location messages.
+    pub fn location_rate(&self) -> Rate {
+        self.location_rate
+    }
+
+    /// Returns the rate of the System messages.
+    pub fn system_rate(&self) -> Rate {
+        self.system_rate
+    }
+
+    /// Returns true if every element is present, no registration number fails its checksum, no
+    /// category or class is declared under another classification type, and no rate is violated.
+    pub fn is_compliant(&self) -> bool {
+        let statuses = [
+            self.uas_id,
+            self.operator_id,
+            self.ua_position,
+            self.ua_height,
+            self.ua_velocity,
+            self.time_mark,
+            self.emergency_status,
+            self.operator_position,
+            self.classification,
+        ];
+
+        let rates = [
+            self.basic_id_rate,
+            self.operator_id_rate,
+            self.location_rate,
+            self.system_rate,
+        ];
+
+        statuses.iter().all(Status::is_present)
+            && self.operator_id_checksum != Some(false)
+            && self.classification_region_matches != Some(false)
+            && !rates.iter().any(Rate::is_violated)
+    }
+}
+
+/// Validates a capture of messages, each paired with the time at which it was received.
+///
+/// The operator registration number checksum is not verified, see [`Validator::with_secret`].
+///
+/// Returns an error if:
+///
+/// - a message is a pack containing a message which fails to be decoded.
+pub fn validate<'a>(
+    messages: impl IntoIterator<Item = (&'a Message, u64)>,
+) -> Result<Report, Error> {
+    let mut validator = Validator::new();
+
+    for (message, time) in messages {
+        validator.update(message, time)?;
+    }
+
+    Ok(validator.report())
+}
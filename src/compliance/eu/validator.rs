@@ -0,0 +1,417 @@
+use crate::basic_id::BasicID;
+use crate::basic_id::UASID;
+use crate::compliance::Rate;
+use crate::compliance::Status;
+use crate::compliance::capture;
+use crate::compliance::capture::Capture;
+use crate::compliance::capture::Record;
+use crate::compliance::eu::Report;
+use crate::error::Error;
+use crate::location::Altitude;
+use crate::location::GroundSpeed;
+use crate::location::HeightType;
+use crate::location::Location;
+use crate::location::TrackDirection;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::operator_id::EuOperatorId;
+use crate::operator_id::OperatorID;
+use crate::operator_id::OperatorIDType;
+use crate::system::ClassificationType;
+use crate::system::OpenClassification;
+use crate::system::System;
+use crate::system::UAClassification;
+
+/// EU Direct Remote Identification Validator
+///
+/// Accumulates the messages of a capture one at a time, see [`Validator::update`], and reports on
+/// them at any point through [`Validator::report`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Validator {
+    capture: Capture<Recorder>,
+}
+
+impl Validator {
+    /// Maximum interval in milliseconds between two Basic ID messages.
+    pub const BASIC_ID_INTERVAL: u64 = 3_000;
+
+    /// Maximum interval in milliseconds between two Operator ID messages.
+    pub const OPERATOR_ID_INTERVAL: u64 = 3_000;
+
+    /// Maximum interval in milliseconds between two Location messages.
+    pub const LOCATION_INTERVAL: u64 = 1_000;
+
+    /// Maximum interval in milliseconds between two System messages.
+    pub const SYSTEM_INTERVAL: u64 = 3_000;
+
+    /// Constructs a new validator, with an empty capture.
+    pub fn new() -> Self {
+        Self::with_optional_secret(None)
+    }
+
+    /// Constructs a new validator, with an empty capture, verifying the checksum of the operator
+    /// registration number against its three secret characters, for example `b"xyz"` for
+    /// `FIN87astrdge12k8-xyz`, see [`EuOperatorId::secret_part`].
+    ///
+    /// Secret characters which are not alphanumeric fail every checksum.
+    pub fn with_secret(secret: [u8; 3]) -> Self {
+        Self::with_optional_secret(Some(secret))
+    }
+
+    // verifies registration number checksums only when given the secret characters.
+    fn with_optional_secret(secret: Option<[u8; 3]>) -> Self {
+        Self {
+            capture: Capture::new(Recorder {
+                secret,
+                report: Report {
+                    uas_id: Status::Missing,
+                    operator_id: Status::Missing,
+                    operator_id_checksum: None,
+                    ua_position: Status::Missing,
+                    ua_height: Status::Missing,
+                    ua_velocity: Status::Missing,
+                    time_mark: Status::Missing,
+                    emergency_status: Status::Missing,
+                    operator_position: Status::Missing,
+                    classification: Status::Missing,
+                    classification_region_matches: None,
+                    basic_id_rate: Rate::new(Self::BASIC_ID_INTERVAL),
+                    operator_id_rate: Rate::new(Self::OPERATOR_ID_INTERVAL),
+                    location_rate: Rate::new(Self::LOCATION_INTERVAL),
+                    system_rate: Rate::new(Self::SYSTEM_INTERVAL),
+                },
+            }),
+        }
+    }
+
+    /// Records a message received at the given time.
+    ///
+    /// [`Pack`](crate::pack::Pack) messages are unpacked and each message inside of them is
+    /// recorded at the same time.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `message` is a pack containing a message which fails to be decoded.
+    pub fn update(&mut self, message: &Message, time: u64) -> Result<(), Error> {
+        self.capture.update(message, time)
+    }
+
+    /// Returns the report of the capture so far.
+    pub fn report(&self) -> Report {
+        self.capture.finish().report
+    }
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the report, along with the secret characters its registration number checksums are verified
+// against.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Recorder {
+    secret: Option<[u8; 3]>,
+    report: Report,
+}
+
+// the elements required of a direct remote identification UA, in each message type.
+impl Record for Recorder {
+    fn record(&mut self, message_type: &MessageType, start: u64, time: u64) {
+        let report = &mut self.report;
+
+        match message_type {
+            MessageType::BasicID(basic_id) => report.record_basic_id(basic_id, start, time),
+            MessageType::OperatorID(operator_id) => {
+                report.record_operator_id(operator_id, self.secret, start, time)
+            }
+            MessageType::Location(location) => report.record_location(location, start, time),
+            MessageType::System(system) => report.record_system(system, start, time),
+            _ => (),
+        }
+    }
+
+    fn finish(self, end: u64) -> Self {
+        let report = self.report;
+
+        Self {
+            report: Report {
+                basic_id_rate: report.basic_id_rate.finish(end),
+                operator_id_rate: report.operator_id_rate.finish(end),
+                location_rate: report.location_rate.finish(end),
+                system_rate: report.system_rate.finish(end),
+                ..report
+            },
+            ..self
+        }
+    }
+}
+
+impl Report {
+    // only a basic id carrying a serial number identifies the UA, other identifiers may be
+    // broadcast alongside but do not count towards the rate.
+    fn record_basic_id(&mut self, basic_id: &BasicID, start: u64, time: u64) {
+        if let UASID::SerialNumber(_) = basic_id.uas_id() {
+            self.uas_id = self.uas_id.observe(true);
+            self.basic_id_rate.record(start, time);
+        }
+    }
+
+    // the checksum of a malformed registration number is not verified, it is already unknown.
+    fn record_operator_id(
+        &mut self,
+        operator_id: &OperatorID,
+        secret: Option<[u8; 3]>,
+        start: u64,
+        time: u64,
+    ) {
+        let well_formed = operator_id.operator_id_type() == OperatorIDType::OperatorID
+            && EuOperatorId::is_broadcast_id(operator_id.id());
+
+        self.operator_id = self.operator_id.observe(well_formed);
+
+        if let (true, Some(secret)) = (well_formed, secret) {
+            let valid = EuOperatorId::is_valid_broadcast_id(operator_id.id(), &secret);
+
+            self.operator_id_checksum = Some(self.operator_id_checksum.unwrap_or(true) && valid);
+        }
+
+        self.operator_id_rate.record(start, time);
+    }
+
+    // the height is broadcast above the take-off point.
+    fn record_location(&mut self, location: &Location, start: u64, time: u64) {
+        self.ua_position = self.ua_position.observe(capture::has_ua_position(location));
+
+        self.ua_height = self.ua_height.observe(matches!(
+            (location.height_type(), location.height()),
+            (HeightType::TakeOff, Altitude::Known(_))
+        ));
+
+        self.ua_velocity = self.ua_velocity.observe(matches!(
+            (location.speed(), location.track_direction()),
+            (GroundSpeed::Known(_), TrackDirection::Known(_))
+        ));
+
+        self.time_mark = self.time_mark.observe(capture::has_time_mark(location));
+
+        self.emergency_status = self
+            .emergency_status
+            .observe(capture::has_emergency_status(location));
+
+        self.location_rate.record(start, time);
+    }
+
+    fn record_system(&mut self, system: &System, start: u64, time: u64) {
+        self.operator_position = self
+            .operator_position
+            .observe(capture::has_operator_position(system));
+
+        let declared = matches!(
+            system.ua_classification(),
+            UAClassification::Open(
+                OpenClassification::Class0
+                    | OpenClassification::Class1
+                    | OpenClassification::Class2
+                    | OpenClassification::Class3
+                    | OpenClassification::Class4
+                    | OpenClassification::Class5
+                    | OpenClassification::Class6
+            ) | UAClassification::Specific
+                | UAClassification::Certified
+        );
+
+        let european_union = system.classification_type() == ClassificationType::EuropeanUnion;
+
+        self.classification = self.classification.observe(declared && european_union);
+
+        // a category or class declared under another classification type is a mismatch rather
+        // than a missing classification.
+        if declared {
+            self.classification_region_matches =
+                Some(self.classification_region_matches.unwrap_or(true) && european_union);
+        }
+
+        self.system_rate.record(start, time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compliance::{
+            Status,
+            capture::fixtures::{self, basic_id, location, system},
+            eu::{self, Validator},
+        },
+        location::{Altitude, HeightType, Latitude, Location, Longitude},
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        system::{
+            AreaCount, ClassificationType, OpenClassification, OperatingAreaRadius,
+            OperatorLocationSourceType, System, UAClassification,
+        },
+    };
+
+    fn operator_id(number: &str) -> Message {
+        let mut id = [0u8; 20];
+
+        id[..number.len()].copy_from_slice(number.as_bytes());
+
+        Message::from(OperatorID::new(OperatorIDType::OperatorID, id))
+    }
+
+    fn class1() -> UAClassification {
+        UAClassification::Open(OpenClassification::Class1)
+    }
+
+    // one second of broadcast, the static messages every third second.
+    fn validate_seconds(validator: &mut Validator, seconds: u64, system: System) -> eu::Report {
+        fixtures::broadcast(
+            seconds,
+            &[location().into()],
+            &[basic_id(), operator_id("FIN87astrdge12k8"), system.into()],
+            |message, time| validator.update(message, time).unwrap(),
+        );
+
+        validator.report()
+    }
+
+    #[test]
+    fn test_compliant() {
+        let report = validate_seconds(&mut Validator::new(), 10, system(class1()));
+
+        assert!(report.is_compliant());
+        assert_eq!(report.operator_id(), Status::Present);
+        assert_eq!(report.operator_id_checksum(), None);
+        assert_eq!(report.operator_id_rate().max_interval(), Some(3_000));
+        assert_eq!(report.location_rate().max_interval(), Some(1_000));
+    }
+
+    #[test]
+    fn test_validate_unpacks() {
+        let pack = Message::from(
+            Pack::try_from([
+                basic_id(),
+                operator_id("FIN87astrdge12k8"),
+                location().into(),
+                system(UAClassification::Specific).into(),
+            ])
+            .unwrap(),
+        );
+
+        let messages = [(pack, 0), (pack, 1_000)];
+
+        let report = eu::validate(messages.iter().map(|(message, time)| (message, *time))).unwrap();
+
+        assert!(report.is_compliant());
+        assert_eq!(report.operator_id_rate().count(), 2);
+    }
+
+    #[test]
+    fn test_missing_operator_id() {
+        let mut validator = Validator::new();
+
+        validator.update(&basic_id(), 0).unwrap();
+        validator.update(&Message::from(location()), 0).unwrap();
+        validator
+            .update(&Message::from(system(class1())), 0)
+            .unwrap();
+
+        let report = validator.report();
+
+        assert!(!report.is_compliant());
+        assert_eq!(report.operator_id(), Status::Missing);
+        assert!(report.operator_id_rate().is_violated());
+        assert_eq!(report.classification(), Status::Present);
+    }
+
+    #[test]
+    fn test_malformed_operator_id() {
+        let mut validator = Validator::new();
+
+        validator
+            .update(&operator_id("FIN87astrdge12k8"), 0)
+            .unwrap();
+        validator.update(&operator_id("N123456"), 1_000).unwrap();
+
+        assert_eq!(validator.report().operator_id(), Status::Unknown);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let valid = validate_seconds(&mut Validator::with_secret(*b"xyz"), 3, system(class1()));
+
+        let invalid = validate_seconds(&mut Validator::with_secret(*b"xya"), 3, system(class1()));
+
+        assert!(valid.is_compliant());
+        assert_eq!(valid.operator_id_checksum(), Some(true));
+        assert!(!invalid.is_compliant());
+        assert_eq!(invalid.operator_id_checksum(), Some(false));
+        assert_eq!(invalid.operator_id(), Status::Present);
+    }
+
+    #[test]
+    fn test_height_above_ground() {
+        let location = Location::builder()
+            .height(Altitude::Known(40.0))
+            .height_type(HeightType::AGL)
+            .build()
+            .unwrap();
+
+        let mut validator = Validator::new();
+
+        validator.update(&Message::from(location), 0).unwrap();
+
+        assert_eq!(validator.report().ua_height(), Status::Unknown);
+    }
+
+    #[test]
+    fn test_classification() {
+        let undeclared = System::builder()
+            .operator_latitude(Latitude::Known(60.17))
+            .operator_longitude(Longitude::Known(24.941))
+            .timestamp(1_000.into())
+            .build()
+            .unwrap();
+
+        let unclassed = system(UAClassification::Open(OpenClassification::Undefined));
+
+        for system in [undeclared, unclassed, system(UAClassification::Undefined)] {
+            let report = validate_seconds(&mut Validator::new(), 3, system);
+
+            assert!(!report.is_compliant());
+            assert_eq!(report.classification(), Status::Unknown);
+            assert_eq!(report.classification_region_matches(), None);
+        }
+
+        let report = validate_seconds(&mut Validator::new(), 3, system(class1()));
+
+        assert_eq!(report.classification_region_matches(), Some(true));
+    }
+
+    #[test]
+    fn test_classification_region_mismatch() {
+        let system = System::new(
+            ClassificationType::Undeclared,
+            OperatorLocationSourceType::TakeOff,
+            Latitude::Known(60.17),
+            Longitude::Known(24.941),
+            AreaCount::try_from(1).unwrap(),
+            OperatingAreaRadius::try_new(0).unwrap(),
+            Altitude::Unknown,
+            Altitude::Unknown,
+            class1(),
+            Altitude::Unknown,
+            1_000.into(),
+        );
+
+        let report = validate_seconds(&mut Validator::new(), 3, system);
+
+        assert!(!report.is_compliant());
+        assert_eq!(report.classification(), Status::Unknown);
+        assert_eq!(report.classification_region_matches(), Some(false));
+    }
+}
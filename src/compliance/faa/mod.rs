@@ -0,0 +1,158 @@
+//! ### FAA Standard Remote ID
+//!
+//! Checks a capture against the message elements of a standard remote identification UA under 14
+//! CFR Part 89, as broadcast through the ASTM F3411 means of compliance.
+//!
+//! - the UA identification, a [`BasicID`](crate::basic_id::BasicID) carrying a
+//!   [`SerialNumber`](crate::basic_id::SerialNumber) or a [`SessionID`](crate::basic_id::SessionID).
+//! - the UA latitude, longitude, and geodetic altitude, from the
+//!   [`Location`](crate::location::Location).
+//! - the UA velocity, that is the ground speed, track direction, and vertical speed.
+//! - the time mark, the [`Location`](crate::location::Location) timestamp.
+//! - the emergency status, a declared
+//!   [`OperationalStatus`](crate::location::OperationalStatus).
+//! - the operator latitude, longitude, and geodetic altitude, from the
+//!   [`System`](crate::system::System).
+//! - the time mark of the operator location, the [`System`](crate::system::System) timestamp.
+//!
+//! The Location and System messages must be received at least once per second, see
+//! [`Validator::LOCATION_INTERVAL`] and [`Validator::SYSTEM_INTERVAL`], and the Basic ID at least
+//! every three seconds, see [`Validator::BASIC_ID_INTERVAL`].
+//!
+//! ```rust
+//! use drone_id::compliance::faa;
+//! # use drone_id::messages::Message;
+//! # let capture: [(u64, Message); 0] = [];
+//!
+//! let report = faa::validate(capture.iter().map(|(time, message)| (message, *time))).unwrap();
+//!
+//! if !report.is_compliant() {
+//!     let location_rate = report.location_rate();
+//! }
+//! ```
+mod validator;
+
+pub use validator::Validator;
+
+use crate::compliance::Rate;
+use crate::compliance::Status;
+use crate::error::Error;
+use crate::messages::Message;
+
+/// FAA Standard Remote ID Report
+///
+/// The [`Status`] of each required message element, and the [`Rate`] of each required message type
+/// over the capture, see [`Validator::report`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Report {
+    uas_id: Status,
+    ua_position: Status,
+    ua_altitude: Status,
+    ua_velocity: Status,
+    time_mark: Status,
+    emergency_status: Status,
+    operator_position: Status,
+    operator_altitude: Status,
+    system_timestamp: Status,
+    basic_id_rate: Rate,
+    location_rate: Rate,
+    system_rate: Rate,
+}
+
+impl Report {
+    /// Returns the status of the UA identification, a serial number or session ID.
+    pub fn uas_id(&self) -> Status {
+        self.uas_id
+    }
+
+    /// Returns the status of the UA latitude and longitude.
+    pub fn ua_position(&self) -> Status {
+        self.ua_position
+    }
+
+    /// Returns the status of the UA geodetic altitude.
+    pub fn ua_altitude(&self) -> Status {
+        self.ua_altitude
+    }
+
+    /// Returns the status of the UA ground speed, track direction, and vertical speed.
+    pub fn ua_velocity(&self) -> Status {
+        self.ua_velocity
+    }
+
+    /// Returns the status of the time mark.
+    pub fn time_mark(&self) -> Status {
+        self.time_mark
+    }
+
+    /// Returns the status of the emergency status, unknown while it is undeclared.
+    pub fn emergency_status(&self) -> Status {
+        self.emergency_status
+    }
+
+    /// Returns the status of the operator latitude and longitude.
+    pub fn operator_position(&self) -> Status {
+        self.operator_position
+    }
+
+    /// Returns the status of the operator geodetic altitude.
+    pub fn operator_altitude(&self) -> Status {
+        self.operator_altitude
+    }
+
+    /// Returns the status of the System timestamp, unknown while it is zero.
+    pub fn system_timestamp(&self) -> Status {
+        self.system_timestamp
+    }
+
+    /// Returns the rate of the Basic ID messages carrying the UA identification.
+    pub fn basic_id_rate(&self) -> Rate {
+        self.basic_id_rate
+    }
+
+    /// Returns the rate of the Location messages.
+    pub fn location_rate(&self) -> Rate {
+        self.location_rate
+    }
+
+    /// Returns the rate of the System messages.
+    pub fn system_rate(&self) -> Rate {
+        self.system_rate
+    }
+
+    /// Returns true if every element is present and no rate is violated.
+    pub fn is_compliant(&self) -> bool {
+        let statuses = [
+            self.uas_id,
+            self.ua_position,
+            self.ua_altitude,
+            self.ua_velocity,
+            self.time_mark,
+            self.emergency_status,
+            self.operator_position,
+            self.operator_altitude,
+            self.system_timestamp,
+        ];
+
+        let rates = [self.basic_id_rate, self.location_rate, self.system_rate];
+
+        statuses.iter().all(Status::is_present) && !rates.iter().any(Rate::is_violated)
+    }
+}
+
+/// Validates a capture of messages, each paired with the time at which it was received.
+///
+/// Returns an error if:
+///
+/// - a message is a pack containing a message which fails to be decoded.
+pub fn validate<'a>(
+    messages: impl IntoIterator<Item = (&'a Message, u64)>,
+) -> Result<Report, Error> {
+    let mut validator = Validator::new();
+
+    for (message, time) in messages {
+        validator.update(message, time)?;
+    }
+
+    Ok(validator.report())
+}
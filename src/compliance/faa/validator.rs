@@ -0,0 +1,297 @@
+use crate::basic_id::BasicID;
+use crate::basic_id::UASID;
+use crate::compliance::Rate;
+use crate::compliance::Status;
+use crate::compliance::capture;
+use crate::compliance::capture::Capture;
+use crate::compliance::capture::Record;
+use crate::compliance::faa::Report;
+use crate::error::Error;
+use crate::location::Altitude;
+use crate::location::GroundSpeed;
+use crate::location::Location;
+use crate::location::TrackDirection;
+use crate::location::VerticalSpeed;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::system::System;
+
+/// FAA Standard Remote ID Validator
+///
+/// Accumulates the messages of a capture one at a time, see [`Validator::update`], and reports on
+/// them at any point through [`Validator::report`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Validator {
+    capture: Capture<Report>,
+}
+
+impl Validator {
+    /// Maximum interval in milliseconds between two Basic ID messages.
+    pub const BASIC_ID_INTERVAL: u64 = 3_000;
+
+    /// Maximum interval in milliseconds between two Location messages.
+    pub const LOCATION_INTERVAL: u64 = 1_000;
+
+    /// Maximum interval in milliseconds between two System messages.
+    pub const SYSTEM_INTERVAL: u64 = 1_000;
+
+    /// Constructs a new validator, with an empty capture.
+    pub fn new() -> Self {
+        Self {
+            capture: Capture::new(Report {
+                uas_id: Status::Missing,
+                ua_position: Status::Missing,
+                ua_altitude: Status::Missing,
+                ua_velocity: Status::Missing,
+                time_mark: Status::Missing,
+                emergency_status: Status::Missing,
+                operator_position: Status::Missing,
+                operator_altitude: Status::Missing,
+                system_timestamp: Status::Missing,
+                basic_id_rate: Rate::new(Self::BASIC_ID_INTERVAL),
+                location_rate: Rate::new(Self::LOCATION_INTERVAL),
+                system_rate: Rate::new(Self::SYSTEM_INTERVAL),
+            }),
+        }
+    }
+
+    /// Records a message received at the given time.
+    ///
+    /// [`Pack`](crate::pack::Pack) messages are unpacked and each message inside of them is
+    /// recorded at the same time.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `message` is a pack containing a message which fails to be decoded.
+    pub fn update(&mut self, message: &Message, time: u64) -> Result<(), Error> {
+        self.capture.update(message, time)
+    }
+
+    /// Returns the report of the capture so far.
+    pub fn report(&self) -> Report {
+        self.capture.finish()
+    }
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the elements required of a standard remote id UA, in each message type.
+impl Record for Report {
+    fn record(&mut self, message_type: &MessageType, start: u64, time: u64) {
+        match message_type {
+            MessageType::BasicID(basic_id) => self.record_basic_id(basic_id, start, time),
+            MessageType::Location(location) => self.record_location(location, start, time),
+            MessageType::System(system) => self.record_system(system, start, time),
+            _ => (),
+        }
+    }
+
+    fn finish(self, end: u64) -> Self {
+        Self {
+            basic_id_rate: self.basic_id_rate.finish(end),
+            location_rate: self.location_rate.finish(end),
+            system_rate: self.system_rate.finish(end),
+            ..self
+        }
+    }
+}
+
+impl Report {
+    // only a basic id carrying a serial number or session id identifies a standard remote id UA,
+    // a registration id may be broadcast alongside but does not count towards the rate.
+    fn record_basic_id(&mut self, basic_id: &BasicID, start: u64, time: u64) {
+        if let UASID::SerialNumber(_) | UASID::SessionID(_) = basic_id.uas_id() {
+            self.uas_id = self.uas_id.observe(true);
+            self.basic_id_rate.record(start, time);
+        }
+    }
+
+    fn record_location(&mut self, location: &Location, start: u64, time: u64) {
+        self.ua_position = self.ua_position.observe(capture::has_ua_position(location));
+
+        self.ua_altitude = self
+            .ua_altitude
+            .observe(matches!(location.geodetic_altitude(), Altitude::Known(_)));
+
+        self.ua_velocity = self.ua_velocity.observe(matches!(
+            (
+                location.speed(),
+                location.track_direction(),
+                location.vertical_speed()
+            ),
+            (
+                GroundSpeed::Known(_),
+                TrackDirection::Known(_),
+                VerticalSpeed::Known(_)
+            )
+        ));
+
+        self.time_mark = self.time_mark.observe(capture::has_time_mark(location));
+
+        self.emergency_status = self
+            .emergency_status
+            .observe(capture::has_emergency_status(location));
+
+        self.location_rate.record(start, time);
+    }
+
+    fn record_system(&mut self, system: &System, start: u64, time: u64) {
+        self.operator_position = self
+            .operator_position
+            .observe(capture::has_operator_position(system));
+
+        self.operator_altitude = self
+            .operator_altitude
+            .observe(matches!(system.operator_altitude(), Altitude::Known(_)));
+
+        self.system_timestamp = self
+            .system_timestamp
+            .observe(system.timestamp().system_time() != 0);
+
+        self.system_rate.record(start, time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        basic_id::{BasicID, RegistrationID, UASID, UAType},
+        compliance::{
+            Status,
+            capture::fixtures::{self, basic_id, location},
+            faa::{self, Validator},
+        },
+        location::{Altitude, Latitude, Location, Longitude},
+        messages::Message,
+        pack::Pack,
+        system::{System, UAClassification},
+    };
+
+    fn system() -> System {
+        fixtures::system(UAClassification::Undefined)
+    }
+
+    // one second of broadcast, the basic id every third second.
+    fn validate_seconds(seconds: u64, location: Location, system: System) -> faa::Report {
+        let mut validator = Validator::new();
+
+        fixtures::broadcast(
+            seconds,
+            &[location.into(), system.into()],
+            &[basic_id()],
+            |message, time| validator.update(message, time).unwrap(),
+        );
+
+        validator.report()
+    }
+
+    #[test]
+    fn test_compliant() {
+        let report = validate_seconds(10, location(), system());
+
+        assert!(report.is_compliant());
+        assert_eq!(report.location_rate().count(), 10);
+        assert_eq!(report.location_rate().max_interval(), Some(1_000));
+        assert_eq!(report.basic_id_rate().max_interval(), Some(3_000));
+    }
+
+    #[test]
+    fn test_validate_unpacks() {
+        let pack = Message::from(
+            Pack::try_from([basic_id(), location().into(), system().into()]).unwrap(),
+        );
+
+        let messages = [(pack, 0), (pack, 1_000)];
+
+        let report =
+            faa::validate(messages.iter().map(|(message, time)| (message, *time))).unwrap();
+
+        assert!(report.is_compliant());
+        assert_eq!(report.system_rate().count(), 2);
+    }
+
+    #[test]
+    fn test_missing() {
+        let mut validator = Validator::new();
+
+        let registration_id = RegistrationID::try_new("N", "123ABC").unwrap();
+
+        validator
+            .update(
+                &Message::from(BasicID::new(
+                    UAType::Helicopter,
+                    UASID::RegistrationID(registration_id),
+                )),
+                0,
+            )
+            .unwrap();
+        validator.update(&Message::from(location()), 0).unwrap();
+
+        let report = validator.report();
+
+        assert!(!report.is_compliant());
+        assert_eq!(report.uas_id(), Status::Missing);
+        assert_eq!(report.operator_position(), Status::Missing);
+        assert_eq!(report.operator_altitude(), Status::Missing);
+        assert_eq!(report.system_timestamp(), Status::Missing);
+        assert!(report.system_rate().is_violated());
+        assert_eq!(report.ua_position(), Status::Present);
+    }
+
+    #[test]
+    fn test_unknown() {
+        let location = Location::builder()
+            .latitude(Latitude::Known(37.5))
+            .longitude(Longitude::Known(-122.25))
+            .build()
+            .unwrap();
+
+        let report = validate_seconds(3, location, system());
+
+        assert!(!report.is_compliant());
+        assert_eq!(report.ua_position(), Status::Present);
+        assert_eq!(report.ua_altitude(), Status::Unknown);
+        assert_eq!(report.ua_velocity(), Status::Unknown);
+        assert_eq!(report.time_mark(), Status::Unknown);
+        assert_eq!(report.emergency_status(), Status::Unknown);
+    }
+
+    #[test]
+    fn test_system_timestamp_unknown() {
+        let system = System::builder()
+            .operator_latitude(Latitude::Known(37.5))
+            .operator_longitude(Longitude::Known(-122.249))
+            .operator_altitude(Altitude::Known(20.0))
+            .timestamp(0.into())
+            .build()
+            .unwrap();
+
+        let report = validate_seconds(3, location(), system);
+
+        assert!(!report.is_compliant());
+        assert_eq!(report.operator_position(), Status::Present);
+        assert_eq!(report.system_timestamp(), Status::Unknown);
+    }
+
+    #[test]
+    fn test_rate_violation() {
+        let mut validator = Validator::new();
+
+        for time in [0, 1_000, 2_500] {
+            validator.update(&basic_id(), time).unwrap();
+            validator.update(&Message::from(location()), time).unwrap();
+            validator.update(&Message::from(system()), time).unwrap();
+        }
+
+        let report = validator.report();
+
+        assert!(!report.is_compliant());
+        assert!(report.location_rate().is_violated());
+        assert_eq!(report.location_rate().max_interval(), Some(1_500));
+        assert!(!report.basic_id_rate().is_violated());
+    }
+}
@@ -0,0 +1,22 @@
+//! ## Compliance
+//!
+//! Checks a capture of the messages broadcast by one UA against the content and update rate
+//! requirements of a regulation, see [`faa`] and [`eu`].
+//!
+//! Each validator is fed the decoded [`Message`](crate::messages::Message)s along with the time
+//! they were received, in milliseconds since an arbitrary epoch chosen by the caller, as in the
+//! [`receiver`](crate::receiver) module. Its report lists the [`Status`] of each required message
+//! element and the [`Rate`] at which each required message type was received.
+//!
+//! Elements are checked in every message received, so a single message carrying an unknown value
+//! marks the element as [`Status::Unknown`]. Rates are checked over the whole capture, from the
+//! first message of any type to the last one.
+mod capture;
+mod rate;
+mod status;
+
+pub mod eu;
+pub mod faa;
+
+pub use rate::Rate;
+pub use status::Status;
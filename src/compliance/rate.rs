@@ -0,0 +1,104 @@
+/// Update Rate of a Required Message Type
+///
+/// Tracks the longest interval, in milliseconds, without the message type over a capture,
+/// including the intervals from the start of the capture to the first message and from the last
+/// message to the end of the capture.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rate {
+    required_interval: u64,
+    count: usize,
+    last: Option<u64>,
+    max_interval: u64,
+}
+
+impl Rate {
+    pub(crate) fn new(required_interval: u64) -> Self {
+        Self {
+            required_interval,
+            count: 0,
+            last: None,
+            max_interval: 0,
+        }
+    }
+
+    /// Returns the maximum interval allowed between two messages.
+    pub fn required_interval(&self) -> u64 {
+        self.required_interval
+    }
+
+    /// Returns the number of messages received.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the longest interval without a message, `None` if none was received.
+    pub fn max_interval(&self) -> Option<u64> {
+        match self.count {
+            0 => None,
+            _ => Some(self.max_interval),
+        }
+    }
+
+    /// Returns true if no message was received, or the longest interval exceeds the required one.
+    pub fn is_violated(&self) -> bool {
+        self.max_interval()
+            .is_none_or(|max_interval| max_interval > self.required_interval)
+    }
+
+    // records a message received at `time`, in a capture which started at `start`.
+    pub(crate) fn record(&mut self, start: u64, time: u64) {
+        let previous = self.last.unwrap_or(start);
+
+        self.max_interval = self.max_interval.max(time.saturating_sub(previous));
+        self.last = Some(time);
+        self.count += 1;
+    }
+
+    // closes the capture at `end`.
+    pub(crate) fn finish(mut self, end: u64) -> Self {
+        if let Some(last) = self.last {
+            self.max_interval = self.max_interval.max(end.saturating_sub(last));
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compliance::Rate;
+
+    #[test]
+    fn test_record() {
+        let mut rate = Rate::new(1_000);
+
+        for time in [100, 1_000, 2_500, 3_000] {
+            rate.record(0, time);
+        }
+
+        let rate = rate.finish(3_200);
+
+        assert_eq!(rate.count(), 4);
+        assert_eq!(rate.max_interval(), Some(1_500));
+        assert!(rate.is_violated());
+    }
+
+    #[test]
+    fn test_finish_includes_end_of_capture() {
+        let mut rate = Rate::new(1_000);
+
+        rate.record(0, 500);
+
+        assert!(!rate.finish(1_500).is_violated());
+        assert_eq!(rate.finish(2_000).max_interval(), Some(1_500));
+    }
+
+    #[test]
+    fn test_none_received() {
+        let rate = Rate::new(1_000).finish(5_000);
+
+        assert_eq!(rate.count(), 0);
+        assert_eq!(rate.max_interval(), None);
+        assert!(rate.is_violated());
+    }
+}
@@ -0,0 +1,41 @@
+/// Status of a Required Message Element
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Status {
+    /// No message carrying the element was received.
+    #[default]
+    Missing,
+    /// At least one message carried the element as an unknown value.
+    Unknown,
+    /// Every message carried a known value.
+    Present,
+}
+
+impl Status {
+    /// Returns true if the element is [`Status::Present`].
+    pub fn is_present(&self) -> bool {
+        matches!(self, Self::Present)
+    }
+
+    // status after one more message carrying the element. an unknown value is never forgotten.
+    pub(crate) fn observe(self, known: bool) -> Self {
+        match (self, known) {
+            (Self::Unknown, _) | (_, false) => Self::Unknown,
+            (_, true) => Self::Present,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compliance::Status;
+
+    #[test]
+    fn test_observe() {
+        assert_eq!(Status::default(), Status::Missing);
+        assert_eq!(Status::Missing.observe(true), Status::Present);
+        assert_eq!(Status::Missing.observe(false), Status::Unknown);
+        assert_eq!(Status::Present.observe(false), Status::Unknown);
+        assert_eq!(Status::Unknown.observe(true), Status::Unknown);
+        assert!(Status::Present.observe(true).is_present());
+    }
+}
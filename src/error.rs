@@ -22,7 +22,65 @@
 //! another Pack message.
 //!
 //! Invalid Protocol Version refers to a bytes array deserializing to
-//! [`Message`](crate::messages::Message) but which contains a protocol version other than `2`.
+//! [`Message`](crate::messages::Message) but which contains a protocol version other than `0`, `1`,
+//! or `2`, see [`ProtocolVersion`](crate::messages::ProtocolVersion).
+//!
+//! Invalid Frame Header refers to a [`transport`](crate::transport) frame whose constant header
+//! fields, such as the advertising data type or the service UUID, do not match the specification.
+//!
+//! Truncated Frame refers to a captured [`transport`](crate::transport) frame which is shorter
+//! than the length its own header declares.
+//!
+//! Unsupported Message Type refers to a message which the target container cannot carry, for
+//! example a [`Pack`](crate::pack::Pack) in a Bluetooth legacy advertisement.
+//!
+//! Authentication Type Mismatch refers to an [`Authentication`](crate::authentication) page whose
+//! type differs from the other pages of the same set.
+//!
+//! Duplicate Page refers to an [`Authentication`](crate::authentication) page received more than
+//! once for the same set.
+//!
+//! Missing Page refers to an [`Authentication`](crate::authentication) set which is not yet
+//! complete.
+//!
+//! Missing Message refers to a message required to build the signed bytes of an
+//! [`Authentication`](crate::authentication) signature, for example the
+//! [`BasicID`](crate::basic_id::BasicID) signed by a UAS ID signature, which was not provided.
+//!
+//! Unsupported Authentication Type refers to an
+//! [`AuthenticationType`](crate::authentication::AuthenticationType) for which no signed bytes are
+//! defined or which the [`Verifier`](crate::authentication::Verifier) does not implement.
+//!
+//! Invalid Public Key and Invalid Signature refer to a
+//! [`Verifier`](crate::authentication::Verifier) rejecting the key or the signature, respectively.
+//!
+//! Invalid Session ID refers to a [`SessionID`](crate::basic_id::SessionID) read as a type other
+//! than its own, or whose padding after the identifier is not zero.
+//!
+//! Invalid Drone Entity Tag refers to a [`DroneEntityTag`](crate::basic_id::DroneEntityTag) which
+//! does not start with the HHIT prefix.
+//!
+//! Invalid Operator ID refers to a malformed [`EuOperatorId`](crate::operator_id::EuOperatorId),
+//! and Invalid Checksum to one whose check character does not match the rest of the number.
+//!
+//! Unknown Nationality Mark refers to a [`RegistrationID`](crate::basic_id::RegistrationID) whose
+//! nationality mark is not in the ICAO table of the `icao` feature.
+//!
+//! Out Of Range refers to a known floating point value, such as a
+//! [`GroundSpeed`](crate::location::GroundSpeed), which lies outside the range its encoding can
+//! represent, or is not a number. Encoding clamps such values, while
+//! [`Location::validate`](crate::location::Location::validate) and the message builders reject
+//! them.
+//!
+//! Missing Field refers to a message builder, such as
+//! [`LocationBuilder`](crate::location::LocationBuilder), missing a field the message cannot do
+//! without, for example a known height without its height type.
+//!
+//! Inconsistent Fields refers to a message builder given fields which contradict each other, for
+//! example a known latitude paired with an unknown longitude.
+//!
+//! Errors implement [`core::fmt::Display`] with a short lowercase description, as well as
+//! [`core::error::Error`].
 //!
 //! Unreachable is a special error value. Per the convention of this library, we only allow `unwrap`
 //! operations on [`Error::Unreachable`] to make explicit it is not reachable. If you are a library
@@ -46,11 +104,83 @@ pub enum Error {
     InvalidSerialNumber,
     /// [`Pack`](crate::pack::Pack) cannot recursively contain pack messages.
     CannotRecursivelyPack,
-    /// Protocol version is not
-    /// [`Message::PROTOCOL_VERSION`](crate::messages::Message::PROTOCOL_VERSION).
+    /// Protocol version is not a [`ProtocolVersion`](crate::messages::ProtocolVersion).
     InvalidProtocolVersion,
+    /// Invalid [`transport`](crate::transport) frame header.
+    InvalidFrameHeader,
+    /// Captured [`transport`](crate::transport) frame is shorter than its declared length.
+    TruncatedFrame,
+    /// Message type cannot be carried by the container.
+    UnsupportedMessageType,
+    /// Authentication pages of one set do not share the same
+    /// [`AuthenticationType`](crate::authentication::AuthenticationType).
+    AuthenticationTypeMismatch,
+    /// Authentication page was already received.
+    DuplicatePage,
+    /// Authentication set is missing at least one page.
+    MissingPage,
+    /// Message required for the signed bytes was not provided.
+    MissingMessage,
+    /// Authentication type is not supported.
+    UnsupportedAuthenticationType,
+    /// Public key is malformed.
+    InvalidPublicKey,
+    /// Signature does not verify.
+    InvalidSignature,
+    /// Invalid [`SessionID`](crate::basic_id::SessionID) for the requested identifier.
+    InvalidSessionID,
+    /// Invalid [`DroneEntityTag`](crate::basic_id::DroneEntityTag) prefix.
+    InvalidDroneEntityTag,
+    /// Invalid [`EuOperatorId`](crate::operator_id::EuOperatorId) character.
+    InvalidOperatorID,
+    /// Check character does not match.
+    InvalidChecksum,
+    /// Nationality mark is not allocated by ICAO.
+    UnknownNationalityMark,
+    /// Known value outside the range of its encoding.
+    OutOfRange,
+    /// Message builder is missing a required field.
+    MissingField,
+    /// Message builder fields contradict each other.
+    InconsistentFields,
     /// Unreachable.
     ///
     /// If you see this error in a panic trace, this is a bug, please open a bug report.
     Unreachable,
 }
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let description = match self {
+            Self::InvalidDataLength => "invalid data length",
+            Self::InvalidInteger => "invalid integer",
+            Self::InvalidRegistrationID => "invalid registration id",
+            Self::InvalidSerialNumber => "invalid serial number",
+            Self::CannotRecursivelyPack => "pack cannot contain a pack",
+            Self::InvalidProtocolVersion => "invalid protocol version",
+            Self::InvalidFrameHeader => "invalid frame header",
+            Self::TruncatedFrame => "truncated frame",
+            Self::UnsupportedMessageType => "unsupported message type",
+            Self::AuthenticationTypeMismatch => "authentication type mismatch",
+            Self::DuplicatePage => "duplicate authentication page",
+            Self::MissingPage => "missing authentication page",
+            Self::MissingMessage => "missing message",
+            Self::UnsupportedAuthenticationType => "unsupported authentication type",
+            Self::InvalidPublicKey => "invalid public key",
+            Self::InvalidSignature => "invalid signature",
+            Self::InvalidSessionID => "invalid session id",
+            Self::InvalidDroneEntityTag => "invalid drone entity tag",
+            Self::InvalidOperatorID => "invalid operator id",
+            Self::InvalidChecksum => "invalid checksum",
+            Self::UnknownNationalityMark => "unknown nationality mark",
+            Self::OutOfRange => "value out of range",
+            Self::MissingField => "missing field",
+            Self::InconsistentFields => "inconsistent fields",
+            Self::Unreachable => "unreachable, please open a bug report",
+        };
+
+        f.write_str(description)
+    }
+}
+
+impl core::error::Error for Error {}
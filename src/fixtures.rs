@@ -0,0 +1,21 @@
+//! Messages shared by the tests of the modules which frame, pack, or borrow them.
+use crate::basic_id::BasicID;
+use crate::basic_id::UASID;
+use crate::basic_id::UAType;
+use crate::messages::Message;
+use crate::operator_id::OperatorID;
+use crate::operator_id::OperatorIDType;
+use crate::pack::Pack;
+
+pub(crate) fn basic_id_message() -> Message {
+    Message::from(BasicID::new(UAType::Helicopter, UASID::None))
+}
+
+pub(crate) fn operator_id_message() -> Message {
+    Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]))
+}
+
+// a pack of two distinct messages, the operator id first.
+pub(crate) fn pack() -> Pack {
+    Pack::try_from([operator_id_message(), basic_id_message()]).unwrap()
+}
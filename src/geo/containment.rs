@@ -0,0 +1,27 @@
+use crate::geo::Excursion;
+
+/// Containment
+///
+/// Whether a UA lies inside of an [`OperatingArea`](crate::geo::OperatingArea), see
+/// [`OperatingArea::containment`](crate::geo::OperatingArea::containment).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Containment {
+    /// Inside the area, within its radius and between its floor and ceiling.
+    Inside,
+    /// Outside the area, by the given excursion.
+    Outside(Excursion),
+    /// Neither inside nor outside is certain, as a required position or altitude is unknown.
+    Unknown,
+}
+
+impl Containment {
+    /// Returns true if the UA is known to be inside the area.
+    pub fn is_inside(&self) -> bool {
+        matches!(self, Self::Inside)
+    }
+
+    /// Returns true if the UA is known to be outside the area.
+    pub fn is_outside(&self) -> bool {
+        matches!(self, Self::Outside(_))
+    }
+}
@@ -0,0 +1,43 @@
+/// Excursion
+///
+/// How far, and in which direction, a UA lies outside of an
+/// [`OperatingArea`](crate::geo::OperatingArea), see [`Containment::Outside`]. Each component is
+/// `None` when it cannot be determined, for example when the UA altitude is unknown.
+///
+/// [`Containment::Outside`]: crate::geo::Containment::Outside
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Excursion {
+    horizontal_distance: Option<f64>,
+    vertical_distance: Option<f64>,
+    bearing: Option<f64>,
+}
+
+impl Excursion {
+    pub(crate) fn new(
+        horizontal_distance: Option<f64>,
+        vertical_distance: Option<f64>,
+        bearing: Option<f64>,
+    ) -> Self {
+        Self {
+            horizontal_distance,
+            vertical_distance,
+            bearing,
+        }
+    }
+
+    /// Returns the distance in meters beyond the area radius, zero if within it.
+    pub fn horizontal_distance(&self) -> Option<f64> {
+        self.horizontal_distance
+    }
+
+    /// Returns the distance in meters above the ceiling, or below the floor as a negative value,
+    /// zero if between both.
+    pub fn vertical_distance(&self) -> Option<f64> {
+        self.vertical_distance
+    }
+
+    /// Returns the bearing in degrees from the area center to the UA, if beyond the area radius.
+    pub fn bearing(&self) -> Option<f64> {
+        self.bearing
+    }
+}
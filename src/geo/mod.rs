@@ -0,0 +1,362 @@
+//! ## Geodesy
+//!
+//! Distances and bearings between two [`Position`]s, such as the UA of a
+//! [`Location`](crate::location::Location) message and the operator of a
+//! [`System`](crate::system::System) message, see
+//! [`Location::distance_to_operator`](crate::location::Location::distance_to_operator).
+//!
+//! Only available with the `geo` feature, which pulls in `libm` for the trigonometry the core
+//! library lacks without `std`.
+//!
+//! ### Distances
+//!
+//! The great-circle distance treats the Earth as a sphere of the mean Earth radius, it is cheap
+//! and within half a percent of the true distance. The ellipsoidal distance follows the WGS-84
+//! ellipsoid through Vincenty's inverse formula, it is accurate to within a millimeter but may fail
+//! to converge for nearly antipodal points.
+//!
+//! The slant range is the straight line distance between both positions, combining the
+//! ellipsoidal distance with the difference of their altitudes.
+//!
+//! All distances are in meters and all bearings in degrees clockwise from True North.
+//!
+//! ### Operating Area
+//!
+//! [`OperatingArea`] checks whether the UA of a [`Location`](crate::location::Location) lies inside
+//! the volume declared by a [`System`](crate::system::System) message, and if it does not, reports
+//! the [`Excursion`] from it.
+mod containment;
+mod excursion;
+mod operating_area;
+
+pub use containment::Containment;
+pub use excursion::Excursion;
+pub use operating_area::OperatingArea;
+
+use crate::location::Altitude;
+use crate::location::Latitude;
+use crate::location::Longitude;
+
+/// Geographic Position
+///
+/// A latitude, longitude, and altitude, each of which may be unknown. Computations involving an
+/// unknown value return `None`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Position {
+    latitude: Latitude,
+    longitude: Longitude,
+    altitude: Altitude,
+}
+
+impl Position {
+    /// Mean Earth radius in meters, as used by the great-circle distance.
+    pub const MEAN_EARTH_RADIUS: f64 = 6_371_008.8;
+
+    /// Semi-major axis of the WGS-84 ellipsoid in meters.
+    pub const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+
+    /// Flattening of the WGS-84 ellipsoid.
+    pub const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+    // iteration limit and convergence threshold, in radians, of vincenty's inverse formula.
+    const VINCENTY_ITERATIONS: usize = 200;
+    const VINCENTY_THRESHOLD: f64 = 1e-12;
+
+    /// Constructs a new position.
+    pub fn new(latitude: Latitude, longitude: Longitude, altitude: Altitude) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Returns the latitude.
+    pub fn latitude(&self) -> Latitude {
+        self.latitude
+    }
+
+    /// Returns the longitude.
+    pub fn longitude(&self) -> Longitude {
+        self.longitude
+    }
+
+    /// Returns the altitude.
+    pub fn altitude(&self) -> Altitude {
+        self.altitude
+    }
+
+    /// Returns the great-circle distance to `other`.
+    pub fn great_circle_distance_to(&self, other: &Position) -> Option<f64> {
+        let (latitude_1, longitude_1) = self.radians()?;
+        let (latitude_2, longitude_2) = other.radians()?;
+
+        let half_chord = square(libm::sin((latitude_2 - latitude_1) / 2.0))
+            + libm::cos(latitude_1)
+                * libm::cos(latitude_2)
+                * square(libm::sin((longitude_2 - longitude_1) / 2.0));
+
+        let central_angle = 2.0 * libm::asin(libm::sqrt(half_chord).min(1.0));
+
+        Some(Self::MEAN_EARTH_RADIUS * central_angle)
+    }
+
+    /// Returns the distance to `other` along the WGS-84 ellipsoid.
+    ///
+    /// Returns `None` if Vincenty's formula does not converge, which only happens for nearly
+    /// antipodal points.
+    pub fn ellipsoidal_distance_to(&self, other: &Position) -> Option<f64> {
+        let (latitude_1, longitude_1) = self.radians()?;
+        let (latitude_2, longitude_2) = other.radians()?;
+
+        let a = Self::WGS84_SEMI_MAJOR_AXIS;
+        let f = Self::WGS84_FLATTENING;
+        let b = a * (1.0 - f);
+
+        // reduced latitudes, on the auxiliary sphere.
+        let u_1 = libm::atan((1.0 - f) * libm::tan(latitude_1));
+        let u_2 = libm::atan((1.0 - f) * libm::tan(latitude_2));
+
+        let (sin_u_1, cos_u_1) = (libm::sin(u_1), libm::cos(u_1));
+        let (sin_u_2, cos_u_2) = (libm::sin(u_2), libm::cos(u_2));
+
+        let l = longitude_2 - longitude_1;
+
+        let mut lambda = l;
+
+        for _ in 0..Self::VINCENTY_ITERATIONS {
+            let (sin_lambda, cos_lambda) = (libm::sin(lambda), libm::cos(lambda));
+
+            let sin_sigma = libm::sqrt(
+                square(cos_u_2 * sin_lambda)
+                    + square(cos_u_1 * sin_u_2 - sin_u_1 * cos_u_2 * cos_lambda),
+            );
+
+            // coincident points.
+            if sin_sigma == 0.0 {
+                return Some(0.0);
+            }
+
+            let cos_sigma = sin_u_1 * sin_u_2 + cos_u_1 * cos_u_2 * cos_lambda;
+            let sigma = libm::atan2(sin_sigma, cos_sigma);
+
+            let sin_alpha = cos_u_1 * cos_u_2 * sin_lambda / sin_sigma;
+            let cos_squared_alpha = 1.0 - square(sin_alpha);
+
+            // both points on the equator.
+            let cos_2_sigma_m = match cos_squared_alpha {
+                0.0 => 0.0,
+                _ => cos_sigma - 2.0 * sin_u_1 * sin_u_2 / cos_squared_alpha,
+            };
+
+            let c = f / 16.0 * cos_squared_alpha * (4.0 + f * (4.0 - 3.0 * cos_squared_alpha));
+
+            let previous_lambda = lambda;
+
+            lambda = l
+                + (1.0 - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2_sigma_m
+                                + c * cos_sigma * (-1.0 + 2.0 * square(cos_2_sigma_m))));
+
+            if libm::fabs(lambda - previous_lambda) > Self::VINCENTY_THRESHOLD {
+                continue;
+            }
+
+            let u_squared = cos_squared_alpha * (square(a) - square(b)) / square(b);
+
+            let big_a = 1.0
+                + u_squared / 16_384.0
+                    * (4_096.0 + u_squared * (-768.0 + u_squared * (320.0 - 175.0 * u_squared)));
+
+            let big_b = u_squared / 1_024.0
+                * (256.0 + u_squared * (-128.0 + u_squared * (74.0 - 47.0 * u_squared)));
+
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2_sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * square(cos_2_sigma_m))
+                            - big_b / 6.0
+                                * cos_2_sigma_m
+                                * (-3.0 + 4.0 * square(sin_sigma))
+                                * (-3.0 + 4.0 * square(cos_2_sigma_m))));
+
+            return Some(b * big_a * (sigma - delta_sigma));
+        }
+
+        None
+    }
+
+    /// Returns the distance to `other` along the WGS-84 ellipsoid, falling back on the great-circle
+    /// distance if the ellipsoidal distance does not converge.
+    pub fn distance_to(&self, other: &Position) -> Option<f64> {
+        self.ellipsoidal_distance_to(other)
+            .or_else(|| self.great_circle_distance_to(other))
+    }
+
+    /// Returns the initial bearing towards `other`, from 0 up to 360 degrees.
+    pub fn bearing_to(&self, other: &Position) -> Option<f64> {
+        let (latitude_1, longitude_1) = self.radians()?;
+        let (latitude_2, longitude_2) = other.radians()?;
+
+        let delta_longitude = longitude_2 - longitude_1;
+
+        let y = libm::sin(delta_longitude) * libm::cos(latitude_2);
+        let x = libm::cos(latitude_1) * libm::sin(latitude_2)
+            - libm::sin(latitude_1) * libm::cos(latitude_2) * libm::cos(delta_longitude);
+
+        let bearing = libm::atan2(y, x).to_degrees();
+
+        Some((bearing + 360.0) % 360.0)
+    }
+
+    /// Returns the straight line distance to `other`, through the difference of their altitudes.
+    ///
+    /// The horizontal component is [`Position::distance_to`].
+    pub fn slant_range_to(&self, other: &Position) -> Option<f64> {
+        let (Altitude::Known(altitude_1), Altitude::Known(altitude_2)) =
+            (self.altitude, other.altitude)
+        else {
+            return None;
+        };
+
+        let distance = self.distance_to(other)?;
+
+        let height = altitude_2 as f64 - altitude_1 as f64;
+
+        Some(libm::sqrt(square(distance) + square(height)))
+    }
+
+    // latitude and longitude in radians, if both are known.
+    fn radians(&self) -> Option<(f64, f64)> {
+        match (self.latitude, self.longitude) {
+            (Latitude::Known(latitude), Longitude::Known(longitude)) => {
+                Some((latitude.to_radians(), longitude.to_radians()))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn square(value: f64) -> f64 {
+    value * value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geo::Position,
+        location::{Altitude, Latitude, Longitude},
+    };
+
+    fn position(latitude: f64, longitude: f64) -> Position {
+        Position::new(
+            Latitude::Known(latitude),
+            Longitude::Known(longitude),
+            Altitude::Known(0.0),
+        )
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64, tolerance: f64) {
+        let actual = actual.unwrap();
+
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_great_circle_distance() {
+        let origin = position(0.0, 0.0);
+
+        assert_close(
+            origin.great_circle_distance_to(&position(0.0, 1.0)),
+            111_195.08,
+            0.01,
+        );
+        assert_close(origin.great_circle_distance_to(&origin), 0.0, 0.0);
+    }
+
+    #[test]
+    fn test_ellipsoidal_distance() {
+        let origin = position(0.0, 0.0);
+
+        // one degree along the equator, and along the meridian.
+        assert_close(
+            origin.ellipsoidal_distance_to(&position(0.0, 1.0)),
+            111_319.491,
+            0.001,
+        );
+        assert_close(
+            origin.ellipsoidal_distance_to(&position(1.0, 0.0)),
+            110_574.389,
+            0.001,
+        );
+        assert_close(origin.ellipsoidal_distance_to(&origin), 0.0, 0.0);
+    }
+
+    #[test]
+    fn test_ellipsoidal_distance_fails_nearly_antipodal() {
+        let origin = position(0.0, 0.0);
+        let nearly_antipodal = position(0.5, 179.7);
+
+        assert_eq!(origin.ellipsoidal_distance_to(&nearly_antipodal), None);
+        assert_eq!(
+            origin.distance_to(&nearly_antipodal),
+            origin.great_circle_distance_to(&nearly_antipodal)
+        );
+    }
+
+    #[test]
+    fn test_bearing() {
+        let origin = position(0.0, 0.0);
+
+        assert_close(origin.bearing_to(&position(1.0, 0.0)), 0.0, 1e-9);
+        assert_close(origin.bearing_to(&position(0.0, 1.0)), 90.0, 1e-9);
+        assert_close(origin.bearing_to(&position(-1.0, 0.0)), 180.0, 1e-9);
+        assert_close(origin.bearing_to(&position(0.0, -1.0)), 270.0, 1e-9);
+    }
+
+    #[test]
+    fn test_slant_range() {
+        let ground = position(37.5, -122.25);
+        let above = Position::new(
+            Latitude::Known(37.5),
+            Longitude::Known(-122.25),
+            Altitude::Known(100.0),
+        );
+
+        assert_close(ground.slant_range_to(&above), 100.0, 1e-9);
+
+        let distance = ground
+            .ellipsoidal_distance_to(&position(37.501, -122.25))
+            .unwrap();
+
+        assert_close(
+            above.slant_range_to(&position(37.501, -122.25)),
+            libm::sqrt(distance * distance + 100.0 * 100.0),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        let origin = position(0.0, 0.0);
+        let unknown = Position::new(Latitude::Unknown, Longitude::Known(1.0), Altitude::Unknown);
+        let unknown_altitude = Position::new(
+            Latitude::Known(0.0),
+            Longitude::Known(1.0),
+            Altitude::Unknown,
+        );
+
+        assert_eq!(origin.great_circle_distance_to(&unknown), None);
+        assert_eq!(origin.ellipsoidal_distance_to(&unknown), None);
+        assert_eq!(origin.bearing_to(&unknown), None);
+        assert_eq!(origin.slant_range_to(&unknown_altitude), None);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::geo::Containment;
+use crate::geo::Excursion;
+use crate::geo::Position;
+use crate::location::Altitude;
+use crate::location::Location;
+use crate::system::AreaCount;
+use crate::system::OperatingAreaRadius;
+
+/// Operating Area
+///
+/// Volume declared by a [`System`](crate::system::System) message, a cylinder of the area radius
+/// centered on the operator, between the area floor and ceiling, see
+/// [`System::operating_area`](crate::system::System::operating_area).
+///
+/// A zero radius declares no horizontal bound, and an unknown floor or ceiling declares no vertical
+/// bound on that side. The floor and ceiling are geodetic altitudes, so they are compared against
+/// the geodetic altitude of a [`Location`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OperatingArea {
+    center: Position,
+    count: AreaCount,
+    radius: OperatingAreaRadius,
+    ceiling: Altitude,
+    floor: Altitude,
+}
+
+impl OperatingArea {
+    /// Constructs a new operating area.
+    pub fn new(
+        center: Position,
+        count: AreaCount,
+        radius: OperatingAreaRadius,
+        ceiling: Altitude,
+        floor: Altitude,
+    ) -> Self {
+        Self {
+            center,
+            count,
+            radius,
+            ceiling,
+            floor,
+        }
+    }
+
+    /// Returns the center of the area.
+    pub fn center(&self) -> Position {
+        self.center
+    }
+
+    /// Returns the number of UAS's in the area.
+    pub fn count(&self) -> AreaCount {
+        self.count
+    }
+
+    /// Returns the radius of the area.
+    pub fn radius(&self) -> OperatingAreaRadius {
+        self.radius
+    }
+
+    /// Returns the ceiling altitude of the area.
+    pub fn ceiling(&self) -> Altitude {
+        self.ceiling
+    }
+
+    /// Returns the floor altitude of the area.
+    pub fn floor(&self) -> Altitude {
+        self.floor
+    }
+
+    /// Returns whether the UA of `location` lies inside the area.
+    ///
+    /// A UA beyond one bound is [`Containment::Outside`] even if the other bound cannot be checked.
+    pub fn containment(&self, location: &Location) -> Containment {
+        let position = location.position();
+
+        let horizontal_distance = self.horizontal_distance(&position);
+
+        let vertical_distance = self.vertical_distance(location.geodetic_altitude());
+
+        let beyond_radius = horizontal_distance.is_some_and(|distance| distance > 0.0);
+
+        let beyond_band = vertical_distance.is_some_and(|distance| distance != 0.0);
+
+        if beyond_radius || beyond_band {
+            let bearing = match beyond_radius {
+                true => self.center.bearing_to(&position),
+                false => None,
+            };
+
+            return Containment::Outside(Excursion::new(
+                horizontal_distance,
+                vertical_distance,
+                bearing,
+            ));
+        }
+
+        match (horizontal_distance, vertical_distance) {
+            (Some(_), Some(_)) => Containment::Inside,
+            _ => Containment::Unknown,
+        }
+    }
+
+    /// Returns true if the UA of `location` lies inside the area, `None` if it cannot be checked.
+    pub fn contains(&self, location: &Location) -> Option<bool> {
+        match self.containment(location) {
+            Containment::Inside => Some(true),
+            Containment::Outside(_) => Some(false),
+            Containment::Unknown => None,
+        }
+    }
+
+    // meters beyond the radius. zero within the radius or without a radius, `None` if either
+    // position is unknown.
+    fn horizontal_distance(&self, position: &Position) -> Option<f64> {
+        let radius = self.radius.radius();
+
+        if radius == 0 {
+            return Some(0.0);
+        }
+
+        let distance = self.center.distance_to(position)?;
+
+        Some((distance - radius as f64).max(0.0))
+    }
+
+    // meters above the ceiling, negative below the floor. zero between both or without either,
+    // `None` if the altitude is unknown.
+    fn vertical_distance(&self, altitude: Altitude) -> Option<f64> {
+        let (ceiling, floor) = (bound(self.ceiling), bound(self.floor));
+
+        if ceiling.is_none() && floor.is_none() {
+            return Some(0.0);
+        }
+
+        let altitude = match altitude {
+            Altitude::Known(altitude) => altitude as f64,
+            Altitude::Unknown | Altitude::NoValue | Altitude::Invalid => return None,
+        };
+
+        match (ceiling, floor) {
+            (Some(ceiling), _) if altitude > ceiling => Some(altitude - ceiling),
+            (_, Some(floor)) if altitude < floor => Some(altitude - floor),
+            _ => Some(0.0),
+        }
+    }
+}
+
+// declared bound of the area, only known altitudes bound it.
+fn bound(altitude: Altitude) -> Option<f64> {
+    match altitude {
+        Altitude::Known(altitude) => Some(altitude as f64),
+        Altitude::Unknown | Altitude::NoValue | Altitude::Invalid => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geo::Containment,
+        location::{Altitude, Latitude, Location, Longitude},
+        system::{OperatingAreaRadius, System},
+    };
+
+    fn system(radius: u16, ceiling: Altitude, floor: Altitude) -> System {
+        System::builder()
+            .operator_latitude(Latitude::Known(0.0))
+            .operator_longitude(Longitude::Known(0.0))
+            .area_radius(OperatingAreaRadius::try_new(radius).unwrap())
+            .area_ceiling(ceiling)
+            .area_floor(floor)
+            .timestamp(1_000.into())
+            .build()
+            .unwrap()
+    }
+
+    fn location(longitude: f64, altitude: Altitude) -> Location {
+        Location::builder()
+            .latitude(Latitude::Known(0.0))
+            .longitude(Longitude::Known(longitude))
+            .geodetic_altitude(altitude)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_inside() {
+        let area = system(500, Altitude::Known(150.0), Altitude::Known(10.0)).operating_area();
+
+        // roughly 111 meters east of the operator.
+        let location = location(0.001, Altitude::Known(100.0));
+
+        assert_eq!(area.containment(&location), Containment::Inside);
+        assert_eq!(area.contains(&location), Some(true));
+    }
+
+    #[test]
+    fn test_outside_radius() {
+        let area = system(100, Altitude::Known(150.0), Altitude::Known(10.0)).operating_area();
+
+        let Containment::Outside(excursion) =
+            area.containment(&location(-0.001, Altitude::Known(100.0)))
+        else {
+            panic!("expected outside");
+        };
+
+        assert!((excursion.horizontal_distance().unwrap() - 11.319).abs() < 0.001);
+        assert_eq!(excursion.vertical_distance(), Some(0.0));
+        assert_eq!(excursion.bearing(), Some(270.0));
+    }
+
+    #[test]
+    fn test_outside_band() {
+        let area = system(500, Altitude::Known(150.0), Altitude::Known(10.0)).operating_area();
+
+        let Containment::Outside(above) =
+            area.containment(&location(0.001, Altitude::Known(160.5)))
+        else {
+            panic!("expected outside");
+        };
+
+        let Containment::Outside(below) = area.containment(&location(0.001, Altitude::Known(4.0)))
+        else {
+            panic!("expected outside");
+        };
+
+        assert_eq!(above.vertical_distance(), Some(10.5));
+        assert_eq!(above.horizontal_distance(), Some(0.0));
+        assert_eq!(above.bearing(), None);
+        assert_eq!(below.vertical_distance(), Some(-6.0));
+    }
+
+    #[test]
+    fn test_undeclared_bounds() {
+        let area = system(0, Altitude::Unknown, Altitude::NoValue).operating_area();
+
+        assert!(
+            area.containment(&location(10.0, Altitude::Unknown))
+                .is_inside()
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        let area = system(500, Altitude::Known(150.0), Altitude::Unknown).operating_area();
+
+        assert_eq!(
+            area.containment(&location(0.001, Altitude::Unknown)),
+            Containment::Unknown
+        );
+        assert_eq!(
+            area.containment(&Location::builder().build().unwrap()),
+            Containment::Unknown
+        );
+        assert_eq!(area.contains(&location(0.001, Altitude::Invalid)), None);
+
+        // beyond the radius, though the altitude is unknown.
+        assert!(
+            area.containment(&location(0.01, Altitude::Unknown))
+                .is_outside()
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! The Drone ID Rust library implements the core data types as well as the encoding and decoding
 //! schemes of the ASTM F3411-22a specification, as required by the Federal Aviation Administration
-//! in the United States.
+//! in the United States. Messages of the earlier F3411-19 revision and of pre-release drafts are
+//! supported as well, see [`ProtocolVersion`](messages::ProtocolVersion).
 //!
 //! | Note: Drone, Unmanned Aerial System (UAS), and Unmanned Aircraft (UA) may be used
 //! | interchangeably in the documentation.
@@ -26,34 +27,83 @@
 //!
 //! Decoding should generally be performed through [`Message::try_from`](messages::Message), which
 //! takes a slice reference which must be 25 bytes unless the [`Pack`](pack::Pack) message is used,
-//! in which case the slice must be of length `25 * message_count + 2`.
+//! in which case the slice must be of length `25 * message_count + 3`.
 //!
 //! Partial decoding is possible, for example if a library consumer is certain the message will
 //! always be the [`BasicID`](basic_id::BasicID) type, they may use
 //! [`BasicID::try_from`](basic_id::BasicID) directly, but the proper length must be used; in this
 //! case it is 24 bytes.
 //!
+//! [`MessageRef::try_from`](messages::MessageRef) validates the same bytes without copying them,
+//! its fields decode from the borrowed slice on demand.
+//!
 //! ## Encoding
 //!
 //! Encoding should generally be performed through the [`try_serialize::TrySerialize`] trait
 //! implemented on [`Message`](messages::Message), which takes a mutable slice reference which must
 //! be 25 bytes unless the [`Pack`](pack::Pack) message is used, in which case the slice must be of
-//! length `25 * message_count + 2`. The proper length can be found with
+//! length `25 * message_count + 3`. The proper length can be found with
 //! [`Message::encoding_byte_length`](messages::Message::encoding_byte_length).
 //!
 //! As above, partial encoding is possible, for example if a library consumer only intends to
 //! encode the [`BasicID`](basic_id::BasicID) type, they may use [`try_serialize::TrySerialize`]
 //! directly, but the proper length must be used; in this case it is 24 bytes.
+//!
+//! Reserved and private use variants, such as
+//! [`OperationalStatus::Reserved`](location::OperationalStatus::Reserved), carry the code they were
+//! decoded from and encode back to it. A code outside the range of its variant encodes as the
+//! nearest code within it.
+//!
+//! Encoding quantizes some fields, for example altitudes to half a meter, see the [`quantize`]
+//! module and [`Message::normalized`](messages::Message::normalized) to compare a message against
+//! its decoded counterpart.
+//!
+//! ## Transports
+//!
+//! The [`transport`] module wraps and unwraps messages in the byte structures used by the
+//! Bluetooth and Wi-Fi broadcast transports.
+//!
+//! ## Receiving
+//!
+//! The [`receiver`] module aggregates decoded messages from many frames into one picture per UA.
+//!
+//! ## Compliance
+//!
+//! The [`compliance`] module checks a capture of the messages of one UA against the content and
+//! update rate requirements of a regulation, such as [`compliance::faa`] or [`compliance::eu`].
+//!
+//! ## Geodesy
+//!
+//! The `geo` feature adds the `geo` module, which computes the distance, bearing, and slant range
+//! between the UA and its operator, for example through `Location::distance_to_operator`.
+//!
+//! ## Serde
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for [`Message`](messages::Message)
+//! and every type it contains. Values with special meanings are tagged, for example
+//! `{"Known": 37.7749}` or `"Unknown"` for a [`Latitude`](location::Latitude), and identifiers are
+//! strings, for example `"N.123ABC"` for a [`RegistrationID`](basic_id::RegistrationID). A
+//! [`Pack`](pack::Pack) is a sequence of its messages. The core library stays `#![no_std]`.
 #![no_std]
 #![warn(missing_docs)]
 
 pub mod authentication;
 pub mod basic_id;
+pub mod compliance;
 pub mod error;
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "geo")]
+pub mod geo;
 pub mod location;
 pub mod messages;
 pub mod operator_id;
 pub mod pack;
+pub mod quantize;
+pub mod receiver;
 pub mod self_id;
+#[cfg(feature = "serde")]
+mod serde_support;
 pub mod system;
+pub mod transport;
 pub mod try_serialize;
@@ -0,0 +1,107 @@
+use crate::location::TimestampAccuracy;
+
+/// Absolute Time of a Location Message
+///
+/// A [`Timestamp`](crate::location::Timestamp) resolved into a Unix time, in tenths of a second,
+/// along with its accuracy, see [`Location::absolute_time`](crate::location::Location::absolute_time).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AbsoluteTime {
+    unix_time_tenths: u64,
+    accuracy: TimestampAccuracy,
+}
+
+impl AbsoluteTime {
+    /// Constructs a new absolute time.
+    pub fn new(unix_time_tenths: u64, accuracy: TimestampAccuracy) -> Self {
+        Self {
+            unix_time_tenths,
+            accuracy,
+        }
+    }
+
+    /// Returns the Unix time, in tenths of a second.
+    pub fn unix_time_tenths(&self) -> u64 {
+        self.unix_time_tenths
+    }
+
+    /// Returns the accuracy.
+    pub fn accuracy(&self) -> TimestampAccuracy {
+        self.accuracy
+    }
+
+    /// Returns the earliest Unix time within the accuracy, in tenths of a second.
+    ///
+    /// Returns `None` if the accuracy is unknown.
+    pub fn earliest(&self) -> Option<u64> {
+        Some(
+            self.unix_time_tenths
+                .saturating_sub(self.accuracy_tenths()?),
+        )
+    }
+
+    /// Returns the latest Unix time within the accuracy, in tenths of a second.
+    ///
+    /// Returns `None` if the accuracy is unknown, or if the latest time overflows.
+    pub fn latest(&self) -> Option<u64> {
+        self.unix_time_tenths.checked_add(self.accuracy_tenths()?)
+    }
+
+    // accuracy in tenths of a second, as encoded.
+    fn accuracy_tenths(&self) -> Option<u64> {
+        match self.accuracy {
+            TimestampAccuracy::Known(_) => Some(u8::from(self.accuracy) as u64),
+            TimestampAccuracy::Unknown => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::location::{AbsoluteTime, Location, Timestamp, TimestampAccuracy};
+
+    // 2024-01-01T12:00:00Z, the top of an hour.
+    const HOUR: u64 = 1_704_110_400;
+
+    #[test]
+    fn test_interval() {
+        let absolute_time = AbsoluteTime::new(HOUR * 10 + 610, TimestampAccuracy::Known(0.3));
+
+        assert_eq!(absolute_time.unix_time_tenths(), HOUR * 10 + 610);
+        assert_eq!(absolute_time.earliest(), Some(HOUR * 10 + 607));
+        assert_eq!(absolute_time.latest(), Some(HOUR * 10 + 613));
+    }
+
+    #[test]
+    fn test_interval_unknown_accuracy() {
+        let absolute_time = AbsoluteTime::new(HOUR * 10, TimestampAccuracy::Unknown);
+
+        assert_eq!(absolute_time.earliest(), None);
+        assert_eq!(absolute_time.latest(), None);
+    }
+
+    #[test]
+    fn test_interval_overflow() {
+        let absolute_time = AbsoluteTime::new(u64::MAX, TimestampAccuracy::Known(0.3));
+
+        assert_eq!(absolute_time.earliest(), Some(u64::MAX - 3));
+        assert_eq!(absolute_time.latest(), None);
+    }
+
+    #[test]
+    fn test_location_absolute_time() {
+        let location = Location::builder()
+            .timestamp(Timestamp::from_unix_time(HOUR - 2))
+            .timestamp_accuracy(TimestampAccuracy::Known(1.0))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            location.absolute_time(HOUR + 1, 10),
+            Some(AbsoluteTime::new(
+                HOUR * 10 - 20,
+                TimestampAccuracy::Known(1.0)
+            ))
+        );
+        assert_eq!(location.absolute_time(HOUR + 1, 1), None);
+    }
+}
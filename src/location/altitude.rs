@@ -1,3 +1,5 @@
+use crate::quantize::Quantize;
+
 /// Altitude
 ///
 /// Altitude value which can represent geodetic altitude based on a line from the WGS-84 ellipsoid
@@ -5,6 +7,7 @@
 ///
 /// Altitude MUST be in meters with a resolution of 1 meter.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Altitude {
     /// Invalid altitude value.
     Invalid,
@@ -52,9 +55,23 @@ impl From<Altitude> for u16 {
     }
 }
 
+impl Quantize for Altitude {
+    fn quantize(&self) -> Self {
+        u16::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::location::Altitude;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_encode() {
@@ -72,7 +89,7 @@ mod tests {
     fn test_encode_unknown() {
         let unknown_code = 0;
 
-        let altitude = Altitude::try_from(unknown_code).unwrap();
+        let altitude = Altitude::from(unknown_code);
 
         assert_eq!(altitude, Altitude::Unknown);
     }
@@ -95,4 +112,16 @@ mod tests {
 
         assert_eq!(u16::from(altitude), unknown_code);
     }
+
+    #[test]
+    fn test_quantize() {
+        let altitude = Altitude::Known(100.3);
+
+        assert_eq!(altitude.quantize(), Altitude::Known(100.0));
+        assert!((altitude.quantization_error().unwrap() - 0.3).abs() < 1e-4);
+        assert!(!altitude.is_lossless());
+        assert!(Altitude::Known(100.5).is_lossless());
+        assert!(Altitude::Unknown.is_lossless());
+        assert_eq!(Altitude::Unknown.quantization_error(), None);
+    }
 }
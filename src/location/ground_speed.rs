@@ -1,3 +1,5 @@
+use crate::quantize::Quantize;
+
 /// Ground Speed
 ///
 /// Measured in meters per second, minimum resolution is 0.25m/s.
@@ -14,6 +16,7 @@
 /// 8-bit integer representing 0.25 m/s increments and return a flag value of `false` which tells
 /// the decoder to use high precision.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GroundSpeed {
     /// Invalid ground speed.
     Invalid,
@@ -42,7 +45,15 @@ impl GroundSpeed {
     /// it will use high precision.
     pub const PRECISION_THRESHOLD: f32 = 63.75;
 
+    // NaN encodes as an invalid speed, and negative speeds clamp up to zero, so every cast below
+    // is within range.
     fn encode_speed(speed: f32) -> (bool, u8) {
+        if speed.is_nan() {
+            return (false, 0);
+        }
+
+        let speed = speed.clamp(0.0, Self::MAX);
+
         // if speed is greater than or equal to 254.25: use low precision, clamp to 254
         if speed >= Self::MAX {
             return (true, 254);
@@ -50,23 +61,14 @@ impl GroundSpeed {
 
         // if speed is less than or equal to 63.75: use high precision
         if speed <= Self::PRECISION_THRESHOLD {
-            // UNSAFE REASON: A safe API is not included without the std library. This is safe
-            // because the max `speed` value at this point is 63.75, implying the max encoded value
-            // is 255.
-            return unsafe { (false, f32::to_int_unchecked(speed / 0.25)) };
+            return (false, (speed / Self::HIGH_PRECISION_UNIT) as u8);
         }
 
         // otherwise, value is between 63.75 and 254.25; use low precision
-        //
-        // UNSAFE REASON: A safe API is not included without the std library. This is safe because
-        // the max `speed` value at this point is 254.24, implying the max encoded value is 253.987,
-        // which rounds up to 254.
-        return unsafe {
-            (
-                true,
-                f32::to_int_unchecked((speed - Self::PRECISION_THRESHOLD) / 0.75),
-            )
-        };
+        (
+            true,
+            ((speed - Self::PRECISION_THRESHOLD) / Self::LOW_PRECISION_UNIT) as u8,
+        )
     }
 }
 
@@ -97,9 +99,23 @@ impl From<GroundSpeed> for (bool, u8) {
     }
 }
 
+impl Quantize for GroundSpeed {
+    fn quantize(&self) -> Self {
+        <(bool, u8)>::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::location::GroundSpeed;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_encode_low_precision() {
@@ -109,8 +125,7 @@ mod tests {
 
         let (use_low_precision, encoded_speed) = ground_speed.into();
 
-        let expected_speed: u8 =
-            unsafe { f32::to_int_unchecked((speed - GroundSpeed::PRECISION_THRESHOLD) / 0.75) };
+        let expected_speed = ((speed - GroundSpeed::PRECISION_THRESHOLD) / 0.75) as u8;
 
         assert!(use_low_precision);
         assert_eq!(encoded_speed, expected_speed);
@@ -124,7 +139,7 @@ mod tests {
 
         let (use_low_precision, encoded_speed) = ground_speed.into();
 
-        let expected_speed: u8 = unsafe { f32::to_int_unchecked(speed / 0.25) };
+        let expected_speed = (speed / 0.25) as u8;
 
         assert!(!use_low_precision);
         assert_eq!(encoded_speed, expected_speed);
@@ -157,6 +172,24 @@ mod tests {
         assert_eq!(encoded_speed, GroundSpeed::MAX as u8);
     }
 
+    #[test]
+    fn test_encode_negative() {
+        let ground_speed = GroundSpeed::Known(-5.0);
+
+        assert_eq!(<(bool, u8)>::from(ground_speed), (false, 0));
+        assert_eq!(ground_speed.quantize(), GroundSpeed::Known(0.0));
+    }
+
+    #[test]
+    fn test_encode_nan() {
+        let ground_speed = GroundSpeed::Known(f32::NAN);
+
+        assert_eq!(
+            <(bool, u8)>::from(ground_speed),
+            <(bool, u8)>::from(GroundSpeed::Invalid)
+        );
+    }
+
     #[test]
     fn test_decode_low_precision() {
         let encoded = 1u8;
@@ -193,4 +226,16 @@ mod tests {
         assert!(!also_use_low_precision);
         assert_eq!(also_encoded_speed, expected);
     }
+
+    #[test]
+    fn test_quantize() {
+        let high_precision = GroundSpeed::Known(10.3);
+        let low_precision = GroundSpeed::Known(100.0);
+
+        assert_eq!(high_precision.quantize(), GroundSpeed::Known(10.25));
+        assert_eq!(low_precision.quantize(), GroundSpeed::Known(99.75));
+        assert!((low_precision.quantization_error().unwrap() - 0.25).abs() < 1e-4);
+        assert!(GroundSpeed::Known(70.5).is_lossless());
+        assert!(!high_precision.is_lossless());
+    }
 }
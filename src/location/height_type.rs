@@ -4,6 +4,7 @@ use crate::error::Error;
 ///
 /// Enumerates relative height based on takeoff height versus height above ground level (AGL).
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HeightType {
     /// Height relative to take-off altitude.
     TakeOff,
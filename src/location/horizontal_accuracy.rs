@@ -9,9 +9,10 @@
 /// Ideally, this would be fully enumerated, but since the values to enumerate are also numeric,
 /// writing out "EighteenPointFiveTwoKm" etc would be obnoxious.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HorizontalAccuracy {
-    /// Reserved.
-    Reserved,
+    /// Reserved, codes 13 to 15.
+    Reserved(u8),
     /// Unknown value (indicated by 0).
     Unknown,
     /// Known, valid value.
@@ -40,7 +41,7 @@ impl HorizontalAccuracy {
     /// return `accuracy`
     pub fn accuracy_meters(&self) -> f32 {
         match self {
-            Self::Unknown | Self::Reserved => Self::MAX,
+            Self::Unknown | Self::Reserved(_) => Self::MAX,
             Self::Known(n) => match n {
                 1 => Self::MAX,
                 2 => 7_408.0,
@@ -63,7 +64,7 @@ impl HorizontalAccuracy {
 impl From<u8> for HorizontalAccuracy {
     fn from(value: u8) -> Self {
         if value >= Self::RESERVED_THRESHOLD {
-            return Self::Reserved;
+            return Self::Reserved(value);
         }
 
         match value {
@@ -75,19 +76,15 @@ impl From<u8> for HorizontalAccuracy {
 
 impl From<HorizontalAccuracy> for u8 {
     fn from(value: HorizontalAccuracy) -> Self {
-        let value = match value {
-            HorizontalAccuracy::Reserved => HorizontalAccuracy::RESERVED_THRESHOLD,
-            HorizontalAccuracy::Unknown => HorizontalAccuracy::UNKNOWN_CODE,
-            HorizontalAccuracy::Known(n) => n,
-        };
-
         // we clamp the value in the event that a library consumer constructs an invalid state such
-        // as `HorizontalAccuracy::Known(13)`.
-        if value > HorizontalAccuracy::RESERVED_THRESHOLD {
-            return HorizontalAccuracy::RESERVED_THRESHOLD;
+        // as `HorizontalAccuracy::Known(14)`. the accuracy is encoded in four bits.
+        match value {
+            HorizontalAccuracy::Reserved(n) => {
+                n.clamp(HorizontalAccuracy::RESERVED_THRESHOLD, 0x0f)
+            }
+            HorizontalAccuracy::Unknown => HorizontalAccuracy::UNKNOWN_CODE,
+            HorizontalAccuracy::Known(n) => n.min(HorizontalAccuracy::RESERVED_THRESHOLD),
         }
-
-        value
     }
 }
 
@@ -171,7 +168,10 @@ mod tests {
 
         let decoded = HorizontalAccuracy::from(reserved_encoded);
 
-        assert_eq!(decoded, HorizontalAccuracy::Reserved);
+        assert_eq!(
+            decoded,
+            HorizontalAccuracy::Reserved(HorizontalAccuracy::RESERVED_THRESHOLD)
+        );
     }
 
     #[test]
@@ -182,4 +182,13 @@ mod tests {
 
         assert_eq!(decoded, HorizontalAccuracy::Unknown);
     }
+
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=0x0f {
+            assert_eq!(u8::from(HorizontalAccuracy::from(code)), code);
+        }
+
+        assert_eq!(u8::from(HorizontalAccuracy::Reserved(0)), 13);
+    }
 }
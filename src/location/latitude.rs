@@ -1,3 +1,5 @@
+use crate::quantize::Quantize;
+
 /// Latitude
 ///
 /// Specification calls for the decoded value to be a 64-bit floating point number and the encoded
@@ -9,6 +11,7 @@
 /// values dependent on one another and we have to draw the line somewhere and that line is right
 /// here.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Latitude {
     /// Invalid value.
     Invalid,
@@ -53,7 +56,7 @@ impl From<i32> for Latitude {
             return Self::Unknown;
         }
 
-        if value < Self::MIN || value > Self::MAX {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
             return Self::Invalid;
         }
 
@@ -67,9 +70,23 @@ impl From<Latitude> for i32 {
     }
 }
 
+impl Quantize for Latitude {
+    fn quantize(&self) -> Self {
+        i32::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::location::Latitude;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_inner_latitude() {
@@ -146,4 +163,14 @@ mod tests {
 
         assert_eq!(latitude, Latitude::Unknown);
     }
+
+    #[test]
+    fn test_quantize() {
+        let latitude = Latitude::Known(37.123_456_789);
+
+        assert_eq!(latitude.quantize(), Latitude::Known(37.123_456_7));
+        assert!(latitude.quantization_error().unwrap() < 1e-7);
+        assert!(!latitude.is_lossless());
+        assert!(latitude.quantize().is_lossless());
+    }
 }
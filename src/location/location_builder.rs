@@ -0,0 +1,376 @@
+use crate::error::Error;
+use crate::location::Altitude;
+use crate::location::GroundSpeed;
+use crate::location::HeightType;
+use crate::location::HorizontalAccuracy;
+use crate::location::Latitude;
+use crate::location::Location;
+use crate::location::Longitude;
+use crate::location::OperationalStatus;
+use crate::location::SpeedAccuracy;
+use crate::location::Timestamp;
+use crate::location::TimestampAccuracy;
+use crate::location::TrackDirection;
+use crate::location::VerticalAccuracy;
+use crate::location::VerticalSpeed;
+
+/// Location Builder
+///
+/// Builds a [`Location`] through named setters, see [`Location::builder`]. Fields which are not set
+/// default to their unknown value, and the operational status to
+/// [`OperationalStatus::Undeclared`].
+///
+/// The height type defaults to [`HeightType::TakeOff`], though it must be set explicitly alongside
+/// a known [`LocationBuilder::height`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LocationBuilder {
+    operational_status: OperationalStatus,
+    height_type: Option<HeightType>,
+    track_direction: TrackDirection,
+    speed: GroundSpeed,
+    vertical_speed: VerticalSpeed,
+    latitude: Latitude,
+    longitude: Longitude,
+    pressure_altitude: Altitude,
+    geodetic_altitude: Altitude,
+    height: Altitude,
+    vertical_accuracy: VerticalAccuracy,
+    horizontal_accuracy: HorizontalAccuracy,
+    altitude_accuracy: VerticalAccuracy,
+    speed_accuracy: SpeedAccuracy,
+    timestamp: Timestamp,
+    timestamp_accuracy: TimestampAccuracy,
+}
+
+impl LocationBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            operational_status: OperationalStatus::Undeclared,
+            height_type: None,
+            track_direction: TrackDirection::Unknown,
+            speed: GroundSpeed::Unknown,
+            vertical_speed: VerticalSpeed::Unknown,
+            latitude: Latitude::Unknown,
+            longitude: Longitude::Unknown,
+            pressure_altitude: Altitude::Unknown,
+            geodetic_altitude: Altitude::Unknown,
+            height: Altitude::Unknown,
+            vertical_accuracy: VerticalAccuracy::Unknown,
+            horizontal_accuracy: HorizontalAccuracy::Unknown,
+            altitude_accuracy: VerticalAccuracy::Unknown,
+            speed_accuracy: SpeedAccuracy::Unknown,
+            timestamp: Timestamp::Unknown,
+            timestamp_accuracy: TimestampAccuracy::Unknown,
+        }
+    }
+
+    /// Sets the operational status.
+    pub fn operational_status(mut self, operational_status: OperationalStatus) -> Self {
+        self.operational_status = operational_status;
+
+        self
+    }
+
+    /// Sets whether the height is above ground level or relative to takeoff altitude.
+    pub fn height_type(mut self, height_type: HeightType) -> Self {
+        self.height_type = Some(height_type);
+
+        self
+    }
+
+    /// Sets the angle relative to True North.
+    pub fn track_direction(mut self, track_direction: TrackDirection) -> Self {
+        self.track_direction = track_direction;
+
+        self
+    }
+
+    /// Sets the ground speed.
+    pub fn speed(mut self, speed: GroundSpeed) -> Self {
+        self.speed = speed;
+
+        self
+    }
+
+    /// Sets the vertical speed.
+    pub fn vertical_speed(mut self, vertical_speed: VerticalSpeed) -> Self {
+        self.vertical_speed = vertical_speed;
+
+        self
+    }
+
+    /// Sets the latitude angle.
+    pub fn latitude(mut self, latitude: Latitude) -> Self {
+        self.latitude = latitude;
+
+        self
+    }
+
+    /// Sets the longitude angle.
+    pub fn longitude(mut self, longitude: Longitude) -> Self {
+        self.longitude = longitude;
+
+        self
+    }
+
+    /// Sets the barometric pressure altitude.
+    pub fn pressure_altitude(mut self, pressure_altitude: Altitude) -> Self {
+        self.pressure_altitude = pressure_altitude;
+
+        self
+    }
+
+    /// Sets the distance above the WGS-84 ellipsoid.
+    pub fn geodetic_altitude(mut self, geodetic_altitude: Altitude) -> Self {
+        self.geodetic_altitude = geodetic_altitude;
+
+        self
+    }
+
+    /// Sets the height either above ground level or relative to takeoff altitude.
+    ///
+    /// A known height requires [`LocationBuilder::height_type`] to be set as well.
+    pub fn height(mut self, height: Altitude) -> Self {
+        self.height = height;
+
+        self
+    }
+
+    /// Sets the accuracy of the geodetic altitude.
+    pub fn vertical_accuracy(mut self, vertical_accuracy: VerticalAccuracy) -> Self {
+        self.vertical_accuracy = vertical_accuracy;
+
+        self
+    }
+
+    /// Sets the accuracy of the latitude and longitude.
+    pub fn horizontal_accuracy(mut self, horizontal_accuracy: HorizontalAccuracy) -> Self {
+        self.horizontal_accuracy = horizontal_accuracy;
+
+        self
+    }
+
+    /// Sets the accuracy of the barometric pressure altitude.
+    pub fn altitude_accuracy(mut self, altitude_accuracy: VerticalAccuracy) -> Self {
+        self.altitude_accuracy = altitude_accuracy;
+
+        self
+    }
+
+    /// Sets the accuracy of the ground speed.
+    pub fn speed_accuracy(mut self, speed_accuracy: SpeedAccuracy) -> Self {
+        self.speed_accuracy = speed_accuracy;
+
+        self
+    }
+
+    /// Sets the number of tenths of a second since the most recent hour.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = timestamp;
+
+        self
+    }
+
+    /// Sets the accuracy of the timestamp.
+    pub fn timestamp_accuracy(mut self, timestamp_accuracy: TimestampAccuracy) -> Self {
+        self.timestamp_accuracy = timestamp_accuracy;
+
+        self
+    }
+
+    /// Builds the location.
+    ///
+    /// Returns an error if:
+    ///
+    /// - the height is known but the height type was not set.
+    /// - [`Location::validate`] fails, for example when only one of the latitude and longitude is
+    ///   known, or when a speed is negative.
+    pub fn build(self) -> Result<Location, Error> {
+        let height_type = match (self.height, self.height_type) {
+            (Altitude::Known(_), None) => return Err(Error::MissingField),
+            (_, height_type) => height_type.unwrap_or(HeightType::TakeOff),
+        };
+
+        let location = Location::new(
+            self.operational_status,
+            height_type,
+            self.track_direction,
+            self.speed,
+            self.vertical_speed,
+            self.latitude,
+            self.longitude,
+            self.pressure_altitude,
+            self.geodetic_altitude,
+            self.height,
+            self.vertical_accuracy,
+            self.horizontal_accuracy,
+            self.altitude_accuracy,
+            self.speed_accuracy,
+            self.timestamp,
+            self.timestamp_accuracy,
+        );
+
+        location.validate()?;
+
+        Ok(location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        location::{
+            Altitude, GroundSpeed, HeightType, HorizontalAccuracy, Latitude, Location, Longitude,
+            OperationalStatus, SpeedAccuracy, Timestamp, TimestampAccuracy, TrackDirection,
+            VerticalAccuracy, VerticalSpeed,
+        },
+    };
+
+    #[test]
+    fn test_build() {
+        let location = Location::builder()
+            .operational_status(OperationalStatus::Airborne)
+            .height_type(HeightType::AGL)
+            .track_direction(TrackDirection::Known(90))
+            .speed(GroundSpeed::Known(5.25))
+            .vertical_speed(VerticalSpeed::Known(1.5))
+            .latitude(Latitude::Known(37.5))
+            .longitude(Longitude::Known(-122.25))
+            .pressure_altitude(Altitude::Known(110.0))
+            .geodetic_altitude(Altitude::Known(100.0))
+            .height(Altitude::Known(20.0))
+            .vertical_accuracy(VerticalAccuracy::Known(3))
+            .horizontal_accuracy(HorizontalAccuracy::Known(10))
+            .altitude_accuracy(VerticalAccuracy::Known(4))
+            .speed_accuracy(SpeedAccuracy::Known(2))
+            .timestamp(Timestamp::Known(100))
+            .timestamp_accuracy(TimestampAccuracy::Known(0.5))
+            .build()
+            .unwrap();
+
+        let expected = Location::new(
+            OperationalStatus::Airborne,
+            HeightType::AGL,
+            TrackDirection::Known(90),
+            GroundSpeed::Known(5.25),
+            VerticalSpeed::Known(1.5),
+            Latitude::Known(37.5),
+            Longitude::Known(-122.25),
+            Altitude::Known(110.0),
+            Altitude::Known(100.0),
+            Altitude::Known(20.0),
+            VerticalAccuracy::Known(3),
+            HorizontalAccuracy::Known(10),
+            VerticalAccuracy::Known(4),
+            SpeedAccuracy::Known(2),
+            Timestamp::Known(100),
+            TimestampAccuracy::Known(0.5),
+        );
+
+        assert_eq!(location, expected);
+    }
+
+    #[test]
+    fn test_build_defaults() {
+        let location = Location::builder().build().unwrap();
+
+        let expected = Location::new(
+            OperationalStatus::Undeclared,
+            HeightType::TakeOff,
+            TrackDirection::Unknown,
+            GroundSpeed::Unknown,
+            VerticalSpeed::Unknown,
+            Latitude::Unknown,
+            Longitude::Unknown,
+            Altitude::Unknown,
+            Altitude::Unknown,
+            Altitude::Unknown,
+            VerticalAccuracy::Unknown,
+            HorizontalAccuracy::Unknown,
+            VerticalAccuracy::Unknown,
+            SpeedAccuracy::Unknown,
+            Timestamp::Unknown,
+            TimestampAccuracy::Unknown,
+        );
+
+        assert_eq!(location, expected);
+    }
+
+    #[test]
+    fn test_build_fails_missing_height_type() {
+        let builder = Location::builder().height(Altitude::Known(20.0));
+
+        assert_eq!(builder.build(), Err(Error::MissingField));
+        assert!(builder.height_type(HeightType::TakeOff).build().is_ok());
+    }
+
+    #[test]
+    fn test_build_fails_inconsistent_coordinates() {
+        assert_eq!(
+            Location::builder().latitude(Latitude::Known(37.5)).build(),
+            Err(Error::InconsistentFields)
+        );
+        assert_eq!(
+            Location::builder()
+                .longitude(Longitude::Known(-122.25))
+                .build(),
+            Err(Error::InconsistentFields)
+        );
+    }
+
+    #[test]
+    fn test_build_fails_out_of_range() {
+        let builder = Location::builder()
+            .latitude(Latitude::Known(37.5))
+            .longitude(Longitude::Known(-122.25));
+
+        assert_eq!(
+            builder.latitude(Latitude::Known(90.5)).build(),
+            Err(Error::OutOfRange)
+        );
+        assert_eq!(
+            builder.longitude(Longitude::Known(-180.5)).build(),
+            Err(Error::OutOfRange)
+        );
+        assert_eq!(
+            builder.track_direction(TrackDirection::Known(360)).build(),
+            Err(Error::InvalidInteger)
+        );
+        assert_eq!(
+            builder
+                .timestamp(Timestamp::Known(Timestamp::MAX + 1))
+                .build(),
+            Err(Error::InvalidInteger)
+        );
+    }
+
+    #[test]
+    fn test_build_fails_speed_out_of_range() {
+        let builder = Location::builder();
+
+        for speed in [-5.0, f32::NAN, GroundSpeed::MAX + 1.0] {
+            assert_eq!(
+                builder.speed(GroundSpeed::Known(speed)).build(),
+                Err(Error::OutOfRange)
+            );
+        }
+
+        for vertical_speed in [-63.0, f32::NAN, VerticalSpeed::MAX + 1.0] {
+            assert_eq!(
+                builder
+                    .vertical_speed(VerticalSpeed::Known(vertical_speed))
+                    .build(),
+                Err(Error::OutOfRange)
+            );
+        }
+
+        assert!(
+            builder
+                .speed(GroundSpeed::Known(0.0))
+                .vertical_speed(VerticalSpeed::Known(-3.0))
+                .build()
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::error::Error;
+use crate::location::Altitude;
+use crate::location::GroundSpeed;
+use crate::location::HeightType;
+use crate::location::HorizontalAccuracy;
+use crate::location::Latitude;
+use crate::location::Location;
+use crate::location::Longitude;
+use crate::location::OperationalStatus;
+use crate::location::SpeedAccuracy;
+use crate::location::Timestamp;
+use crate::location::TimestampAccuracy;
+use crate::location::TrackDirection;
+use crate::location::VerticalAccuracy;
+use crate::location::VerticalSpeed;
+use crate::messages::ProtocolVersion;
+
+/// Borrowed Location Message
+///
+/// Zero-copy view of an encoded [`Location`], each getter decodes its field from the borrowed
+/// bytes on demand. The bytes are validated once, on construction, so the getters never fail.
+///
+/// Getters return the same field types as [`Location`], see [`Location::from`] for an owned copy.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LocationRef<'a> {
+    bytes: &'a [u8],
+    protocol_version: ProtocolVersion,
+}
+
+impl<'a> LocationRef<'a> {
+    /// Returns a copy which decodes as the given protocol version.
+    ///
+    /// Fields the version reserves decode to their zero value, see [`ProtocolVersion`].
+    pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = protocol_version;
+
+        self
+    }
+
+    /// Returns the operational status.
+    ///
+    /// May be used to signal an emergency.
+    pub fn operational_status(&self) -> OperationalStatus {
+        // INVARIANT: the operational status is validated on construction.
+        (self.bytes[0] >> 4 & 0b0000_1111)
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns whether the height is above ground level or relative to takeoff altitude.
+    pub fn height_type(&self) -> HeightType {
+        (self.bytes[0] >> 2 & 1)
+            .try_into()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the angle relative to True North.
+    pub fn track_direction(&self) -> TrackDirection {
+        (self.bytes[0] >> 1 & 1 != 0, self.bytes[1]).into()
+    }
+
+    /// Returns the ground speed.
+    pub fn speed(&self) -> GroundSpeed {
+        (self.bytes[0] & 1 != 0, self.bytes[2]).into()
+    }
+
+    /// Returns the vertical speed.
+    pub fn vertical_speed(&self) -> VerticalSpeed {
+        self.bytes[3].into()
+    }
+
+    /// Returns the latitude angle.
+    pub fn latitude(&self) -> Latitude {
+        i32::from_le_bytes([self.bytes[4], self.bytes[5], self.bytes[6], self.bytes[7]]).into()
+    }
+
+    /// Returns the longitude angle.
+    pub fn longitude(&self) -> Longitude {
+        i32::from_le_bytes([self.bytes[8], self.bytes[9], self.bytes[10], self.bytes[11]]).into()
+    }
+
+    /// Returns the barometric pressure altitude.
+    pub fn pressure_altitude(&self) -> Altitude {
+        u16::from_le_bytes([self.bytes[12], self.bytes[13]]).into()
+    }
+
+    /// Returns the distance above the WGS-84 ellipsoid.
+    pub fn geodetic_altitude(&self) -> Altitude {
+        u16::from_le_bytes([self.bytes[14], self.bytes[15]]).into()
+    }
+
+    /// Returns the height either above ground level or relative to takeoff altitude.
+    ///
+    /// Determine which it is through [`LocationRef::height_type`].
+    pub fn height(&self) -> Altitude {
+        u16::from_le_bytes([self.bytes[16], self.bytes[17]]).into()
+    }
+
+    /// Returns the accuracy of the geodetic altitude.
+    pub fn vertical_accuracy(&self) -> VerticalAccuracy {
+        (self.bytes[18] >> 4).into()
+    }
+
+    /// Returns the accuracy of the latitude and longitude.
+    pub fn horizontal_accuracy(&self) -> HorizontalAccuracy {
+        (self.bytes[18] & 0b0000_1111).into()
+    }
+
+    /// Returns the accuracy of the barometric pressure altitude.
+    pub fn altitude_accuracy(&self) -> VerticalAccuracy {
+        (self.bytes[19] >> 4).into()
+    }
+
+    /// Returns the accuracy of the ground speed.
+    pub fn speed_accuracy(&self) -> SpeedAccuracy {
+        (self.bytes[19] & 0b0000_1111).into()
+    }
+
+    /// Returns the number of tenths of a second since the most recent hour.
+    pub fn timestamp(&self) -> Timestamp {
+        u16::from_le_bytes([self.bytes[20], self.bytes[21]]).into()
+    }
+
+    /// Returns the accuracy of the timestamp.
+    ///
+    /// Always [`TimestampAccuracy::Unknown`] for [`ProtocolVersion::Version0`], which reserves it.
+    pub fn timestamp_accuracy(&self) -> TimestampAccuracy {
+        match self.protocol_version.has_timestamp_accuracy() {
+            true => (self.bytes[22] & 0b0000_1111).into(),
+            false => TimestampAccuracy::Unknown,
+        }
+    }
+
+    /// Returns the borrowed bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for LocationRef<'a> {
+    type Error = Error;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        if value.len() != 24 {
+            return Err(Error::InvalidDataLength);
+        }
+
+        OperationalStatus::try_from(value[0] >> 4 & 0b0000_1111)?;
+
+        Ok(Self {
+            bytes: value,
+            protocol_version: ProtocolVersion::default(),
+        })
+    }
+}
+
+impl From<LocationRef<'_>> for Location {
+    fn from(value: LocationRef<'_>) -> Self {
+        Self::new(
+            value.operational_status(),
+            value.height_type(),
+            value.track_direction(),
+            value.speed(),
+            value.vertical_speed(),
+            value.latitude(),
+            value.longitude(),
+            value.pressure_altitude(),
+            value.geodetic_altitude(),
+            value.height(),
+            value.vertical_accuracy(),
+            value.horizontal_accuracy(),
+            value.altitude_accuracy(),
+            value.speed_accuracy(),
+            value.timestamp(),
+            value.timestamp_accuracy(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        location::{
+            Altitude, GroundSpeed, HeightType, HorizontalAccuracy, Latitude, Location, LocationRef,
+            Longitude, OperationalStatus, SpeedAccuracy, Timestamp, TimestampAccuracy,
+            TrackDirection, VerticalAccuracy, VerticalSpeed,
+        },
+        messages::ProtocolVersion,
+        try_serialize::TrySerialize,
+    };
+
+    fn location() -> Location {
+        Location::new(
+            OperationalStatus::Airborne,
+            HeightType::TakeOff,
+            TrackDirection::Known(90),
+            GroundSpeed::Known(5.25),
+            VerticalSpeed::Known(1.5),
+            Latitude::Known(37.5),
+            Longitude::Known(-122.25),
+            Altitude::Known(110.0),
+            Altitude::Known(100.0),
+            Altitude::Known(20.0),
+            VerticalAccuracy::Unknown,
+            HorizontalAccuracy::Unknown,
+            VerticalAccuracy::Unknown,
+            SpeedAccuracy::Unknown,
+            Timestamp::Known(100),
+            TimestampAccuracy::Known(0.5),
+        )
+    }
+
+    fn encoded() -> [u8; 24] {
+        let mut encoded = [0u8; 24];
+        location().try_serialize(&mut encoded).unwrap();
+
+        encoded
+    }
+
+    #[test]
+    fn test_getters() {
+        let encoded = encoded();
+
+        let location_ref = LocationRef::try_from(encoded.as_ref()).unwrap();
+        let location = location();
+
+        assert_eq!(
+            location_ref.operational_status(),
+            location.operational_status()
+        );
+        assert_eq!(location_ref.height_type(), location.height_type());
+        assert_eq!(location_ref.track_direction(), location.track_direction());
+        assert_eq!(location_ref.speed(), location.speed());
+        assert_eq!(location_ref.vertical_speed(), location.vertical_speed());
+        assert_eq!(location_ref.latitude(), location.latitude());
+        assert_eq!(location_ref.longitude(), location.longitude());
+        assert_eq!(
+            location_ref.pressure_altitude(),
+            location.pressure_altitude()
+        );
+        assert_eq!(
+            location_ref.geodetic_altitude(),
+            location.geodetic_altitude()
+        );
+        assert_eq!(location_ref.height(), location.height());
+        assert_eq!(
+            location_ref.vertical_accuracy(),
+            location.vertical_accuracy()
+        );
+        assert_eq!(
+            location_ref.horizontal_accuracy(),
+            location.horizontal_accuracy()
+        );
+        assert_eq!(
+            location_ref.altitude_accuracy(),
+            location.altitude_accuracy()
+        );
+        assert_eq!(location_ref.speed_accuracy(), location.speed_accuracy());
+        assert_eq!(location_ref.timestamp(), location.timestamp());
+        assert_eq!(
+            location_ref.timestamp_accuracy(),
+            location.timestamp_accuracy()
+        );
+        assert_eq!(location_ref.as_bytes(), encoded.as_ref());
+    }
+
+    #[test]
+    fn test_decode() {
+        let encoded = encoded();
+
+        let location_ref = LocationRef::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(Location::from(location_ref), location());
+        assert_eq!(Location::try_from(encoded.as_ref()).unwrap(), location());
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let too_short = [0u8; 23];
+        let too_long = [0u8; 25];
+
+        assert_eq!(
+            LocationRef::try_from(too_short.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            LocationRef::try_from(too_long.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_with_protocol_version() {
+        let encoded = encoded();
+
+        let location_ref = LocationRef::try_from(encoded.as_ref())
+            .unwrap()
+            .with_protocol_version(ProtocolVersion::Version0);
+
+        assert_eq!(
+            location_ref.timestamp_accuracy(),
+            TimestampAccuracy::Unknown
+        );
+        assert_eq!(location_ref.latitude(), Latitude::Known(37.5));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::quantize::Quantize;
+
 /// Longitude
 ///
 /// Specification calls for the decoded value to be a 64-bit floating point number and the encoded
@@ -9,6 +11,7 @@
 /// values dependent on one another and we have to draw the line somewhere and that line is right
 /// here.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Longitude {
     /// Invalid value (beyond the -180 to 180 degree bound).
     Invalid,
@@ -53,7 +56,7 @@ impl From<i32> for Longitude {
             return Self::Unknown;
         }
 
-        if value < Self::MIN || value > Self::MAX {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
             return Self::Invalid;
         }
 
@@ -67,9 +70,23 @@ impl From<Longitude> for i32 {
     }
 }
 
+impl Quantize for Longitude {
+    fn quantize(&self) -> Self {
+        i32::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::location::Longitude;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_inner_longitude() {
@@ -146,4 +163,14 @@ mod tests {
 
         assert_eq!(longitude, Longitude::Unknown);
     }
+
+    #[test]
+    fn test_quantize() {
+        let longitude = Longitude::Known(-122.123_456_789);
+
+        assert_eq!(longitude.quantize(), Longitude::Known(-122.123_456_7));
+        assert!(longitude.quantization_error().unwrap() < 1e-7);
+        assert!(!longitude.is_lossless());
+        assert!(longitude.quantize().is_lossless());
+    }
 }
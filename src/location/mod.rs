@@ -38,11 +38,31 @@
 //! - `speed_accuracy` is the accuracy of `speed`.
 //! - `timestamp` is the number of tenths of a second since the most recent hour.
 //! - `timestamp_accuracy` is the accuracy of the `timestamp`.
+//!
+//! ### On Timestamps
+//!
+//! The [`Timestamp`] only counts the tenths of a second since the start of the hour. Receivers
+//! resolve it against their own clock through [`Location::absolute_time`], which picks the nearest
+//! hour and widens the result by the [`TimestampAccuracy`] into an [`AbsoluteTime`]. Transmitters
+//! construct it with [`Timestamp::from_unix_time`].
+//!
+//! ### Building
+//!
+//! [`Location::builder`] sets each field by name, rather than by its position among the sixteen
+//! arguments of [`Location::new`], several of which share a type. Fields left unset are unknown.
+//!
+//! ### Borrowed Decoding
+//!
+//! [`LocationRef`] reads the same fields straight from the encoded bytes, without copying them into
+//! a [`Location`]. It suits receivers which only look at a few fields of each message.
+mod absolute_time;
 mod altitude;
 mod ground_speed;
 mod height_type;
 mod horizontal_accuracy;
 mod latitude;
+mod location_builder;
+mod location_ref;
 mod longitude;
 mod operational_status;
 mod speed_accuracy;
@@ -52,11 +72,14 @@ mod track_direction;
 mod vertical_accuracy;
 mod vertical_speed;
 
+pub use absolute_time::AbsoluteTime;
 pub use altitude::Altitude;
 pub use ground_speed::GroundSpeed;
 pub use height_type::HeightType;
 pub use horizontal_accuracy::HorizontalAccuracy;
 pub use latitude::Latitude;
+pub use location_builder::LocationBuilder;
+pub use location_ref::LocationRef;
 pub use longitude::Longitude;
 pub use operational_status::OperationalStatus;
 pub use speed_accuracy::SpeedAccuracy;
@@ -67,6 +90,12 @@ pub use vertical_accuracy::VerticalAccuracy;
 pub use vertical_speed::VerticalSpeed;
 
 use crate::error::Error;
+#[cfg(feature = "geo")]
+use crate::geo::Position;
+use crate::messages::ProtocolVersion;
+use crate::quantize::Quantize;
+#[cfg(feature = "geo")]
+use crate::system::System;
 use crate::try_serialize::TrySerialize;
 
 /// Location Message
@@ -74,6 +103,7 @@ use crate::try_serialize::TrySerialize;
 /// Contains information on the aircraft's location, speed, direction, and accuracy of each
 /// measurement.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     operational_status: OperationalStatus,
     height_type: HeightType,
@@ -94,7 +124,11 @@ pub struct Location {
 }
 
 impl Location {
+    /// Message code for location messages is `0x01`.
+    pub const LOCATION_MESSAGE_CODE: u8 = 0x01;
+
     /// Constructs a new Location.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         operational_status: OperationalStatus,
         height_type: HeightType,
@@ -133,6 +167,11 @@ impl Location {
         }
     }
 
+    /// Returns a builder which names each field, see [`LocationBuilder`].
+    pub fn builder() -> LocationBuilder {
+        LocationBuilder::new()
+    }
+
     /// Returns the operational status.
     ///
     /// May be used to signal an emergency.
@@ -216,72 +255,150 @@ impl Location {
     pub fn timestamp_accuracy(&self) -> TimestampAccuracy {
         self.timestamp_accuracy
     }
-}
 
-impl TryFrom<&[u8]> for Location {
-    type Error = Error;
+    /// Resolves the timestamp into an absolute time against a receiver clock reading `unix_time`,
+    /// in seconds, see [`Timestamp::resolve`].
+    ///
+    /// Returns `None` if the timestamp is not known or lies further than `tolerance` seconds from
+    /// `unix_time`.
+    pub fn absolute_time(&self, unix_time: u64, tolerance: u64) -> Option<AbsoluteTime> {
+        let unix_time_tenths = self.timestamp.resolve(unix_time, tolerance)?;
 
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        if value.len() != 24 {
-            return Err(Error::InvalidDataLength);
-        }
+        Some(AbsoluteTime::new(unix_time_tenths, self.timestamp_accuracy))
+    }
 
-        let operational_status = (value[0] >> 4 & 0b0000_1111).try_into()?;
+    /// Checks that every known field lies within the range its encoding can represent.
+    ///
+    /// Encoding clamps such fields instead, see [`LocationBuilder::build`] for the checked
+    /// construction.
+    ///
+    /// Returns an error if:
+    ///
+    /// - only one of the latitude and longitude is known.
+    /// - the latitude, longitude, ground speed, or vertical speed is known but out of range, or not
+    ///   a number.
+    /// - the track direction or timestamp is known but out of range.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_coordinates(self.latitude, self.longitude)?;
+
+        let speed_out_of_range = matches!(
+            self.speed,
+            GroundSpeed::Known(speed) if !(0.0..=GroundSpeed::MAX).contains(&speed)
+        );
 
-        let height_type: HeightType = (value[0] >> 2 & 1)
-            .try_into()
-            .map_err(|_| Error::Unreachable)
-            .unwrap();
+        let vertical_speed_out_of_range = matches!(
+            self.vertical_speed,
+            VerticalSpeed::Known(vertical_speed)
+                if !(VerticalSpeed::MIN..=VerticalSpeed::MAX).contains(&vertical_speed)
+        );
 
-        let east_west_bit = value[0] >> 1 & 1 != 0;
-        let ground_speed_use_low_precision = value[0] & 1 != 0;
+        if speed_out_of_range || vertical_speed_out_of_range {
+            return Err(Error::OutOfRange);
+        }
 
-        let track_direction = (east_west_bit, value[1]).into();
+        let track_direction_out_of_range = matches!(
+            self.track_direction,
+            TrackDirection::Known(track_direction) if track_direction >= 360
+        );
 
-        let speed = (ground_speed_use_low_precision, value[2]).into();
+        let timestamp_out_of_range = matches!(
+            self.timestamp,
+            Timestamp::Known(timestamp) if timestamp > Timestamp::MAX
+        );
 
-        let vertical_speed = value[3].into();
+        if track_direction_out_of_range || timestamp_out_of_range {
+            return Err(Error::InvalidInteger);
+        }
 
-        let latitude = i32::from_le_bytes([value[4], value[5], value[6], value[7]]).into();
+        Ok(())
+    }
 
-        let longitude = i32::from_le_bytes([value[8], value[9], value[10], value[11]]).into();
+    /// Returns a copy with each field quantized, see [`Quantize`].
+    pub fn normalized(&self) -> Self {
+        Self {
+            track_direction: self.track_direction.quantize(),
+            speed: self.speed.quantize(),
+            vertical_speed: self.vertical_speed.quantize(),
+            latitude: self.latitude.quantize(),
+            longitude: self.longitude.quantize(),
+            pressure_altitude: self.pressure_altitude.quantize(),
+            geodetic_altitude: self.geodetic_altitude.quantize(),
+            height: self.height.quantize(),
+            timestamp_accuracy: self.timestamp_accuracy.quantize(),
+            ..*self
+        }
+    }
 
-        let pressure_altitude = u16::from_le_bytes([value[12], value[13]]).into();
+    // clears the fields `protocol_version` reserves, as they decode.
+    pub(crate) fn conformed(&self, protocol_version: ProtocolVersion) -> Self {
+        match protocol_version.has_timestamp_accuracy() {
+            true => *self,
+            false => Self {
+                timestamp_accuracy: TimestampAccuracy::Unknown,
+                ..*self
+            },
+        }
+    }
+}
 
-        let geodetic_altitude = u16::from_le_bytes([value[14], value[15]]).into();
+// checks a pair of coordinates, of the UA or of its operator, which are either both known or both
+// not, and lie within range when known.
+pub(crate) fn check_coordinates(latitude: Latitude, longitude: Longitude) -> Result<(), Error> {
+    match (latitude, longitude) {
+        (Latitude::Known(_), Longitude::Known(_)) => (),
+        (Latitude::Known(_), _) | (_, Longitude::Known(_)) => {
+            return Err(Error::InconsistentFields);
+        }
+        _ => (),
+    }
 
-        let height = u16::from_le_bytes([value[16], value[17]]).into();
+    let latitude_out_of_range = matches!(
+        latitude,
+        Latitude::Known(latitude) if !(Latitude::MIN..=Latitude::MAX).contains(&latitude)
+    );
 
-        let vertical_accuracy = (value[18] >> 4).into();
+    let longitude_out_of_range = matches!(
+        longitude,
+        Longitude::Known(longitude) if !(Longitude::MIN..=Longitude::MAX).contains(&longitude)
+    );
 
-        let horizontal_accuracy = (value[18] & 0b0000_1111).into();
+    if latitude_out_of_range || longitude_out_of_range {
+        return Err(Error::OutOfRange);
+    }
 
-        let altitude_accuracy = (value[19] >> 4).into();
+    Ok(())
+}
 
-        let speed_accuracy = (value[19] & 0b0000_1111).into();
+#[cfg(feature = "geo")]
+impl Location {
+    /// Returns the position of the UA, at its geodetic altitude.
+    pub fn position(&self) -> Position {
+        Position::new(self.latitude, self.longitude, self.geodetic_altitude)
+    }
 
-        let timestamp = u16::from_le_bytes([value[20], value[21]]).into();
+    /// Returns the distance in meters from the UA to the operator of `system`.
+    ///
+    /// See [`Position::distance_to`].
+    pub fn distance_to_operator(&self, system: &System) -> Option<f64> {
+        self.position().distance_to(&system.operator_position())
+    }
 
-        let timestamp_accuracy = (value[22] & 0b0000_1111).into();
+    /// Returns the bearing in degrees from the UA to the operator of `system`.
+    pub fn bearing_to_operator(&self, system: &System) -> Option<f64> {
+        self.position().bearing_to(&system.operator_position())
+    }
 
-        Ok(Self {
-            operational_status,
-            height_type,
-            track_direction,
-            speed,
-            vertical_speed,
-            latitude,
-            longitude,
-            pressure_altitude,
-            geodetic_altitude,
-            height,
-            vertical_accuracy,
-            horizontal_accuracy,
-            altitude_accuracy,
-            speed_accuracy,
-            timestamp,
-            timestamp_accuracy,
-        })
+    /// Returns the straight line distance in meters from the UA to the operator of `system`.
+    pub fn slant_range_to_operator(&self, system: &System) -> Option<f64> {
+        self.position().slant_range_to(&system.operator_position())
+    }
+}
+
+impl TryFrom<&[u8]> for Location {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        LocationRef::try_from(value).map(Self::from)
     }
 }
 
@@ -569,4 +686,41 @@ mod tests {
         assert!(Location::try_from(too_short.as_ref()).is_err());
         assert!(Location::try_from(too_long.as_ref()).is_err());
     }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn test_distance_to_operator() {
+        let location = Location::builder()
+            .latitude(Latitude::Known(0.0))
+            .longitude(Longitude::Known(1.0))
+            .geodetic_altitude(Altitude::Known(120.0))
+            .build()
+            .unwrap();
+
+        let system = crate::system::System::builder()
+            .operator_latitude(Latitude::Known(0.0))
+            .operator_longitude(Longitude::Known(0.999))
+            .operator_altitude(Altitude::Known(20.0))
+            .timestamp(1_000.into())
+            .build()
+            .unwrap();
+
+        let distance = location.distance_to_operator(&system).unwrap();
+
+        assert!((distance - 111.319).abs() < 0.001);
+        assert_eq!(location.bearing_to_operator(&system), Some(270.0));
+        assert!(
+            (location.slant_range_to_operator(&system).unwrap()
+                - libm::sqrt(distance * distance + 100.0 * 100.0))
+            .abs()
+                < 1e-9
+        );
+
+        let unknown_operator = crate::system::System::builder()
+            .timestamp(1_000.into())
+            .build()
+            .unwrap();
+
+        assert_eq!(location.distance_to_operator(&unknown_operator), None);
+    }
 }
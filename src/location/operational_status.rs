@@ -2,6 +2,7 @@ use crate::error::Error;
 
 /// Operational Status
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OperationalStatus {
     /// Undeclared status.
     Undeclared,
@@ -13,8 +14,8 @@ pub enum OperationalStatus {
     Emergency,
     /// UAS Remote ID system is failing.
     RemoteIDSystemFailure,
-    /// Reserved.
-    Reserved,
+    /// Reserved, codes 5 to 15.
+    Reserved(u8),
 }
 
 impl OperationalStatus {
@@ -27,7 +28,17 @@ impl OperationalStatus {
 
 impl From<OperationalStatus> for u8 {
     fn from(value: OperationalStatus) -> Self {
-        value as u8
+        match value {
+            OperationalStatus::Undeclared => 0,
+            OperationalStatus::Ground => 1,
+            OperationalStatus::Airborne => 2,
+            OperationalStatus::Emergency => 3,
+            OperationalStatus::RemoteIDSystemFailure => 4,
+            OperationalStatus::Reserved(n) => n.clamp(
+                OperationalStatus::RESERVED_THRESHOLD,
+                OperationalStatus::MAX,
+            ),
+        }
     }
 }
 
@@ -45,7 +56,7 @@ impl TryFrom<u8> for OperationalStatus {
             2 => Ok(Self::Airborne),
             3 => Ok(Self::Emergency),
             4 => Ok(Self::RemoteIDSystemFailure),
-            _ => Ok(Self::Reserved),
+            n => Ok(Self::Reserved(n)),
         }
     }
 }
@@ -76,7 +87,32 @@ mod tests {
 
         let decoded = OperationalStatus::try_from(reserved).unwrap();
 
-        assert_eq!(decoded, OperationalStatus::Reserved);
+        assert_eq!(
+            decoded,
+            OperationalStatus::Reserved(OperationalStatus::RESERVED_THRESHOLD)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_reserved_is_lossless() {
+        for code in OperationalStatus::RESERVED_THRESHOLD..=OperationalStatus::MAX {
+            let decoded = OperationalStatus::try_from(code).unwrap();
+
+            assert_eq!(decoded, OperationalStatus::Reserved(code));
+            assert_eq!(u8::from(decoded), code);
+        }
+    }
+
+    #[test]
+    fn test_encode_reserved_clamps_invalid_value() {
+        assert_eq!(
+            u8::from(OperationalStatus::Reserved(0)),
+            OperationalStatus::RESERVED_THRESHOLD
+        );
+        assert_eq!(
+            u8::from(OperationalStatus::Reserved(0xff)),
+            OperationalStatus::MAX
+        );
     }
 
     #[test]
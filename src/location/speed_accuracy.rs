@@ -6,9 +6,10 @@
 /// Ideally, this would be fully enumerated, but since the values to enumerate are also numeric,
 /// writing out "ZeroPointThree" etc would be obnoxious.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpeedAccuracy {
-    /// Reserved.
-    Reserved,
+    /// Reserved, codes 5 to 15.
+    Reserved(u8),
     /// Unknown value (indicated by 0).
     Unknown,
     /// Known, valid value.
@@ -28,9 +29,8 @@ impl SpeedAccuracy {
     /// Returns the raw enumerated code.
     pub fn code(&self) -> u8 {
         match self {
-            Self::Known(n) => *n,
+            Self::Known(n) | Self::Reserved(n) => *n,
             Self::Unknown => Self::UNKNOWN_CODE,
-            Self::Reserved => Self::RESERVED_THRESHOLD,
         }
     }
 
@@ -40,7 +40,7 @@ impl SpeedAccuracy {
     /// is unknown. All other values returned implies the accuracy is less than the returned value.
     pub fn meters_per_second(&self) -> f32 {
         match self {
-            Self::Unknown | Self::Reserved => Self::MAX,
+            Self::Unknown | Self::Reserved(_) => Self::MAX,
             Self::Known(n) => match n {
                 1 => 10.0,
                 2 => 3.0,
@@ -55,7 +55,7 @@ impl SpeedAccuracy {
 impl From<u8> for SpeedAccuracy {
     fn from(value: u8) -> Self {
         if value >= Self::RESERVED_THRESHOLD {
-            return Self::Reserved;
+            return Self::Reserved(value);
         }
 
         match value {
@@ -70,7 +70,8 @@ impl From<SpeedAccuracy> for u8 {
         // clamp the value down to max valid value in case a library consumer constructs an invalid
         // state manually.
         match value {
-            SpeedAccuracy::Reserved => SpeedAccuracy::RESERVED_THRESHOLD,
+            // the accuracy is encoded in four bits.
+            SpeedAccuracy::Reserved(n) => n.clamp(SpeedAccuracy::RESERVED_THRESHOLD, 0x0f),
             SpeedAccuracy::Unknown => SpeedAccuracy::UNKNOWN_CODE,
             SpeedAccuracy::Known(n) => n.clamp(0, SpeedAccuracy::RESERVED_THRESHOLD),
         }
@@ -89,7 +90,7 @@ mod tests {
             SpeedAccuracy::MAX
         );
         assert_eq!(
-            SpeedAccuracy::Reserved.meters_per_second(),
+            SpeedAccuracy::Reserved(5).meters_per_second(),
             SpeedAccuracy::MAX
         );
     }
@@ -103,7 +104,7 @@ mod tests {
 
     #[test]
     fn test_encode_reserved() {
-        let reserved = SpeedAccuracy::Reserved;
+        let reserved = SpeedAccuracy::Reserved(SpeedAccuracy::RESERVED_THRESHOLD);
 
         assert_eq!(u8::from(reserved), SpeedAccuracy::RESERVED_THRESHOLD);
     }
@@ -133,7 +134,10 @@ mod tests {
     fn test_decode_reserved() {
         let reserved = SpeedAccuracy::from(SpeedAccuracy::RESERVED_THRESHOLD);
 
-        assert_eq!(reserved, SpeedAccuracy::Reserved);
+        assert_eq!(
+            reserved,
+            SpeedAccuracy::Reserved(SpeedAccuracy::RESERVED_THRESHOLD)
+        );
     }
 
     #[test]
@@ -142,4 +146,13 @@ mod tests {
 
         assert_eq!(unknown, SpeedAccuracy::Unknown);
     }
+
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=0x0f {
+            assert_eq!(u8::from(SpeedAccuracy::from(code)), code);
+        }
+
+        assert_eq!(SpeedAccuracy::Reserved(0x0f).code(), 0x0f);
+    }
 }
@@ -1,8 +1,11 @@
+use crate::system;
+
 /// Timestamp for Location Messages
 ///
 /// Differs from [`crate::system::Timestamp`], as this encapsulates a 16-bit unsigned
 /// integer representing the number of tenths of a second since the start of the current hour.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Timestamp {
     /// Invalid value (greater than 36,000).
     Invalid,
@@ -21,6 +24,68 @@ impl Timestamp {
     /// Maximum number of seconds since the last hour.
     pub const MAX: u16 = 36_000;
 
+    /// Number of tenths of a second in an hour.
+    pub const TENTHS_PER_HOUR: u64 = 36_000;
+
+    /// Constructs the timestamp of a Unix time, in seconds.
+    pub fn from_unix_time(unix_time: u64) -> Self {
+        // whole hours are dropped first, so the conversion to tenths cannot overflow.
+        Self::from_unix_time_tenths(unix_time % (Self::TENTHS_PER_HOUR / 10) * 10)
+    }
+
+    /// Constructs the timestamp of a Unix time, in tenths of a second.
+    ///
+    /// Unix time ignores leap seconds, so its hours start on a multiple of
+    /// [`Timestamp::TENTHS_PER_HOUR`].
+    pub fn from_unix_time_tenths(unix_time_tenths: u64) -> Self {
+        Self::Known((unix_time_tenths % Self::TENTHS_PER_HOUR) as u16)
+    }
+
+    /// Resolves the timestamp into a Unix time, in tenths of a second, against a receiver clock
+    /// reading `unix_time`, in seconds.
+    ///
+    /// The timestamp is placed in the hour which brings it closest to `unix_time`, so a timestamp
+    /// from the end of the previous hour, or the start of the next one, resolves across the hour
+    /// rollover.
+    ///
+    /// Returns `None` if the timestamp is not known, or if it resolves further than `tolerance`
+    /// seconds from `unix_time`.
+    pub fn resolve(&self, unix_time: u64, tolerance: u64) -> Option<u64> {
+        let tenths = match self {
+            Self::Known(tenths) if *tenths <= Self::MAX => *tenths as u64,
+            _ => return None,
+        };
+
+        let reference = unix_time.saturating_mul(10);
+
+        let hour = reference - reference % Self::TENTHS_PER_HOUR;
+
+        let resolved = [
+            hour.checked_sub(Self::TENTHS_PER_HOUR),
+            Some(hour),
+            hour.checked_add(Self::TENTHS_PER_HOUR),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|hour| hour.checked_add(tenths))
+        .min_by_key(|resolved| resolved.abs_diff(reference))?;
+
+        match resolved.abs_diff(reference) <= tolerance.saturating_mul(10) {
+            true => Some(resolved),
+            false => None,
+        }
+    }
+
+    /// Resolves the timestamp against a receiver clock reading as a [`system::Timestamp`], see
+    /// [`Timestamp::resolve`].
+    pub fn resolve_system_time(
+        &self,
+        system_time: system::Timestamp,
+        tolerance: u64,
+    ) -> Option<u64> {
+        self.resolve(system_time.unix_time(), tolerance)
+    }
+
     /// Returns the inner timestamp value.
     pub fn timestamp(&self) -> u16 {
         let value = match self {
@@ -63,6 +128,10 @@ impl From<Timestamp> for u16 {
 #[cfg(test)]
 mod tests {
     use crate::location::Timestamp;
+    use crate::system;
+
+    // 2024-01-01T12:00:00Z, the top of an hour.
+    const HOUR: u64 = 1_704_110_400;
 
     #[test]
     fn test_timestamp() {
@@ -120,4 +189,73 @@ mod tests {
 
         assert_eq!(decoded, Timestamp::Invalid);
     }
+
+    #[test]
+    fn test_from_unix_time() {
+        assert_eq!(Timestamp::from_unix_time(HOUR), Timestamp::Known(0));
+        assert_eq!(Timestamp::from_unix_time(HOUR + 61), Timestamp::Known(610));
+        assert_eq!(
+            Timestamp::from_unix_time_tenths(HOUR * 10 + 35_999),
+            Timestamp::Known(35_999)
+        );
+        assert_eq!(
+            Timestamp::from_unix_time(u64::MAX),
+            Timestamp::Known((u64::MAX % 3_600 * 10) as u16)
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let timestamp = Timestamp::Known(610);
+
+        assert_eq!(timestamp.resolve(HOUR + 62, 5), Some(HOUR * 10 + 610));
+        assert_eq!(
+            timestamp.resolve_system_time(system::Timestamp::from_unix_time(HOUR + 62), 5),
+            Some(HOUR * 10 + 610)
+        );
+    }
+
+    #[test]
+    fn test_resolve_rollover() {
+        // sent just before the hour, received just after it.
+        assert_eq!(
+            Timestamp::Known(35_990).resolve(HOUR + 1, 5),
+            Some(HOUR * 10 - 10)
+        );
+
+        // sent just after the hour, received by a clock running behind.
+        assert_eq!(
+            Timestamp::Known(10).resolve(HOUR - 1, 5),
+            Some(HOUR * 10 + 10)
+        );
+    }
+
+    #[test]
+    fn test_resolve_fails_tolerance() {
+        let timestamp = Timestamp::Known(610);
+
+        assert_eq!(timestamp.resolve(HOUR + 600, 60), None);
+        assert_eq!(timestamp.resolve(HOUR + 600, 600), Some(HOUR * 10 + 610));
+    }
+
+    #[test]
+    fn test_resolve_fails_unknown() {
+        for timestamp in [Timestamp::Unknown, Timestamp::NoValue, Timestamp::Invalid] {
+            assert_eq!(timestamp.resolve(HOUR, 3_600), None);
+        }
+    }
+
+    #[test]
+    fn test_resolve_does_not_overflow() {
+        let reference = u64::MAX - u64::MAX % Timestamp::TENTHS_PER_HOUR;
+
+        assert_eq!(
+            Timestamp::Known(0).resolve(u64::MAX, u64::MAX),
+            Some(reference)
+        );
+        assert_eq!(
+            Timestamp::Known(35_999).resolve(u64::MAX, u64::MAX),
+            Some(reference - 1)
+        );
+    }
 }
@@ -1,8 +1,11 @@
+use crate::quantize::Quantize;
+
 /// Timestamp Accuracy
 ///
 /// Accuracy is measured from a range of 0.1s to 1.5s, anything beyond these bounds are labelled
 /// "unknown".
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimestampAccuracy {
     /// Unknown value (indicated by a number greater than 15).
     Unknown,
@@ -38,9 +41,23 @@ impl From<TimestampAccuracy> for u8 {
     }
 }
 
+impl Quantize for TimestampAccuracy {
+    fn quantize(&self) -> Self {
+        u8::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::location::TimestampAccuracy;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_accuracy() {
@@ -75,4 +92,13 @@ mod tests {
 
         assert_eq!(decoded, TimestampAccuracy::Unknown);
     }
+
+    #[test]
+    fn test_quantize() {
+        let accuracy = TimestampAccuracy::Known(0.25);
+
+        assert_eq!(accuracy.quantize(), TimestampAccuracy::Known(0.2));
+        assert!((accuracy.quantization_error().unwrap() - 0.05).abs() < 1e-4);
+        assert!(TimestampAccuracy::Known(2.0).quantize() == TimestampAccuracy::Known(1.5));
+    }
 }
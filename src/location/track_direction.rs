@@ -1,11 +1,14 @@
 use core::cmp::Ordering;
 
+use crate::quantize::Quantize;
+
 /// Flight Direction
 ///
 /// Measured from True North, clockwise degrees with a resolution of 1 degree. The
 /// [`TrackDirection::Unknown`] value is 361 degrees. If the aircraft is not moving horizontally,
 /// return the unknown value.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrackDirection {
     /// Invalid value (values greater than 361).
     Invalid,
@@ -73,9 +76,23 @@ impl From<TrackDirection> for (bool, u8) {
     }
 }
 
+impl Quantize for TrackDirection {
+    fn quantize(&self) -> Self {
+        <(bool, u8)>::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::location::TrackDirection;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_direction() {
@@ -161,4 +178,16 @@ mod tests {
 
         assert_eq!(decoded, TrackDirection::Invalid);
     }
+
+    #[test]
+    fn test_quantize() {
+        assert!(TrackDirection::Known(359).is_lossless());
+        assert_eq!(TrackDirection::Known(359).quantization_error(), Some(0.0));
+        assert_eq!(
+            TrackDirection::Known(400).quantize(),
+            TrackDirection::Invalid
+        );
+        assert_eq!(TrackDirection::Known(400).quantization_error(), None);
+        assert_eq!(TrackDirection::NoValue.quantize(), TrackDirection::Invalid);
+    }
 }
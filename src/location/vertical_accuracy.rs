@@ -9,9 +9,10 @@
 /// Ideally, this would be fully enumerated, but since the values to enumerate are also numeric,
 /// writing out "OneHundredFiftyM" etc would be obnoxious.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalAccuracy {
-    /// Reserved.
-    Reserved,
+    /// Reserved, codes 7 to 15.
+    Reserved(u8),
     /// Unknown value (indicated by 0).
     Unknown,
     /// Known, valid value.
@@ -31,8 +32,8 @@ impl VerticalAccuracy {
     /// Returns the raw enumerated code.
     pub fn code(&self) -> u8 {
         match self {
-            Self::Known(n) => *n,
-            Self::Unknown | Self::Reserved => 0,
+            Self::Known(n) | Self::Reserved(n) => *n,
+            Self::Unknown => Self::UNKNOWN_CODE,
         }
     }
 
@@ -45,7 +46,7 @@ impl VerticalAccuracy {
     /// treat the value the same as if it were [`VerticalAccuracy::Reserved`].
     pub fn accuracy(&self) -> f32 {
         match self {
-            Self::Unknown | Self::Reserved => Self::MAX,
+            Self::Unknown | Self::Reserved(_) => Self::MAX,
             Self::Known(n) => match n {
                 1 => 150.0,
                 2 => 45.0,
@@ -62,7 +63,7 @@ impl VerticalAccuracy {
 impl From<u8> for VerticalAccuracy {
     fn from(value: u8) -> Self {
         if value >= Self::RESERVED_THRESHOLD {
-            return Self::Reserved;
+            return Self::Reserved(value);
         }
 
         match value {
@@ -75,7 +76,8 @@ impl From<u8> for VerticalAccuracy {
 impl From<VerticalAccuracy> for u8 {
     fn from(value: VerticalAccuracy) -> Self {
         match value {
-            VerticalAccuracy::Reserved => VerticalAccuracy::RESERVED_THRESHOLD,
+            // the accuracy is encoded in four bits.
+            VerticalAccuracy::Reserved(n) => n.clamp(VerticalAccuracy::RESERVED_THRESHOLD, 0x0f),
             VerticalAccuracy::Unknown => VerticalAccuracy::UNKNOWN_CODE,
             VerticalAccuracy::Known(n) => n.clamp(0, VerticalAccuracy::RESERVED_THRESHOLD),
         }
@@ -88,7 +90,10 @@ mod tests {
 
     #[test]
     fn test_accuracy() {
-        assert_eq!(VerticalAccuracy::Reserved.accuracy(), VerticalAccuracy::MAX);
+        assert_eq!(
+            VerticalAccuracy::Reserved(7).accuracy(),
+            VerticalAccuracy::MAX
+        );
         assert_eq!(VerticalAccuracy::Unknown.accuracy(), VerticalAccuracy::MAX);
         assert_eq!(VerticalAccuracy::Known(1).accuracy(), 150.0);
     }
@@ -109,7 +114,7 @@ mod tests {
 
     #[test]
     fn test_encode_reserved() {
-        let reserved = VerticalAccuracy::Reserved;
+        let reserved = VerticalAccuracy::Reserved(VerticalAccuracy::RESERVED_THRESHOLD);
 
         assert_eq!(u8::from(reserved), VerticalAccuracy::RESERVED_THRESHOLD);
     }
@@ -142,6 +147,18 @@ mod tests {
     fn test_decode_reserved() {
         let decoded = VerticalAccuracy::from(VerticalAccuracy::RESERVED_THRESHOLD);
 
-        assert_eq!(decoded, VerticalAccuracy::Reserved);
+        assert_eq!(
+            decoded,
+            VerticalAccuracy::Reserved(VerticalAccuracy::RESERVED_THRESHOLD)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=0x0f {
+            assert_eq!(u8::from(VerticalAccuracy::from(code)), code);
+        }
+
+        assert_eq!(VerticalAccuracy::Reserved(0x0f).code(), 0x0f);
     }
 }
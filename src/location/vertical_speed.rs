@@ -1,3 +1,5 @@
+use crate::quantize::Quantize;
+
 /// Vertical Speed
 ///
 /// Represents speed upward relative to the WSG-84 datum measured in meters per second.
@@ -9,6 +11,7 @@
 /// > Though it would be more readable to invert these (`n * 0.5 == n / 2`), we leave it as-is to
 /// > more explicitly conform to the specification.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalSpeed {
     /// Invalid value.
     ///
@@ -30,12 +33,18 @@ impl VerticalSpeed {
     /// Multiplier to encode/decode speed.
     pub const MULTIPLIER: f32 = 0.5;
 
+    /// Minimum vertical speed; anything less than this is clamped up to this.
+    pub const MIN: f32 = -62.0;
+
+    /// Maximum vertical speed; anything greater than this is clamped down to this.
+    pub const MAX: f32 = 62.0;
+
     /// Returns the inner vertical speed.
     pub fn vertical_speed(&self) -> f32 {
         match self {
             Self::Invalid | Self::NoValue => 0.0,
             Self::Unknown => Self::UNKNOWN_CODE,
-            Self::Known(n) => n.clamp(-62.0, 62.0),
+            Self::Known(n) => n.clamp(Self::MIN, Self::MAX),
         }
     }
 }
@@ -47,24 +56,40 @@ impl From<u8> for VerticalSpeed {
 
         match value {
             Self::UNKNOWN_CODE => Self::Unknown,
-            n => Self::Known(n.clamp(-62.0, 62.0)),
+            n => Self::Known(n.clamp(Self::MIN, Self::MAX)),
         }
     }
 }
 
 impl From<VerticalSpeed> for u8 {
     fn from(value: VerticalSpeed) -> Self {
-        let value = value.vertical_speed() / VerticalSpeed::MULTIPLIER;
+        // NaN encodes as no value, the cast to `i8` keeps the sign in 2's complement.
+        let value = match value {
+            VerticalSpeed::Known(n) if n.is_nan() => VerticalSpeed::NoValue,
+            value => value,
+        };
 
-        // UNSAFE REASON: There is no safe API for rounding the `VerticalSpeed::Known` branch. This
-        // is safe because the value is checked prior to this expression.
-        unsafe { f32::to_int_unchecked(value) }
+        (value.vertical_speed() / VerticalSpeed::MULTIPLIER) as i8 as u8
+    }
+}
+
+impl Quantize for VerticalSpeed {
+    fn quantize(&self) -> Self {
+        u8::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        match self {
+            Self::Known(value) => Some(*value as f64),
+            _ => None,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::location::VerticalSpeed;
+    use crate::quantize::Quantize;
 
     #[test]
     fn test_vertical_speed() {
@@ -112,6 +137,21 @@ mod tests {
         assert_eq!(u8::from(invalid_state), 124);
     }
 
+    #[test]
+    fn test_encode_negative() {
+        assert_eq!(u8::from(VerticalSpeed::Known(-3.0)), (-6i8) as u8);
+        assert_eq!(u8::from(VerticalSpeed::Known(-100.0)), (-124i8) as u8);
+        assert_eq!(
+            VerticalSpeed::from(u8::from(VerticalSpeed::Known(-3.0))),
+            VerticalSpeed::Known(-3.0)
+        );
+    }
+
+    #[test]
+    fn test_encode_nan() {
+        assert_eq!(u8::from(VerticalSpeed::Known(f32::NAN)), 0);
+    }
+
     #[test]
     fn test_decode() {
         let decoded = VerticalSpeed::from(2);
@@ -125,4 +165,14 @@ mod tests {
 
         assert_eq!(decoded, VerticalSpeed::Unknown);
     }
+
+    #[test]
+    fn test_quantize() {
+        let vertical_speed = VerticalSpeed::Known(-2.7);
+
+        assert_eq!(vertical_speed.quantize(), VerticalSpeed::Known(-2.5));
+        assert!((vertical_speed.quantization_error().unwrap() - 0.2).abs() < 1e-4);
+        assert!(VerticalSpeed::Known(-2.5).is_lossless());
+        assert!(VerticalSpeed::Unknown.is_lossless());
+    }
 }
@@ -0,0 +1,303 @@
+use crate::error::Error;
+use crate::location::Location;
+use crate::location::LocationRef;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::messages::MessageTypeRef;
+use crate::messages::ProtocolVersion;
+use crate::pack::Pack;
+use crate::pack::PackRef;
+
+/// Borrowed Core Message
+///
+/// Zero-copy view of an encoded [`Message`]. The bytes are validated once, on construction, to the
+/// same rules as [`Message::try_from`], so retrieving the message type never fails.
+///
+/// The [`Location`](crate::location::Location) and [`Pack`] messages are borrowed as
+/// [`LocationRef`] and [`PackRef`], which decode their fields on demand. The remaining message
+/// types are small, they are decoded by [`MessageRef::message_type`].
+///
+/// As with [`Message::try_from`], only the pack header is validated on construction, the messages
+/// inside of a pack are validated when they are retrieved, see [`PackRef`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MessageRef<'a> {
+    protocol_version: ProtocolVersion,
+    bytes: &'a [u8],
+}
+
+impl<'a> MessageRef<'a> {
+    /// Returns the protocol version as the raw lower nibble of the message header, see
+    /// [`Message::protocol_version`].
+    ///
+    /// Kept to mirror [`Message`], prefer [`MessageRef::version`].
+    pub fn protocol_version(&self) -> u8 {
+        self.protocol_version.into()
+    }
+
+    /// Returns the enumerated protocol version.
+    ///
+    /// This is the accessor to match on, rather than the raw [`MessageRef::protocol_version`].
+    pub fn version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the enumerated message type.
+    pub fn message_type(&self) -> MessageTypeRef<'a> {
+        // INVARIANT: the message is validated on construction.
+        self.try_message_type()
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+
+    /// Returns the borrowed Location, if the message is one.
+    pub fn location(&self) -> Option<LocationRef<'a>> {
+        match self.message_type_code() {
+            Location::LOCATION_MESSAGE_CODE => self.location_ref().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the borrowed Pack, if the message is one.
+    pub fn pack(&self) -> Option<PackRef<'a>> {
+        match self.is_pack() {
+            true => PackRef::try_from(&self.bytes[1..]).ok(),
+            false => None,
+        }
+    }
+
+    /// Returns the byte length, see [`Message::encoding_byte_length`].
+    pub fn encoding_byte_length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns true if the message is a [`Pack`].
+    pub fn is_pack(&self) -> bool {
+        self.message_type_code() == Pack::PACK_MESSAGE_CODE
+    }
+
+    /// Returns the borrowed bytes, the message header included.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    // upper nibble of the message header.
+    fn message_type_code(&self) -> u8 {
+        self.bytes[0] >> 4
+    }
+
+    fn location_ref(&self) -> Result<LocationRef<'a>, Error> {
+        Ok(LocationRef::try_from(&self.bytes[1..])?.with_protocol_version(self.protocol_version))
+    }
+
+    // decodes the message type, borrowing where possible. the owned message types are conformed
+    // to the protocol version on a copy, as in `Message::try_from`.
+    fn try_message_type(&self) -> Result<MessageTypeRef<'a>, Error> {
+        if self.is_pack() {
+            return Ok(MessageTypeRef::Pack(PackRef::try_from(&self.bytes[1..])?));
+        }
+
+        if self.bytes.len() != Pack::MESSAGES_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        if self.message_type_code() == Location::LOCATION_MESSAGE_CODE {
+            return Ok(MessageTypeRef::Location(self.location_ref()?));
+        }
+
+        let mut conformed = [0u8; Pack::MESSAGES_LENGTH];
+        conformed.clone_from_slice(self.bytes);
+
+        self.protocol_version.try_conform(&mut conformed)?;
+
+        match MessageType::try_from(conformed.as_ref())? {
+            MessageType::BasicID(basic_id) => Ok(MessageTypeRef::BasicID(basic_id)),
+            MessageType::Authentication(authentication) => {
+                Ok(MessageTypeRef::Authentication(authentication))
+            }
+            MessageType::SelfID(self_id) => Ok(MessageTypeRef::SelfID(self_id)),
+            MessageType::System(system) => Ok(MessageTypeRef::System(system)),
+            MessageType::OperatorID(operator_id) => Ok(MessageTypeRef::OperatorID(operator_id)),
+            MessageType::Location(_) | MessageType::Pack(_) => Err(Error::Unreachable),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for MessageRef<'a> {
+    type Error = Error;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        let header = *value.first().ok_or(Error::InvalidDataLength)?;
+
+        let message_ref = Self {
+            protocol_version: ProtocolVersion::try_from(header & 0b0000_1111)?,
+            bytes: value,
+        };
+
+        message_ref.try_message_type()?;
+
+        Ok(message_ref)
+    }
+}
+
+impl From<MessageRef<'_>> for Message {
+    fn from(value: MessageRef<'_>) -> Self {
+        Message::new(value.message_type().into()).with_protocol_version(value.protocol_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        fixtures::operator_id_message,
+        location::{
+            Altitude, GroundSpeed, HeightType, HorizontalAccuracy, Latitude, Location, Longitude,
+            OperationalStatus, SpeedAccuracy, Timestamp, TimestampAccuracy, TrackDirection,
+            VerticalAccuracy, VerticalSpeed,
+        },
+        messages::{Message, MessageRef, MessageTypeRef, ProtocolVersion},
+        pack::Pack,
+        try_serialize::TrySerialize,
+    };
+
+    fn location_message() -> Message {
+        Message::from(Location::new(
+            OperationalStatus::Airborne,
+            HeightType::TakeOff,
+            TrackDirection::Known(90),
+            GroundSpeed::Known(5.25),
+            VerticalSpeed::Unknown,
+            Latitude::Known(37.5),
+            Longitude::Known(-122.25),
+            Altitude::Unknown,
+            Altitude::Known(100.0),
+            Altitude::Known(20.0),
+            VerticalAccuracy::Unknown,
+            HorizontalAccuracy::Unknown,
+            VerticalAccuracy::Unknown,
+            SpeedAccuracy::Unknown,
+            Timestamp::Known(100),
+            TimestampAccuracy::Known(0.5),
+        ))
+    }
+
+    fn encode(message: &Message, buffer: &mut [u8]) {
+        message.try_serialize(buffer).unwrap();
+    }
+
+    #[test]
+    fn test_getters() {
+        let mut encoded = [0u8; 25];
+        encode(&location_message(), &mut encoded);
+
+        let message_ref = MessageRef::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(message_ref.protocol_version(), Message::PROTOCOL_VERSION);
+        assert_eq!(message_ref.version(), ProtocolVersion::Version2);
+        assert_eq!(message_ref.encoding_byte_length(), 25);
+        assert_eq!(message_ref.as_bytes(), encoded.as_ref());
+        assert!(!message_ref.is_pack());
+        assert!(message_ref.pack().is_none());
+        assert_eq!(
+            message_ref.location().unwrap().latitude(),
+            Latitude::Known(37.5)
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        for message in [location_message(), operator_id_message()] {
+            let mut encoded = [0u8; 25];
+            encode(&message, &mut encoded);
+
+            let message_ref = MessageRef::try_from(encoded.as_ref()).unwrap();
+
+            assert_eq!(Message::from(message_ref), message);
+        }
+    }
+
+    #[test]
+    fn test_decode_pack() {
+        let message =
+            Message::from(Pack::try_from([operator_id_message(), location_message()]).unwrap());
+
+        let mut encoded = [0u8; 53];
+        encode(&message, &mut encoded);
+
+        let message_ref = MessageRef::try_from(encoded.as_ref()).unwrap();
+
+        assert!(message_ref.is_pack());
+        assert!(message_ref.location().is_none());
+        assert_eq!(Message::from(message_ref), message);
+
+        let MessageTypeRef::Pack(pack_ref) = message_ref.message_type() else {
+            panic!("expected a pack");
+        };
+
+        let location_ref = pack_ref.try_get_message(1).unwrap().unwrap();
+
+        assert_eq!(
+            location_ref.location().unwrap().speed(),
+            GroundSpeed::Known(5.25)
+        );
+        assert_eq!(location_ref.as_bytes(), &encoded[28..53]);
+    }
+
+    #[test]
+    fn test_decode_conforms_protocol_version() {
+        // version 2 encoding relabelled as version 0, which reserves the timestamp accuracy.
+        let mut encoded = [0u8; 25];
+        encode(&location_message(), &mut encoded);
+        encoded[0] = encoded[0] & 0b1111_0000 | u8::from(ProtocolVersion::Version0);
+
+        let message_ref = MessageRef::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(
+            message_ref.location().unwrap().timestamp_accuracy(),
+            TimestampAccuracy::Unknown
+        );
+        assert_eq!(
+            Message::from(message_ref),
+            Message::try_from(encoded.as_ref()).unwrap()
+        );
+        assert_eq!(message_ref.version(), ProtocolVersion::Version0);
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let mut encoded = [0u8; 26];
+        encode(&operator_id_message(), &mut encoded[..25]);
+
+        assert_eq!(
+            MessageRef::try_from(&encoded[..24]),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            MessageRef::try_from(encoded.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            MessageRef::try_from([].as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_value() {
+        let mut encoded = [0u8; 25];
+        encode(&operator_id_message(), &mut encoded);
+
+        let mut invalid_version = encoded;
+        invalid_version[0] |= 0x03;
+
+        let mut invalid_type = encoded;
+        invalid_type[0] = 0x62;
+
+        for invalid in [invalid_version, invalid_type] {
+            assert_eq!(
+                MessageRef::try_from(invalid.as_ref()).err(),
+                Message::try_from(invalid.as_ref()).err()
+            );
+        }
+    }
+}
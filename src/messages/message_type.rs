@@ -12,6 +12,7 @@ use crate::try_serialize::TrySerialize;
 ///
 /// This enumerates the internal message types as well.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MessageType {
     /// Basic ID.
     BasicID(BasicID),
@@ -33,19 +34,20 @@ impl TryFrom<&[u8]> for MessageType {
     type Error = Error;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let message_type = *value.first().ok_or(Error::InvalidDataLength)? >> 4;
+
+        // we exit here for pack first because all other message types are only 25 bytes long, while
+        // the pack message can extend out to 228 bytes. the pack checks its own length.
+        if message_type == Pack::PACK_MESSAGE_CODE {
+            return Ok(MessageType::Pack(value[1..].try_into()?));
+        }
+
         if value.len() != 25 {
             return Err(Error::InvalidDataLength);
         }
 
-        let message_type = value[0] >> 4;
         let value = &value[1..25];
 
-        // we exit here for pack first because all other message types are only 25 bytes long, while
-        // the pack message can extend out to 227 bytes.
-        if message_type == 0x0f {
-            return Ok(MessageType::Pack(value.try_into()?));
-        }
-
         match message_type {
             0x00 => Ok(MessageType::BasicID(value.try_into()?)),
             0x01 => Ok(MessageType::Location(value.try_into()?)),
@@ -62,10 +64,14 @@ impl TrySerialize for MessageType {
     type Error = Error;
 
     fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.is_empty() {
+            return Err(Error::InvalidDataLength);
+        }
+
         // we exit here for pack first because all other message types are only 25 bytes long, while
-        // the pack message can extend out to 227 bytes.
+        // the pack message can extend out to 228 bytes. the pack checks its own length.
         if let Self::Pack(pack) = self {
-            buffer[0] |= 0x0f << 4;
+            buffer[0] |= Pack::PACK_MESSAGE_CODE << 4;
             return pack.try_serialize(&mut buffer[1..]);
         }
 
@@ -95,6 +101,7 @@ impl TrySerialize for MessageType {
                 buffer[0] |= 5 << 4;
                 operator_id.try_serialize(&mut buffer[1..])
             }
+            #[allow(clippy::unnecessary_literal_unwrap)]
             _ => Err(Error::Unreachable).unwrap(),
         }
     }
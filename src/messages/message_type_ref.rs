@@ -0,0 +1,46 @@
+use crate::authentication::Authentication;
+use crate::basic_id::BasicID;
+use crate::location::LocationRef;
+use crate::messages::MessageType;
+use crate::operator_id::OperatorID;
+use crate::pack::PackRef;
+use crate::self_id::SelfID;
+use crate::system::System;
+
+/// Borrowed Type of Message
+///
+/// As [`MessageType`], except the [`Location`](crate::location::Location) and
+/// [`Pack`](crate::pack::Pack) messages are borrowed views. The remaining message types are
+/// decoded when the enumeration is retrieved through
+/// [`MessageRef::message_type`](crate::messages::MessageRef::message_type).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MessageTypeRef<'a> {
+    /// Basic ID.
+    BasicID(BasicID),
+    /// Borrowed Location.
+    Location(LocationRef<'a>),
+    /// Authentication.
+    Authentication(Authentication),
+    /// Self ID
+    SelfID(SelfID),
+    /// System.
+    System(System),
+    /// Operator ID
+    OperatorID(OperatorID),
+    /// Borrowed Message Pack
+    Pack(PackRef<'a>),
+}
+
+impl From<MessageTypeRef<'_>> for MessageType {
+    fn from(value: MessageTypeRef<'_>) -> Self {
+        match value {
+            MessageTypeRef::BasicID(basic_id) => Self::BasicID(basic_id),
+            MessageTypeRef::Location(location) => Self::Location(location.into()),
+            MessageTypeRef::Authentication(authentication) => Self::Authentication(authentication),
+            MessageTypeRef::SelfID(self_id) => Self::SelfID(self_id),
+            MessageTypeRef::System(system) => Self::System(system),
+            MessageTypeRef::OperatorID(operator_id) => Self::OperatorID(operator_id),
+            MessageTypeRef::Pack(pack) => Self::Pack(pack.into()),
+        }
+    }
+}
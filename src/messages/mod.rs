@@ -34,26 +34,60 @@
 //! let deserialized_message = Message::try_from(buffer.as_ref()).unwrap();
 //! ```
 //!
+//! Messages of every [`ProtocolVersion`] decode, the version is kept on the [`Message`] so it
+//! encodes back in the layout it was received in. Constructed messages default to
+//! [`Message::PROTOCOL_VERSION`], see [`Message::with_protocol_version`] to target another one.
+//!
+//! [`MessageRef`] decodes without copying, borrowing the encoded bytes. It is validated once up
+//! front like [`Message`], then the [`Location`](crate::location::Location) and
+//! [`Pack`] messages decode their fields on demand through
+//! [`LocationRef`](crate::location::LocationRef) and [`PackRef`](crate::pack::PackRef).
+//!
+//! ```rust
+//! use drone_id::messages::{MessageRef, MessageTypeRef};
+//!
+//! # let frame = [0x12, 0x20, 0x5a, 0x15, 0x7e, 0xc0, 0x0b, 0x5a, 0x16, 0x60, 0x21, 0x22, 0xb7,
+//! #     0x00, 0x00, 0x98, 0x08, 0xf8, 0x07, 0x00, 0x00, 0x64, 0x00, 0x00, 0x00];
+//! let message = MessageRef::try_from(frame.as_ref()).unwrap();
+//!
+//! if let MessageTypeRef::Location(location) = message.message_type() {
+//!     let latitude = location.latitude();
+//! }
+//! ```
+//!
 //! Deserialization and serialization through this means should NEVER panic, any internal panic
 //! would be a bug, instead it will enumerate all errors through [`crate::error::Error`].
+mod message_ref;
 mod message_type;
+mod message_type_ref;
+mod protocol_version;
 
+pub use message_ref::MessageRef;
 pub use message_type::MessageType;
+pub use message_type_ref::MessageTypeRef;
+pub use protocol_version::ProtocolVersion;
 
 use crate::error::Error;
+use crate::pack::Pack;
 use crate::try_serialize::TrySerialize;
 
 /// Core Message
 ///
 /// Contains a protocol version and an enumerated form of the message.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Message {
-    protocol_version: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    protocol_version: ProtocolVersion,
     message_type: MessageType,
 }
 
 impl Message {
-    /// Protocol version.
+    /// Default protocol version, [`ProtocolVersion::Version2`], as the raw lower nibble of the
+    /// message header.
+    ///
+    /// Kept for building raw headers, compare against [`ProtocolVersion`] through
+    /// [`Message::version`] instead.
     pub const PROTOCOL_VERSION: u8 = 0x02;
 
     /// Constructs a new Message.
@@ -61,16 +95,31 @@ impl Message {
     /// `protocol_version` is defaulted.
     pub fn new(message_type: MessageType) -> Self {
         Self {
-            protocol_version: Self::PROTOCOL_VERSION,
+            protocol_version: ProtocolVersion::default(),
             message_type,
         }
     }
 
-    /// Returns the protocol version.
+    /// Returns a copy with the given protocol version.
+    ///
+    /// Fields the version reserves are encoded as zero, see [`ProtocolVersion`].
+    pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = protocol_version;
+
+        self
+    }
+
+    /// Returns the protocol version as the raw lower nibble of the message header.
     ///
-    /// This should always be [`Message::PROTOCOL_VERSION`], but we add this redundancy for
-    /// convention consistency.
+    /// Kept for compatibility, prefer [`Message::version`].
     pub fn protocol_version(&self) -> u8 {
+        self.protocol_version.into()
+    }
+
+    /// Returns the enumerated protocol version.
+    ///
+    /// This is the accessor to match on, rather than the raw [`Message::protocol_version`].
+    pub fn version(&self) -> ProtocolVersion {
         self.protocol_version
     }
 
@@ -81,19 +130,41 @@ impl Message {
 
     /// Returns the byte length
     ///
-    /// Always `25` unless [`MessageType::Pack`] is used.
+    /// Always `25` unless [`MessageType::Pack`] is used, in which case it is the one byte message
+    /// header followed by the encoded [`Pack`], that is `3 + 25 * number_of_messages`.
     pub fn encoding_byte_length(&self) -> usize {
         match self.message_type {
-            MessageType::Pack(pack) => pack.number_of_messages() as usize * 25,
-            _ => 25,
+            MessageType::Pack(pack) => 1 + pack.encoding_byte_length(),
+            _ => Pack::MESSAGES_LENGTH,
         }
     }
 
     /// Returns true if [`MessageType::Pack`] is used.
     pub fn is_pack(&self) -> bool {
-        match self.message_type {
-            MessageType::Pack(_) => true,
-            _ => false,
+        matches!(self.message_type, MessageType::Pack(_))
+    }
+
+    /// Returns a copy with every field quantized, as it would decode after being encoded.
+    ///
+    /// See [`Quantize`](crate::quantize::Quantize). Fields reserved by the protocol version are
+    /// cleared, see [`ProtocolVersion`]. A [`Pack`] holds its messages encoded, so it is already
+    /// normalized.
+    pub fn normalized(&self) -> Self {
+        let protocol_version = self.protocol_version;
+
+        let message_type = match self.message_type {
+            MessageType::Location(location) => {
+                MessageType::Location(location.normalized().conformed(protocol_version))
+            }
+            MessageType::System(system) => {
+                MessageType::System(system.normalized().conformed(protocol_version))
+            }
+            message_type => message_type,
+        };
+
+        Self {
+            message_type,
+            ..*self
         }
     }
 }
@@ -102,20 +173,28 @@ impl TryFrom<&[u8]> for Message {
     type Error = Error;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        // We don't check the length here because it is checked in the `message_type` parsing, which
-        // determines first if the internal message is a `pack`, implying a different value length.
-        //
-        // We could also check this here but we're separating concerns & reducing redundancy by not
-        // doing this.
+        // We only check for the header byte here because the full length is checked in the
+        // `message_type` parsing, which determines first if the internal message is a `pack`,
+        // implying a different value length.
         //
         // Length should be `25` if anything but a pack. if the message is a pack, the length should
-        // be `2 + (msg_count * 25)`.
-        let protocol_version = value[0] & 0b0000_1111;
-        let message_type = value.as_ref().try_into()?;
+        // be `3 + (msg_count * 25)`.
+        let header = *value.first().ok_or(Error::InvalidDataLength)?;
 
-        if protocol_version != Self::PROTOCOL_VERSION {
-            return Err(Error::InvalidProtocolVersion);
-        }
+        let protocol_version = ProtocolVersion::try_from(header & 0b0000_1111)?;
+
+        // packs are shared across versions, each packed message is conformed to its own version
+        // when it is decoded.
+        let message_type = if value.len() == Pack::MESSAGES_LENGTH {
+            let mut conformed = [0u8; Pack::MESSAGES_LENGTH];
+            conformed.clone_from_slice(value);
+
+            protocol_version.try_conform(&mut conformed)?;
+
+            conformed.as_ref().try_into()?
+        } else {
+            value.try_into()?
+        };
 
         Ok(Self {
             protocol_version,
@@ -128,13 +207,15 @@ impl TrySerialize for Message {
     type Error = Error;
 
     fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
-        if buffer.len() != 25 {
+        if buffer.len() != self.encoding_byte_length() {
             return Err(Error::InvalidDataLength);
         }
 
-        buffer[0] = self.protocol_version;
+        buffer[0] = self.protocol_version.into();
+
+        self.message_type.try_serialize(buffer)?;
 
-        self.message_type.try_serialize(buffer)
+        self.protocol_version.try_conform(buffer)
     }
 }
 
@@ -143,7 +224,7 @@ macro_rules! impl_from_for_message {
         impl From<$type_path> for Message {
             fn from(value: $type_path) -> Self {
                 Self {
-                    protocol_version: Message::PROTOCOL_VERSION,
+                    protocol_version: ProtocolVersion::default(),
                     message_type: MessageType::$message_type(value),
                 }
             }
@@ -162,12 +243,51 @@ impl_from_for_message!(Pack, crate::pack::Pack);
 #[cfg(test)]
 mod tests {
     use crate::{
+        authentication::{Authentication, AuthenticationType, Subsequent},
         basic_id::{BasicID, UASID, UAType, UTMAssignedUUID},
-        messages::{Message, MessageType},
+        error::Error,
+        fixtures::operator_id_message,
+        location::{
+            Altitude, GroundSpeed, HeightType, Latitude, Location, Longitude, TimestampAccuracy,
+        },
+        messages::{Message, MessageType, ProtocolVersion},
         operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        system::{
+            AreaCount, ClassificationType, OperatingAreaRadius, OperatorLocationSourceType, System,
+            UAClassification,
+        },
         try_serialize::TrySerialize,
     };
 
+    fn system_message(classification_type: ClassificationType) -> Message {
+        let ua_classification = match classification_type {
+            ClassificationType::EuropeanUnion => UAClassification::Certified,
+            _ => UAClassification::Undefined,
+        };
+
+        Message::from(System::new(
+            classification_type,
+            OperatorLocationSourceType::TakeOff,
+            0.into(),
+            0.into(),
+            AreaCount::try_from(1).unwrap(),
+            0.into(),
+            0.into(),
+            0.into(),
+            ua_classification,
+            0.into(),
+            0.into(),
+        ))
+    }
+
+    fn basic_id_message() -> Message {
+        Message::from(BasicID::new(
+            UAType::Aeroplane,
+            UASID::UTMAssignedUUID(UTMAssignedUUID::new([2u8; 20])),
+        ))
+    }
+
     #[test]
     fn test_getters() {
         let basic_id = BasicID::new(UAType::Aeroplane, UASID::None);
@@ -177,7 +297,7 @@ mod tests {
         assert_eq!(message.protocol_version(), Message::PROTOCOL_VERSION);
         assert_eq!(message.message_type(), &message_type);
         assert_eq!(message.encoding_byte_length(), 25);
-        assert_eq!(message.is_pack(), false);
+        assert!(!message.is_pack());
     }
 
     #[test]
@@ -194,7 +314,7 @@ mod tests {
         let mut encoded = [0u8; 25];
         message.try_serialize(&mut encoded).unwrap();
 
-        assert_eq!(encoded[0], Message::PROTOCOL_VERSION | 0 << 4);
+        assert_eq!(encoded[0], Message::PROTOCOL_VERSION);
         assert_eq!(encoded[1] & 0b0000_1111, u8::from(ua_type));
         assert_eq!(encoded[1] >> 4, 3);
         assert_eq!(encoded[2..22], encoded_uas_id[1..]);
@@ -265,8 +385,361 @@ mod tests {
 
     #[test]
     fn test_decode_fails_invalid_protocol_version() {
-        let invalid = [0u8; 25];
+        let mut invalid = [0u8; 25];
+        invalid[0] = 3;
+
+        assert_eq!(
+            Message::try_from(invalid.as_ref()),
+            Err(Error::InvalidProtocolVersion)
+        );
+    }
+
+    #[test]
+    fn test_protocol_version() {
+        let message = basic_id_message();
+
+        assert_eq!(message.version(), ProtocolVersion::Version2);
+
+        let message = message.with_protocol_version(ProtocolVersion::Version1);
+
+        assert_eq!(message.version(), ProtocolVersion::Version1);
+        assert_eq!(message.protocol_version(), 1);
+
+        let mut encoded = [0u8; 25];
+        message.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[0], 1);
+        assert_eq!(Message::try_from(encoded.as_ref()), Ok(message));
+    }
+
+    #[test]
+    fn test_decode_version_1_system_ignores_classification() {
+        let message = system_message(ClassificationType::EuropeanUnion);
+
+        let mut encoded = [0u8; 25];
+        message.try_serialize(&mut encoded).unwrap();
+        encoded[0] = encoded[0] & 0b1111_0000 | 1;
+
+        let expected = system_message(ClassificationType::Undeclared)
+            .with_protocol_version(ProtocolVersion::Version1);
+
+        let MessageType::System(system) = expected.message_type() else {
+            panic!("expected system");
+        };
+
+        assert_eq!(system.ua_classification(), UAClassification::Undefined);
+        assert_eq!(Message::try_from(encoded.as_ref()), Ok(expected));
+    }
+
+    #[test]
+    fn test_encode_version_1_system_zeroes_classification() {
+        let message = system_message(ClassificationType::EuropeanUnion)
+            .with_protocol_version(ProtocolVersion::Version1);
+
+        let mut encoded = [0u8; 25];
+        message.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[1] & 0b0001_1100, 0);
+        assert_eq!(encoded[17], 0);
+    }
+
+    #[test]
+    fn test_decode_version_0_location_ignores_timestamp_accuracy() {
+        let mut encoded = [0u8; 25];
+        encoded[0] = 0x10;
+        encoded[23] = 0x05;
+
+        let version_0 = Message::try_from(encoded.as_ref()).unwrap();
+
+        let MessageType::Location(location) = version_0.message_type() else {
+            panic!("expected location");
+        };
+
+        assert_eq!(version_0.version(), ProtocolVersion::Version0);
+        assert_eq!(location.timestamp_accuracy(), TimestampAccuracy::Unknown);
+
+        encoded[0] = 0x11;
+
+        let version_1 = Message::try_from(encoded.as_ref()).unwrap();
+
+        let MessageType::Location(location) = version_1.message_type() else {
+            panic!("expected location");
+        };
+
+        assert_eq!(location.timestamp_accuracy(), TimestampAccuracy::Known(0.5));
+    }
+
+    #[test]
+    fn test_normalized() {
+        let location = Location::builder()
+            .speed(GroundSpeed::Known(10.3))
+            .latitude(Latitude::Known(37.123_456_789))
+            .longitude(Longitude::Known(-122.123_456_789))
+            .height_type(HeightType::AGL)
+            .height(Altitude::Known(20.3))
+            .timestamp_accuracy(TimestampAccuracy::Known(0.25))
+            .build()
+            .unwrap();
+
+        let system = System::builder()
+            .operator_latitude(Latitude::Known(37.123_456_789))
+            .operator_longitude(Longitude::Known(-122.123_456_789))
+            .operator_altitude(Altitude::Known(12.3))
+            .timestamp(1_000.into())
+            .build()
+            .unwrap();
+
+        for message in [
+            Message::from(location),
+            Message::from(system),
+            operator_id_message(),
+        ] {
+            let mut encoded = [0u8; 25];
+            message.try_serialize(&mut encoded).unwrap();
+
+            let decoded = Message::try_from(encoded.as_ref()).unwrap();
+
+            assert_eq!(message.normalized(), decoded);
+            assert_eq!(decoded.normalized(), decoded);
+        }
+
+        assert_ne!(
+            Message::from(location),
+            Message::from(location).normalized()
+        );
+    }
+
+    #[test]
+    fn test_normalized_clears_reserved_fields() {
+        let location = Location::builder()
+            .timestamp_accuracy(TimestampAccuracy::Known(0.5))
+            .build()
+            .unwrap();
+
+        let system = System::builder()
+            .area_radius(OperatingAreaRadius::try_new(15).unwrap())
+            .timestamp(1_000.into())
+            .build()
+            .unwrap();
+
+        for message in [
+            Message::from(location).with_protocol_version(ProtocolVersion::Version0),
+            system_message(ClassificationType::EuropeanUnion)
+                .with_protocol_version(ProtocolVersion::Version1),
+            Message::from(system),
+        ] {
+            let mut encoded = [0u8; 25];
+            message.try_serialize(&mut encoded).unwrap();
+
+            let decoded = Message::try_from(encoded.as_ref()).unwrap();
+
+            assert_eq!(message.normalized(), decoded);
+        }
+    }
+
+    #[test]
+    fn test_version_1_authentication_fails_page_limit() {
+        let subsequent = Message::from(Authentication::Subsequent(
+            Subsequent::try_new(AuthenticationType::UASIDSignature, 5, [0u8; 23]).unwrap(),
+        ));
+
+        let mut encoded = [0u8; 25];
+        subsequent.try_serialize(&mut encoded).unwrap();
+
+        assert!(Message::try_from(encoded.as_ref()).is_ok());
+
+        encoded[0] = encoded[0] & 0b1111_0000 | 1;
+
+        assert_eq!(
+            Message::try_from(encoded.as_ref()),
+            Err(Error::InvalidInteger)
+        );
+        assert_eq!(
+            subsequent
+                .with_protocol_version(ProtocolVersion::Version1)
+                .try_serialize(&mut encoded),
+            Err(Error::InvalidInteger)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_empty() {
+        let empty: [u8; 0] = [];
+
+        assert_eq!(
+            Message::try_from(empty.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_pack_getters() {
+        let pack = Pack::try_from([operator_id_message(), basic_id_message()]).unwrap();
+        let message = Message::from(pack);
+
+        assert_eq!(message.protocol_version(), Message::PROTOCOL_VERSION);
+        assert_eq!(message.message_type(), &MessageType::Pack(pack));
+        assert_eq!(message.encoding_byte_length(), 3 + 2 * 25);
+        assert!(message.is_pack());
+    }
+
+    #[test]
+    fn test_encode_pack() {
+        let operator_id = operator_id_message();
+        let basic_id = basic_id_message();
+
+        let mut encoded_operator_id = [0u8; 25];
+        operator_id.try_serialize(&mut encoded_operator_id).unwrap();
+
+        let mut encoded_basic_id = [0u8; 25];
+        basic_id.try_serialize(&mut encoded_basic_id).unwrap();
+
+        let message = Message::from(Pack::try_from([operator_id, basic_id]).unwrap());
+
+        let mut encoded = [0u8; 53];
+        message.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            encoded[0],
+            Message::PROTOCOL_VERSION | Pack::PACK_MESSAGE_CODE << 4
+        );
+        assert_eq!(encoded[1], Pack::MESSAGES_LENGTH as u8);
+        assert_eq!(encoded[2], 2);
+        assert_eq!(encoded[3..28], encoded_operator_id);
+        assert_eq!(encoded[28..53], encoded_basic_id);
+    }
+
+    #[test]
+    fn test_encode_pack_fails_invalid_data_length() {
+        let message = Message::from(Pack::try_from([operator_id_message()]).unwrap());
+
+        let mut empty: [u8; 0] = [];
+        let mut single_message = [0u8; 25];
+        let mut too_short = [0u8; 27];
+        let mut too_long = [0u8; 29];
+
+        assert!(message.try_serialize(&mut empty).is_err());
+        assert!(message.try_serialize(&mut single_message).is_err());
+        assert!(message.try_serialize(&mut too_short).is_err());
+        assert!(message.try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode_pack() {
+        let operator_id = operator_id_message();
+        let basic_id = basic_id_message();
+
+        let expected = Message::from(Pack::try_from([operator_id, basic_id]).unwrap());
+
+        let mut encoded = [0u8; 53];
+        encoded[0] = Message::PROTOCOL_VERSION | Pack::PACK_MESSAGE_CODE << 4;
+        encoded[1] = Pack::MESSAGES_LENGTH as u8;
+        encoded[2] = 2;
+        operator_id.try_serialize(&mut encoded[3..28]).unwrap();
+        basic_id.try_serialize(&mut encoded[28..53]).unwrap();
+
+        let decoded = Message::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(decoded, expected);
+
+        let MessageType::Pack(pack) = decoded.message_type() else {
+            panic!("expected pack");
+        };
+
+        assert_eq!(pack.try_get_message(0).unwrap().unwrap(), operator_id);
+        assert_eq!(pack.try_get_message(1).unwrap().unwrap(), basic_id);
+        assert!(pack.try_get_message(2).is_none());
+    }
+
+    #[test]
+    fn test_encode_decode_pack_one_to_nine() {
+        let message = operator_id_message();
+
+        macro_rules! round_trip {
+            ($n:literal) => {
+                let expected = Message::from(Pack::try_from([message; $n]).unwrap());
+
+                let mut encoded = [0u8; 3 + $n * 25];
+                expected.try_serialize(&mut encoded).unwrap();
+
+                assert_eq!(expected.encoding_byte_length(), 3 + $n * 25);
+                assert_eq!(Message::try_from(encoded.as_ref()).unwrap(), expected);
+            };
+        }
+
+        round_trip!(1);
+        round_trip!(2);
+        round_trip!(3);
+        round_trip!(4);
+        round_trip!(5);
+        round_trip!(6);
+        round_trip!(7);
+        round_trip!(8);
+        round_trip!(9);
+    }
+
+    #[test]
+    fn test_decode_pack_fails_invalid_data_length() {
+        let message = Message::from(Pack::try_from([operator_id_message()]).unwrap());
+
+        let mut encoded = [0u8; 28];
+        message.try_serialize(&mut encoded).unwrap();
+
+        assert!(Message::try_from(&encoded[..1]).is_err());
+        assert!(Message::try_from(&encoded[..25]).is_err());
+        assert!(Message::try_from(&encoded[..27]).is_err());
+
+        let mut too_long = [0u8; 29];
+        too_long[..28].clone_from_slice(&encoded);
+
+        assert!(Message::try_from(too_long.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_decode_pack_fails_too_many_messages() {
+        let mut encoded = [0u8; 3 + 10 * 25];
+        encoded[0] = Message::PROTOCOL_VERSION | Pack::PACK_MESSAGE_CODE << 4;
+        encoded[1] = Pack::MESSAGES_LENGTH as u8;
+        encoded[2] = 10;
+
+        assert_eq!(
+            Message::try_from(encoded.as_ref()),
+            Err(Error::InvalidInteger)
+        );
+    }
+
+    #[test]
+    fn test_pack_fails_recursive_pack() {
+        let pack = Message::from(Pack::try_from([operator_id_message()]).unwrap());
+
+        assert_eq!(
+            Pack::try_from([operator_id_message(), pack]),
+            Err(Error::CannotRecursivelyPack)
+        );
+    }
+
+    #[test]
+    fn test_decode_pack_fails_recursive_pack() {
+        let inner = Message::from(Pack::try_from([operator_id_message()]).unwrap());
+
+        let mut encoded_inner = [0u8; 28];
+        inner.try_serialize(&mut encoded_inner).unwrap();
+
+        let mut encoded = [0u8; 28];
+        encoded[0] = Message::PROTOCOL_VERSION | Pack::PACK_MESSAGE_CODE << 4;
+        encoded[1] = Pack::MESSAGES_LENGTH as u8;
+        encoded[2] = 1;
+        encoded[3..28].clone_from_slice(&encoded_inner[..25]);
+
+        let decoded = Message::try_from(encoded.as_ref()).unwrap();
+
+        let MessageType::Pack(pack) = decoded.message_type() else {
+            panic!("expected pack");
+        };
 
-        assert!(Message::try_from(invalid.as_ref()).is_err());
+        assert_eq!(
+            pack.try_get_message(0),
+            Some(Err(Error::CannotRecursivelyPack))
+        );
     }
 }
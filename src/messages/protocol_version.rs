@@ -0,0 +1,185 @@
+use crate::authentication::Authentication;
+use crate::error::Error;
+use crate::location::Location;
+use crate::pack::Pack;
+use crate::system::System;
+
+/// Protocol Version
+///
+/// Lower nibble of the message header, signals which revision of ASTM F3411 the message follows.
+///
+/// The message layouts are shared across versions, except for:
+///
+/// | Field                                   | Version 0 | Version 1 | Version 2 |
+/// | --------------------------------------- | --------- | --------- | --------- |
+/// | Location timestamp accuracy             | Reserved  | Present   | Present   |
+/// | System classification type and class    | Reserved  | Reserved  | Present   |
+/// | Maximum authentication last page index  | 4         | 4         | 15        |
+///
+/// Reserved fields decode to their zero value, that is
+/// [`TimestampAccuracy::Unknown`](crate::location::TimestampAccuracy::Unknown),
+/// [`ClassificationType::Undeclared`](crate::system::ClassificationType::Undeclared), and
+/// [`UAClassification::Undefined`](crate::system::UAClassification::Undefined), and encode as
+/// zero whatever their value.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProtocolVersion {
+    /// Version 0, pre-release drafts of ASTM F3411.
+    Version0,
+    /// Version 1, ASTM F3411-19.
+    Version1,
+    /// Version 2, ASTM F3411-22a.
+    #[default]
+    Version2,
+}
+
+impl ProtocolVersion {
+    /// Returns the maximum authentication last page index.
+    pub fn max_last_page_index(&self) -> u8 {
+        match self {
+            Self::Version0 | Self::Version1 => 4,
+            Self::Version2 => 15,
+        }
+    }
+
+    /// Returns true if the Location message carries a timestamp accuracy.
+    pub fn has_timestamp_accuracy(&self) -> bool {
+        !matches!(self, Self::Version0)
+    }
+
+    /// Returns true if the System message carries the classification type and UA classification.
+    pub fn has_classification(&self) -> bool {
+        matches!(self, Self::Version2)
+    }
+
+    // conforms an encoded 25 byte message, header included, to the layout of this version by
+    // zeroing the fields it reserves. returns an error if an authentication page exceeds the page
+    // limit of this version.
+    pub(crate) fn try_conform(&self, message: &mut [u8]) -> Result<(), Error> {
+        if message.len() != Pack::MESSAGES_LENGTH {
+            return Ok(());
+        }
+
+        match message[0] >> 4 {
+            Location::LOCATION_MESSAGE_CODE if !self.has_timestamp_accuracy() => {
+                message[23] &= 0b1111_0000;
+            }
+            System::SYSTEM_MESSAGE_CODE if !self.has_classification() => {
+                message[1] &= 0b1110_0011;
+                message[17] = 0;
+            }
+            Authentication::AUTHENTICATION_MESSAGE_CODE => {
+                let page_number = message[1] & 0b0000_1111;
+
+                let max_last_page_index = self.max_last_page_index();
+
+                if page_number > max_last_page_index
+                    || (page_number == 0 && message[2] & 0b0000_1111 > max_last_page_index)
+                {
+                    return Err(Error::InvalidInteger);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<u8> for ProtocolVersion {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Version0),
+            1 => Ok(Self::Version1),
+            2 => Ok(Self::Version2),
+            _ => Err(Error::InvalidProtocolVersion),
+        }
+    }
+}
+
+impl From<ProtocolVersion> for u8 {
+    fn from(value: ProtocolVersion) -> Self {
+        value as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::Error, messages::ProtocolVersion};
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(u8::from(ProtocolVersion::Version0), 0);
+        assert_eq!(u8::from(ProtocolVersion::Version1), 1);
+        assert_eq!(u8::from(ProtocolVersion::Version2), 2);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(ProtocolVersion::try_from(0), Ok(ProtocolVersion::Version0));
+        assert_eq!(ProtocolVersion::try_from(1), Ok(ProtocolVersion::Version1));
+        assert_eq!(ProtocolVersion::try_from(2), Ok(ProtocolVersion::Version2));
+        assert_eq!(
+            ProtocolVersion::try_from(3),
+            Err(Error::InvalidProtocolVersion)
+        );
+    }
+
+    #[test]
+    fn test_conform_location() {
+        let mut location = [0u8; 25];
+        location[0] = 0x10;
+        location[23] = 0xff;
+
+        ProtocolVersion::Version1
+            .try_conform(&mut location)
+            .unwrap();
+
+        assert_eq!(location[23], 0xff);
+
+        ProtocolVersion::Version0
+            .try_conform(&mut location)
+            .unwrap();
+
+        assert_eq!(location[23], 0xf0);
+    }
+
+    #[test]
+    fn test_conform_system() {
+        let mut system = [0u8; 25];
+        system[0] = 0x40;
+        system[1] = 0xff;
+        system[17] = 0xff;
+
+        ProtocolVersion::Version2.try_conform(&mut system).unwrap();
+
+        assert_eq!(system[1], 0xff);
+        assert_eq!(system[17], 0xff);
+
+        ProtocolVersion::Version1.try_conform(&mut system).unwrap();
+
+        assert_eq!(system[1], 0xe3);
+        assert_eq!(system[17], 0);
+    }
+
+    #[test]
+    fn test_conform_fails_authentication_page_limit() {
+        let mut initial = [0u8; 25];
+        initial[0] = 0x20;
+        initial[2] = 5;
+
+        let mut subsequent = [0u8; 25];
+        subsequent[0] = 0x20;
+        subsequent[1] = 5;
+
+        for message in [&mut initial, &mut subsequent] {
+            assert!(ProtocolVersion::Version2.try_conform(message).is_ok());
+            assert_eq!(
+                ProtocolVersion::Version1.try_conform(message),
+                Err(Error::InvalidInteger)
+            );
+        }
+    }
+}
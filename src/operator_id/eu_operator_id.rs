@@ -0,0 +1,336 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::operator_id::OperatorID;
+use crate::operator_id::OperatorIDType;
+
+/// EU Operator Registration Number
+///
+/// Issued by the Civil Aviation Authority of an EU member state, and written as
+/// `FIN87astrdge12k8-xyz`:
+///
+/// | Characters | Field                                              |
+/// | ---------- | -------------------------------------------------- |
+/// | 3          | ISO 3166 alpha-3 code of the issuing country       |
+/// | 12         | public part, base 36                               |
+/// | 1          | check character, base 36                           |
+/// | 3          | secret part, base 36, after a dash                 |
+///
+/// The check character is the Luhn mod 36 check character of the public part followed by the
+/// secret part. The secret part is never broadcast, so the [`OperatorID`] built from a registration
+/// number carries the country code, the public part, and the check character only.
+///
+/// Country codes are upper case and the other parts lower case, input of either case is accepted.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EuOperatorId([u8; 20]);
+
+impl EuOperatorId {
+    /// Length of the country code.
+    pub const COUNTRY_CODE_LENGTH: usize = 3;
+
+    /// Length of the public part.
+    pub const PUBLIC_PART_LENGTH: usize = 12;
+
+    /// Length of the secret part.
+    pub const SECRET_PART_LENGTH: usize = 3;
+
+    /// Length of the broadcast registration number, without the padding of the [`OperatorID`].
+    pub const BROADCAST_LENGTH: usize = 16;
+
+    /// Tries to construct a new registration number, computing its check character.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `country_code`, `public_part`, or `secret_part` is not of its respective length.
+    /// - `country_code` is not ascii letters.
+    /// - `public_part` or `secret_part` is not ascii letters or digits.
+    pub fn try_new(
+        country_code: &str,
+        public_part: &str,
+        secret_part: &str,
+    ) -> Result<Self, Error> {
+        if country_code.len() != Self::COUNTRY_CODE_LENGTH
+            || public_part.len() != Self::PUBLIC_PART_LENGTH
+            || secret_part.len() != Self::SECRET_PART_LENGTH
+        {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let valid_country_code = country_code.bytes().all(|c| c.is_ascii_alphabetic());
+
+        let valid_public_part = public_part.bytes().all(|c| c.is_ascii_alphanumeric());
+
+        let valid_secret_part = secret_part.bytes().all(|c| c.is_ascii_alphanumeric());
+
+        if !valid_country_code || !valid_public_part || !valid_secret_part {
+            return Err(Error::InvalidOperatorID);
+        }
+
+        // INVARIANT: every character was checked to be base 36.
+        let check_character = check_character(public_part.bytes().chain(secret_part.bytes()))
+            .ok_or(Error::Unreachable)
+            .unwrap();
+
+        let mut id = [0u8; 20];
+        id[..3].clone_from_slice(country_code.as_bytes());
+        id[3..15].clone_from_slice(public_part.as_bytes());
+        id[15] = check_character;
+        id[16] = b'-';
+        id[17..].clone_from_slice(secret_part.as_bytes());
+
+        id[..3].make_ascii_uppercase();
+        id[3..].make_ascii_lowercase();
+
+        Ok(Self(id))
+    }
+
+    /// Returns the country code.
+    pub fn country_code(&self) -> &str {
+        self.as_str(0..3)
+    }
+
+    /// Returns the public part.
+    pub fn public_part(&self) -> &str {
+        self.as_str(3..15)
+    }
+
+    /// Returns the check character.
+    pub fn check_character(&self) -> char {
+        char::from(self.0[15])
+    }
+
+    /// Returns the secret part.
+    pub fn secret_part(&self) -> &str {
+        self.as_str(17..20)
+    }
+
+    /// Returns true if `operator_id` broadcasts this registration number.
+    pub fn is_broadcast_by(&self, operator_id: &OperatorID) -> bool {
+        OperatorID::from(*self) == *operator_id
+    }
+
+    // returns true if `id` has the shape of a broadcast registration number, the country code, the
+    // public part, and the check character, padded with zeroes.
+    pub(crate) fn is_broadcast_id(id: &[u8; 20]) -> bool {
+        let (number, padding) = id.split_at(Self::BROADCAST_LENGTH);
+
+        number[..3].iter().all(u8::is_ascii_uppercase)
+            && number[3..].iter().all(|c| digit(*c).is_some())
+            && padding.iter().all(|byte| *byte == 0)
+    }
+
+    // returns true if the check character of the broadcast registration number `id` matches its
+    // public part followed by `secret_part`.
+    pub(crate) fn is_valid_broadcast_id(id: &[u8; 20], secret_part: &[u8; 3]) -> bool {
+        let characters = id[3..15].iter().chain(secret_part).copied();
+
+        let expected = check_character(characters);
+
+        expected.is_some_and(|expected| expected.eq_ignore_ascii_case(&id[15]))
+    }
+
+    // returns the characters of the registration number within `range`.
+    fn as_str(&self, range: core::ops::Range<usize>) -> &str {
+        str::from_utf8(&self.0[range])
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+}
+
+impl FromStr for EuOperatorId {
+    type Err = Error;
+
+    /// Parses a registration number, such as `FIN87astrdge12k8-xyz`, the dash being optional.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `s` is neither 19 nor 20 characters long, or has no dash at the 17th of 20 characters.
+    /// - `s` contains a character [`EuOperatorId::try_new`] rejects.
+    /// - the check character does not match the public and secret parts.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() {
+            return Err(Error::InvalidOperatorID);
+        }
+
+        let secret_start = match s.len() {
+            19 => 16,
+            20 if s.as_bytes()[16] == b'-' => 17,
+            _ => return Err(Error::InvalidDataLength),
+        };
+
+        let value = Self::try_new(&s[..3], &s[3..15], &s[secret_start..])?;
+
+        if !s.as_bytes()[15].eq_ignore_ascii_case(&value.0[15]) {
+            return Err(Error::InvalidChecksum);
+        }
+
+        Ok(value)
+    }
+}
+
+impl fmt::Display for EuOperatorId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str(0..20))
+    }
+}
+
+impl From<EuOperatorId> for OperatorID {
+    fn from(value: EuOperatorId) -> Self {
+        let mut id = [0u8; 20];
+        id[..EuOperatorId::BROADCAST_LENGTH]
+            .clone_from_slice(&value.0[..EuOperatorId::BROADCAST_LENGTH]);
+
+        OperatorID::new(OperatorIDType::OperatorID, id)
+    }
+}
+
+// luhn mod 36 check character, doubling every other digit starting from the rightmost one. `None`
+// if a character is not base 36.
+fn check_character(characters: impl DoubleEndedIterator<Item = u8>) -> Option<u8> {
+    let mut sum = 0;
+
+    for (index, c) in characters.rev().enumerate() {
+        let factor = match index % 2 {
+            0 => 2,
+            _ => 1,
+        };
+
+        let addend = factor * digit(c)?;
+
+        sum += addend / 36 + addend % 36;
+    }
+
+    let check_digit = (36 - sum % 36) % 36;
+
+    // INVARIANT: the check digit is below 36.
+    let check_character = char::from_digit(check_digit, 36)
+        .ok_or(Error::Unreachable)
+        .unwrap();
+
+    Some(check_character as u8)
+}
+
+// value of a base 36 character, case insensitive.
+fn digit(c: u8) -> Option<u32> {
+    char::from(c).to_digit(36)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::{
+        error::Error,
+        operator_id::{EuOperatorId, OperatorID, OperatorIDType},
+    };
+
+    #[test]
+    fn test_try_new() {
+        let eu_operator_id = EuOperatorId::try_new("FIN", "87astrdge12k", "xyz").unwrap();
+
+        assert_eq!(eu_operator_id.country_code(), "FIN");
+        assert_eq!(eu_operator_id.public_part(), "87astrdge12k");
+        assert_eq!(eu_operator_id.check_character(), '8');
+        assert_eq!(eu_operator_id.secret_part(), "xyz");
+    }
+
+    #[test]
+    fn test_try_new_normalizes_case() {
+        let eu_operator_id = EuOperatorId::try_new("fin", "87ASTRDGE12K", "XYZ").unwrap();
+
+        assert_eq!(eu_operator_id.to_string(), "FIN87astrdge12k8-xyz");
+    }
+
+    #[test]
+    fn test_try_new_fails() {
+        assert_eq!(
+            EuOperatorId::try_new("FI", "87astrdge12k", "xyz"),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            EuOperatorId::try_new("FIN", "87astrdge12", "xyz"),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            EuOperatorId::try_new("F1N", "87astrdge12k", "xyz"),
+            Err(Error::InvalidOperatorID)
+        );
+        assert_eq!(
+            EuOperatorId::try_new("FIN", "87astrdge12k", "xy-"),
+            Err(Error::InvalidOperatorID)
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = EuOperatorId::try_new("FIN", "87astrdge12k", "xyz").unwrap();
+
+        assert_eq!("FIN87astrdge12k8-xyz".parse(), Ok(expected));
+        assert_eq!("FIN87astrdge12k8xyz".parse(), Ok(expected));
+        assert_eq!("fin87ASTRDGE12K8-XYZ".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_fails() {
+        assert_eq!(
+            "FIN87astrdge12k9-xyz".parse::<EuOperatorId>(),
+            Err(Error::InvalidChecksum)
+        );
+        assert_eq!(
+            "FIN87astrdge12k8-xya".parse::<EuOperatorId>(),
+            Err(Error::InvalidChecksum)
+        );
+        assert_eq!(
+            "FIN87astrdge12k8_xyz".parse::<EuOperatorId>(),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            "FIN87astrdge12k8".parse::<EuOperatorId>(),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            "FIN87astrdgé12k8-xy".parse::<EuOperatorId>(),
+            Err(Error::InvalidOperatorID)
+        );
+    }
+
+    #[test]
+    fn test_operator_id() {
+        let eu_operator_id: EuOperatorId = "FIN87astrdge12k8-xyz".parse().unwrap();
+
+        let mut id = [0u8; 20];
+        id[..16].clone_from_slice(b"FIN87astrdge12k8");
+
+        let operator_id = OperatorID::from(eu_operator_id);
+
+        assert_eq!(operator_id.operator_id_type(), OperatorIDType::OperatorID);
+        assert_eq!(operator_id.id(), &id);
+        assert!(eu_operator_id.is_broadcast_by(&operator_id));
+        assert!(
+            !eu_operator_id.is_broadcast_by(&OperatorID::new(OperatorIDType::OperatorID, [0; 20]))
+        );
+    }
+
+    #[test]
+    fn test_broadcast_id() {
+        let mut id = [0u8; 20];
+        id[..16].clone_from_slice(b"FIN87astrdge12k8");
+
+        assert!(EuOperatorId::is_broadcast_id(&id));
+        assert!(EuOperatorId::is_valid_broadcast_id(&id, b"xyz"));
+        assert!(!EuOperatorId::is_valid_broadcast_id(&id, b"xya"));
+        assert!(!EuOperatorId::is_valid_broadcast_id(&id, b"xy-"));
+
+        id[..3].clone_from_slice(b"fin");
+
+        assert!(!EuOperatorId::is_broadcast_id(&id));
+
+        id[..3].clone_from_slice(b"FIN");
+        id[16] = b'-';
+
+        assert!(!EuOperatorId::is_broadcast_id(&id));
+    }
+}
@@ -2,8 +2,27 @@
 //!
 //! [`OperatorID`] messages contain an identifier unique to the UAS operator, often issued by the
 //! operator's respective Civil Aviation Authority.
+//!
+//! ### EU Operator Registration Numbers
+//!
+//! Operators registered in the EU broadcast the public part of their registration number, which an
+//! [`EuOperatorId`] parses from the full number, such as typed in by the operator, and converts
+//! into an [`OperatorID`].
+//!
+//! ```rust
+//! use drone_id::operator_id::EuOperatorId;
+//! use drone_id::operator_id::OperatorID;
+//!
+//! let eu_operator_id: EuOperatorId = "FIN87astrdge12k8-xyz".parse().unwrap();
+//!
+//! let operator_id = OperatorID::from(eu_operator_id);
+//!
+//! assert_eq!(&operator_id.id()[..16], b"FIN87astrdge12k8");
+//! ```
+mod eu_operator_id;
 mod operator_id_type;
 
+pub use eu_operator_id::EuOperatorId;
 pub use operator_id_type::OperatorIDType;
 
 use crate::error::Error;
@@ -14,8 +33,10 @@ use crate::try_serialize::TrySerialize;
 /// Identifies the operator with a unique identifier issued by their respective Civil Aviation
 /// Authority.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperatorID {
     operator_id_type: OperatorIDType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::ascii"))]
     id: [u8; 20],
 }
 
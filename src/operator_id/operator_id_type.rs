@@ -2,15 +2,16 @@
 ///
 /// Generally set to [`OperatorIDType::OperatorID`] (0).
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OperatorIDType {
     /// Operator ID
     ///
     /// Most common option.
     OperatorID,
-    /// Reserved.
-    Reserved,
-    /// Available for private use.
-    PrivateUse,
+    /// Reserved, codes 1 to 200.
+    Reserved(u8),
+    /// Available for private use, codes 201 to 255.
+    PrivateUse(u8),
 }
 
 impl OperatorIDType {
@@ -30,10 +31,10 @@ impl From<u8> for OperatorIDType {
         }
 
         if value < Self::PRIVATE_USE_THRESHOLD {
-            return Self::Reserved;
+            return Self::Reserved(value);
         }
 
-        Self::PrivateUse
+        Self::PrivateUse(value)
     }
 }
 
@@ -41,8 +42,11 @@ impl From<OperatorIDType> for u8 {
     fn from(value: OperatorIDType) -> Self {
         match value {
             OperatorIDType::OperatorID => 0,
-            OperatorIDType::Reserved => OperatorIDType::RESERVED_THRESHOLD,
-            OperatorIDType::PrivateUse => OperatorIDType::PRIVATE_USE_THRESHOLD,
+            OperatorIDType::Reserved(n) => n.clamp(
+                OperatorIDType::RESERVED_THRESHOLD,
+                OperatorIDType::PRIVATE_USE_THRESHOLD - 1,
+            ),
+            OperatorIDType::PrivateUse(n) => n.max(OperatorIDType::PRIVATE_USE_THRESHOLD),
         }
     }
 }
@@ -60,16 +64,25 @@ mod tests {
 
     #[test]
     fn test_encode_reserved() {
-        let reserved = OperatorIDType::Reserved;
+        let reserved = OperatorIDType::Reserved(OperatorIDType::RESERVED_THRESHOLD);
 
         assert_eq!(u8::from(reserved), OperatorIDType::RESERVED_THRESHOLD);
+        assert_eq!(
+            u8::from(OperatorIDType::Reserved(0)),
+            OperatorIDType::RESERVED_THRESHOLD
+        );
+        assert_eq!(u8::from(OperatorIDType::Reserved(0xff)), 200);
     }
 
     #[test]
     fn test_encode_private_use() {
-        let private_use = OperatorIDType::PrivateUse;
+        let private_use = OperatorIDType::PrivateUse(OperatorIDType::PRIVATE_USE_THRESHOLD);
 
         assert_eq!(u8::from(private_use), OperatorIDType::PRIVATE_USE_THRESHOLD);
+        assert_eq!(
+            u8::from(OperatorIDType::PrivateUse(0)),
+            OperatorIDType::PRIVATE_USE_THRESHOLD
+        );
     }
 
     #[test]
@@ -83,13 +96,26 @@ mod tests {
     fn test_decode_reserved() {
         let decoded = OperatorIDType::from(OperatorIDType::RESERVED_THRESHOLD);
 
-        assert_eq!(decoded, OperatorIDType::Reserved);
+        assert_eq!(
+            decoded,
+            OperatorIDType::Reserved(OperatorIDType::RESERVED_THRESHOLD)
+        );
     }
 
     #[test]
     fn test_decode_private_use() {
         let decoded = OperatorIDType::from(OperatorIDType::PRIVATE_USE_THRESHOLD);
 
-        assert_eq!(decoded, OperatorIDType::PrivateUse);
+        assert_eq!(
+            decoded,
+            OperatorIDType::PrivateUse(OperatorIDType::PRIVATE_USE_THRESHOLD)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=u8::MAX {
+            assert_eq!(u8::from(OperatorIDType::from(code)), code);
+        }
     }
 }
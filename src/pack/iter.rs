@@ -0,0 +1,158 @@
+use core::iter::FusedIterator;
+
+use crate::error::Error;
+use crate::messages::Message;
+use crate::pack::Pack;
+
+/// Iterator over the messages of a borrowed [`Pack`], see [`Pack::iter`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    pack: &'a Pack,
+    index: u8,
+}
+
+impl<'a> Iter<'a> {
+    pub(crate) fn new(pack: &'a Pack) -> Self {
+        Self { pack, index: 0 }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Result<Message, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let message = self.pack.try_get_message(self.index)?;
+
+        self.index += 1;
+
+        Some(message)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.pack.number_of_messages() - self.index) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+/// Iterator over the messages of an owned [`Pack`], see [`Pack::into_iter`].
+#[derive(Clone, Debug)]
+pub struct IntoIter {
+    pack: Pack,
+    index: u8,
+}
+
+impl Iterator for IntoIter {
+    type Item = Result<Message, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let message = self.pack.try_get_message(self.index)?;
+
+        self.index += 1;
+
+        Some(message)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.pack.number_of_messages() - self.index) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl FusedIterator for IntoIter {}
+
+impl IntoIterator for Pack {
+    type Item = Result<Message, Error>;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            pack: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Pack {
+    type Item = Result<Message, Error>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        basic_id::{BasicID, UASID, UAType},
+        error::Error,
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        try_serialize::TrySerialize,
+    };
+
+    fn messages() -> [Message; 2] {
+        [
+            Message::from(BasicID::new(UAType::Helicopter, UASID::None)),
+            Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20])),
+        ]
+    }
+
+    #[test]
+    fn test_iter() {
+        let pack = Pack::try_from(messages()).unwrap();
+
+        let mut iter = pack.iter();
+
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(Ok(messages()[0])));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(Ok(messages()[1])));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let pack = Pack::try_from(messages()).unwrap();
+
+        assert!((&pack).into_iter().map(Result::unwrap).eq(messages()));
+        assert!(pack.into_iter().map(Result::unwrap).eq(messages()));
+    }
+
+    #[test]
+    fn test_iter_yields_errors() {
+        let mut encoded = [0u8; 52];
+        Pack::try_from(messages())
+            .unwrap()
+            .try_serialize(&mut encoded)
+            .unwrap();
+
+        // second packed message claims an unsupported protocol version.
+        encoded[27] |= 0x03;
+
+        let pack = Pack::try_from(encoded.as_ref()).unwrap();
+
+        assert!(
+            pack.iter()
+                .eq([Ok(messages()[0]), Err(Error::InvalidProtocolVersion)])
+        );
+    }
+
+    #[test]
+    fn test_iter_empty() {
+        let pack = Pack::try_from([] as [Message; 0]).unwrap();
+
+        assert_eq!(pack.iter().len(), 0);
+        assert_eq!(pack.iter().next(), None);
+    }
+}
@@ -9,8 +9,21 @@
 //! The upper bound of bytes required for the internal messages in the pack is `225` bytes
 //! (`9 * 25`).
 //!
-//! This may be constructed from deserializing bytes directly or from a reference to a message
-//! array.
+//! This may be constructed from deserializing bytes directly, from a message array, or one message
+//! at a time through [`Pack::builder`]. The messages are iterated with [`Pack::iter`], or by
+//! reference or value in a `for` loop.
+//!
+//! [`PackRef`] skips the copy altogether, borrowing the encoded pack and handing out borrowed
+//! messages.
+mod iter;
+mod pack_builder;
+mod pack_ref;
+
+pub use iter::IntoIter;
+pub use iter::Iter;
+pub use pack_builder::PackBuilder;
+pub use pack_ref::PackRef;
+
 use crate::error::Error;
 use crate::messages::Message;
 use crate::try_serialize::TrySerialize;
@@ -32,6 +45,12 @@ impl Pack {
     /// Message code for pack messages is `0x0F`.
     pub const PACK_MESSAGE_CODE: u8 = 0x0f;
 
+    /// Pack header length, one byte for the message length and one for the number of messages.
+    pub const HEADER_LENGTH: usize = 2;
+
+    /// Maximum number of messages in a pack.
+    pub const MAX_MESSAGES: u8 = 9;
+
     /// Tries to get a message.
     ///
     /// Returns [`Option::None`] if the index exceeds the number of messages.
@@ -46,7 +65,7 @@ impl Pack {
 
         let raw_message = &self.messages[offset..offset + 25];
 
-        if raw_message[0] == Self::PACK_MESSAGE_CODE {
+        if raw_message[0] >> 4 == Self::PACK_MESSAGE_CODE {
             // no recursive packing
             return Some(Err(Error::CannotRecursivelyPack));
         }
@@ -62,42 +81,53 @@ impl Pack {
         self.number_of_messages
     }
 
-    /// Returns the raw message data.
+    /// Returns the byte length of the encoded pack.
+    ///
+    /// This is the two byte pack header followed by `25` bytes per message. It does not include
+    /// the message header byte, see [`Message::encoding_byte_length`].
+    pub fn encoding_byte_length(&self) -> usize {
+        Self::HEADER_LENGTH + self.number_of_messages as usize * Self::MESSAGES_LENGTH
+    }
+
+    /// Returns the raw message data, `25` bytes per message.
     ///
     /// For finding a specific message, use [`Pack::try_get_message`].
     pub fn messages(&self) -> &[u8] {
-        &self.messages
+        &self.messages[..self.number_of_messages as usize * Self::MESSAGES_LENGTH]
     }
-}
 
-impl TryFrom<&[u8]> for Pack {
-    type Error = Error;
-
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let number_of_messages = *value.get(1).ok_or(Error::InvalidDataLength)?;
-
-        if number_of_messages > 9 {
-            return Err(Error::InvalidInteger);
-        }
+    /// Returns an iterator decoding each message in turn.
+    ///
+    /// Yields exactly [`Pack::number_of_messages`] results, a message which fails to be
+    /// deserialized yields an error without ending the iteration.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(self)
+    }
 
-        if value.len() != 2 + number_of_messages as usize * Self::MESSAGES_LENGTH {
-            return Err(Error::InvalidDataLength);
+    // decodes every message before yielding any, so a malformed pack is rejected before any of its
+    // messages is used.
+    pub(crate) fn try_decode_all(&self) -> Result<impl Iterator<Item = Message> + '_, Error> {
+        for message in self {
+            message?;
         }
 
-        if value[0] != Self::MESSAGES_LENGTH as u8 {
-            return Err(Error::InvalidInteger);
-        }
+        // INVARIANT: every message was decoded above.
+        Ok(self
+            .iter()
+            .map(|message| message.map_err(|_| Error::Unreachable).unwrap()))
+    }
 
-        let mut messages = [0u8; 225];
+    /// Returns a builder to push messages onto an empty pack one at a time.
+    pub fn builder() -> PackBuilder {
+        PackBuilder::new()
+    }
+}
 
-        for i in 2..value.len() {
-            messages[i - 2] = value[i];
-        }
+impl TryFrom<&[u8]> for Pack {
+    type Error = Error;
 
-        Ok(Self {
-            number_of_messages,
-            messages,
-        })
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        PackRef::try_from(value).map(Self::from)
     }
 }
 
@@ -107,13 +137,13 @@ impl TrySerialize for Pack {
     fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
         let messages_length = self.number_of_messages as usize * Self::MESSAGES_LENGTH;
 
-        if buffer.len() != 2 + messages_length {
+        if buffer.len() != Self::HEADER_LENGTH + messages_length {
             return Err(Error::InvalidDataLength);
         }
 
         buffer[0] = Self::MESSAGES_LENGTH as u8;
         buffer[1] = self.number_of_messages;
-        buffer[2..].clone_from_slice(&self.messages[..messages_length]);
+        buffer[Self::HEADER_LENGTH..].clone_from_slice(&self.messages[..messages_length]);
 
         Ok(())
     }
@@ -123,24 +153,71 @@ impl<const N: usize> TryFrom<[Message; N]> for Pack {
     type Error = Error;
 
     fn try_from(value: [Message; N]) -> Result<Self, Self::Error> {
-        if N > 9 {
+        if N > Self::MAX_MESSAGES as usize {
             return Err(Error::InvalidInteger);
         }
 
-        let mut buffer = [0u8; 225];
+        let mut builder = Self::builder();
 
-        for i in 0..N {
-            let start = i * Self::MESSAGES_LENGTH;
+        for message in value {
+            builder.try_push(message)?;
+        }
 
-            let end = start + Self::MESSAGES_LENGTH;
+        Ok(builder.build())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pack {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.number_of_messages as usize))?;
+
+        for message in self {
+            seq.serialize_element(&message.map_err(serde::ser::Error::custom)?)?;
+        }
+
+        seq.end()
+    }
+}
 
-            value[i].try_serialize(&mut buffer[start..end])?;
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pack {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PackVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for PackVisitor {
+            type Value = Pack;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a sequence of one to nine messages")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut builder = Pack::builder();
+
+                while let Some(message) = seq.next_element::<Message>()? {
+                    if builder.is_full() {
+                        return Err(serde::de::Error::invalid_length(
+                            Pack::MAX_MESSAGES as usize + 1,
+                            &self,
+                        ));
+                    }
+
+                    builder
+                        .try_push(message)
+                        .map_err(serde::de::Error::custom)?;
+                }
+
+                Ok(builder.build())
+            }
         }
 
-        Ok(Self {
-            number_of_messages: N as u8,
-            messages: buffer,
-        })
+        deserializer.deserialize_seq(PackVisitor)
     }
 }
 
@@ -173,7 +250,7 @@ mod tests {
 
         assert_eq!(pack.try_get_message(0).unwrap().unwrap(), message);
         assert_eq!(pack.number_of_messages(), 1);
-        assert_eq!(pack.messages(), encoded_messages);
+        assert_eq!(pack.messages(), &encoded_messages[..25]);
     }
 
     #[test]
@@ -0,0 +1,135 @@
+use crate::error::Error;
+use crate::messages::Message;
+use crate::pack::Pack;
+use crate::try_serialize::TrySerialize;
+
+/// Pack Builder
+///
+/// Builds a [`Pack`] one message at a time, see [`Pack::builder`]. Messages are encoded as they
+/// are pushed, so a message which cannot be packed is rejected immediately and leaves the builder
+/// unchanged.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PackBuilder {
+    pack: Pack,
+}
+
+impl PackBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            pack: Pack {
+                number_of_messages: 0,
+                messages: [0u8; 225],
+            },
+        }
+    }
+
+    /// Pushes a message onto the end of the pack.
+    ///
+    /// Returns an error if:
+    ///
+    /// - the pack already holds [`Pack::MAX_MESSAGES`] messages.
+    /// - `message` is itself a pack.
+    /// - `message` fails to be encoded.
+    pub fn try_push(&mut self, message: Message) -> Result<&mut Self, Error> {
+        if self.pack.number_of_messages == Pack::MAX_MESSAGES {
+            return Err(Error::InvalidInteger);
+        }
+
+        // no recursive packing
+        if message.is_pack() {
+            return Err(Error::CannotRecursivelyPack);
+        }
+
+        let start = self.pack.number_of_messages as usize * Pack::MESSAGES_LENGTH;
+
+        let end = start + Pack::MESSAGES_LENGTH;
+
+        let mut encoded = [0u8; Pack::MESSAGES_LENGTH];
+        message.try_serialize(&mut encoded)?;
+
+        self.pack.messages[start..end].clone_from_slice(&encoded);
+        self.pack.number_of_messages += 1;
+
+        Ok(self)
+    }
+
+    /// Returns the number of messages pushed so far.
+    pub fn number_of_messages(&self) -> u8 {
+        self.pack.number_of_messages
+    }
+
+    /// Returns true if no more messages fit in the pack.
+    pub fn is_full(&self) -> bool {
+        self.pack.number_of_messages == Pack::MAX_MESSAGES
+    }
+
+    /// Returns the pack of the messages pushed so far.
+    pub fn build(&self) -> Pack {
+        self.pack
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        fixtures::{basic_id_message, operator_id_message},
+        messages::Message,
+        pack::Pack,
+    };
+
+    #[test]
+    fn test_build() {
+        let mut builder = Pack::builder();
+
+        builder
+            .try_push(basic_id_message())
+            .unwrap()
+            .try_push(operator_id_message())
+            .unwrap();
+
+        assert_eq!(builder.number_of_messages(), 2);
+        assert!(!builder.is_full());
+        assert_eq!(
+            builder.build(),
+            Pack::try_from([basic_id_message(), operator_id_message()]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_build_empty() {
+        assert_eq!(
+            Pack::builder().build(),
+            Pack::try_from([] as [Message; 0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_try_push_fails_full() {
+        let mut builder = Pack::builder();
+
+        for _ in 0..Pack::MAX_MESSAGES {
+            builder.try_push(operator_id_message()).unwrap();
+        }
+
+        assert!(builder.is_full());
+        assert_eq!(
+            builder.try_push(operator_id_message()).err(),
+            Some(Error::InvalidInteger)
+        );
+        assert_eq!(builder.number_of_messages(), Pack::MAX_MESSAGES);
+    }
+
+    #[test]
+    fn test_try_push_fails_recursive_pack() {
+        let pack = Message::from(Pack::try_from([operator_id_message()]).unwrap());
+
+        let mut builder = Pack::builder();
+
+        assert_eq!(
+            builder.try_push(pack).err(),
+            Some(Error::CannotRecursivelyPack)
+        );
+        assert_eq!(builder.number_of_messages(), 0);
+    }
+}
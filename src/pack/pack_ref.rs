@@ -0,0 +1,193 @@
+use crate::error::Error;
+use crate::messages::MessageRef;
+use crate::pack::Pack;
+
+/// Borrowed Pack Message
+///
+/// Zero-copy view of an encoded [`Pack`], the pack header followed by its messages. The header and
+/// length are validated once, on construction; each message is validated when it is retrieved via
+/// [`PackRef::try_get_message`], as with [`Pack::try_get_message`].
+///
+/// The messages are not validated up front on purpose: a pack is accepted by exactly the same rules
+/// as [`Message::try_from`](crate::messages::Message), and one malformed message does not hide the
+/// others, which can still be retrieved and reported on.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PackRef<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> PackRef<'a> {
+    /// Tries to get a borrowed message.
+    ///
+    /// Returns [`Option::None`] if the index exceeds the number of messages.
+    ///
+    /// Returns [`Result::Err`] if the message fails to be validated.
+    pub fn try_get_message(&self, index: u8) -> Option<Result<MessageRef<'a>, Error>> {
+        if index >= self.number_of_messages() {
+            return None;
+        }
+
+        let offset = Pack::HEADER_LENGTH + index as usize * Pack::MESSAGES_LENGTH;
+
+        let raw_message = &self.bytes[offset..offset + Pack::MESSAGES_LENGTH];
+
+        if raw_message[0] >> 4 == Pack::PACK_MESSAGE_CODE {
+            // no recursive packing
+            return Some(Err(Error::CannotRecursivelyPack));
+        }
+
+        Some(MessageRef::try_from(raw_message))
+    }
+
+    /// Returns the number of messages in the pack.
+    pub fn number_of_messages(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    /// Returns the byte length of the encoded pack.
+    ///
+    /// As with [`Pack::encoding_byte_length`], the message header byte is not included.
+    pub fn encoding_byte_length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the raw message data, without the pack header.
+    ///
+    /// For finding a specific message, use [`PackRef::try_get_message`].
+    pub fn messages(&self) -> &'a [u8] {
+        &self.bytes[Pack::HEADER_LENGTH..]
+    }
+
+    /// Returns the borrowed bytes, the pack header included.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PackRef<'a> {
+    type Error = Error;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        let number_of_messages = *value.get(1).ok_or(Error::InvalidDataLength)?;
+
+        if number_of_messages > Pack::MAX_MESSAGES {
+            return Err(Error::InvalidInteger);
+        }
+
+        if value.len() != Pack::HEADER_LENGTH + number_of_messages as usize * Pack::MESSAGES_LENGTH
+        {
+            return Err(Error::InvalidDataLength);
+        }
+
+        if value[0] != Pack::MESSAGES_LENGTH as u8 {
+            return Err(Error::InvalidInteger);
+        }
+
+        Ok(Self { bytes: value })
+    }
+}
+
+impl From<PackRef<'_>> for Pack {
+    fn from(value: PackRef<'_>) -> Self {
+        let messages = value.messages();
+
+        let mut buffer = [0u8; 225];
+        buffer[..messages.len()].clone_from_slice(messages);
+
+        Pack {
+            number_of_messages: value.number_of_messages(),
+            messages: buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        fixtures::{basic_id_message, pack},
+        messages::Message,
+        pack::{Pack, PackRef},
+        try_serialize::TrySerialize,
+    };
+
+    fn encoded() -> [u8; 52] {
+        let mut encoded = [0u8; 52];
+        pack().try_serialize(&mut encoded).unwrap();
+
+        encoded
+    }
+
+    #[test]
+    fn test_getters() {
+        let encoded = encoded();
+
+        let pack_ref = PackRef::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(pack_ref.number_of_messages(), 2);
+        assert_eq!(pack_ref.encoding_byte_length(), 52);
+        assert_eq!(pack_ref.messages(), &encoded[2..]);
+        assert_eq!(pack_ref.as_bytes(), encoded.as_ref());
+        assert_eq!(
+            Message::from(pack_ref.try_get_message(1).unwrap().unwrap()),
+            basic_id_message()
+        );
+        assert!(pack_ref.try_get_message(2).is_none());
+    }
+
+    #[test]
+    fn test_decode() {
+        let encoded = encoded();
+
+        assert_eq!(
+            Pack::from(PackRef::try_from(encoded.as_ref()).unwrap()),
+            pack()
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let encoded = encoded();
+
+        assert_eq!(
+            PackRef::try_from(&encoded[..51]),
+            Err(Error::InvalidDataLength)
+        );
+        assert_eq!(
+            PackRef::try_from(&encoded[..1]),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_header() {
+        let mut too_many = encoded();
+        too_many[1] = 10;
+
+        let mut message_length = encoded();
+        message_length[0] = 24;
+
+        assert_eq!(
+            PackRef::try_from(too_many.as_ref()),
+            Err(Error::InvalidInteger)
+        );
+        assert_eq!(
+            PackRef::try_from(message_length.as_ref()),
+            Err(Error::InvalidInteger)
+        );
+    }
+
+    #[test]
+    fn test_try_get_message_fails_recursive_pack() {
+        let mut encoded = encoded();
+        encoded[27] = Pack::PACK_MESSAGE_CODE << 4 | Message::PROTOCOL_VERSION;
+
+        let pack_ref = PackRef::try_from(encoded.as_ref()).unwrap();
+
+        assert!(pack_ref.try_get_message(0).unwrap().is_ok());
+        assert_eq!(
+            pack_ref.try_get_message(1).unwrap(),
+            Err(Error::CannotRecursivelyPack)
+        );
+    }
+}
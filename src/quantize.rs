@@ -0,0 +1,34 @@
+//! ## Quantize Trait
+//!
+//! Several field types hold a float, or a wider integer, than their encoding can represent, for
+//! example an [`Altitude`](crate::location::Altitude) is encoded in steps of half a meter. A value
+//! which is encoded and decoded again is therefore not always equal to the original value.
+//!
+//! The [`Quantize`] trait returns the value as it decodes after encoding, as well as the error
+//! introduced by doing so. [`Message::normalized`](crate::messages::Message::normalized) quantizes
+//! every field of a message, such that comparing a sent message against the received one is
+//! exact.
+
+/// Quantize
+pub trait Quantize: PartialEq + Sized {
+    /// Returns the value as it decodes after being encoded.
+    fn quantize(&self) -> Self;
+
+    /// Returns the known value, or `None` if the value is not known.
+    fn known_value(&self) -> Option<f64>;
+
+    /// Returns the absolute difference between the value and its quantized value.
+    ///
+    /// Returns `None` unless both the value and its quantized value are known.
+    fn quantization_error(&self) -> Option<f64> {
+        let value = self.known_value()?;
+        let quantized = self.quantize().known_value()?;
+
+        Some((value - quantized).abs())
+    }
+
+    /// Returns true if the value decodes to itself after being encoded.
+    fn is_lossless(&self) -> bool {
+        self.quantize() == *self
+    }
+}
@@ -0,0 +1,204 @@
+use crate::authentication::Authentication;
+use crate::basic_id::BasicID;
+use crate::location::Location;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::operator_id::OperatorID;
+use crate::self_id::SelfID;
+use crate::system::System;
+
+/// Received Value
+///
+/// Pairs a decoded value with the time, in caller milliseconds, at which it was last received.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Received<T> {
+    value: T,
+    time: u64,
+}
+
+impl<T> Received<T> {
+    /// Returns the received value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the time at which the value was last received.
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+/// Tracked Aircraft
+///
+/// Contains the latest value of each message type received from one UA, the time each was last
+/// received, and the number of frames missed according to the transport message counters.
+///
+/// Authentication is paginated, so the latest value of each page is kept, indexed by page number.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Aircraft<K> {
+    key: K,
+    first_seen: u64,
+    last_seen: u64,
+    basic_id: Option<Received<BasicID>>,
+    location: Option<Received<Location>>,
+    // indexed by page number, up to 16 pages.
+    authentication: [Option<Received<Authentication>>; 16],
+    self_id: Option<Received<SelfID>>,
+    system: Option<Received<System>>,
+    operator_id: Option<Received<OperatorID>>,
+    // last message counter per message type code, with the pack in the last slot.
+    message_counters: [Option<u8>; 7],
+    // counters missed in the reorder window of each slot, bit `n` is set when `last - 1 - n` was
+    // missed.
+    missing_counters: [u16; 7],
+    missed_frames: u32,
+}
+
+impl<K> Aircraft<K> {
+    // largest number of frames a counter may step back by and still be a late frame rather than a
+    // gap of nearly a full wrap around.
+    const REORDER_WINDOW: u8 = 16;
+
+    /// Returns the key identifying the UA.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the time at which the UA was first received.
+    pub fn first_seen(&self) -> u64 {
+        self.first_seen
+    }
+
+    /// Returns the time at which any frame was last received from the UA.
+    pub fn last_seen(&self) -> u64 {
+        self.last_seen
+    }
+
+    /// Returns the latest [`BasicID`], if any.
+    pub fn basic_id(&self) -> Option<&Received<BasicID>> {
+        self.basic_id.as_ref()
+    }
+
+    /// Returns the latest [`Location`], if any.
+    pub fn location(&self) -> Option<&Received<Location>> {
+        self.location.as_ref()
+    }
+
+    /// Returns the latest [`Authentication`] page with the given page number, if any.
+    ///
+    /// Returns [`Option::None`] if the page number is greater than 15.
+    pub fn authentication_page(&self, page_number: usize) -> Option<&Received<Authentication>> {
+        self.authentication.get(page_number)?.as_ref()
+    }
+
+    /// Returns the latest [`SelfID`], if any.
+    pub fn self_id(&self) -> Option<&Received<SelfID>> {
+        self.self_id.as_ref()
+    }
+
+    /// Returns the latest [`System`], if any.
+    pub fn system(&self) -> Option<&Received<System>> {
+        self.system.as_ref()
+    }
+
+    /// Returns the latest [`OperatorID`], if any.
+    pub fn operator_id(&self) -> Option<&Received<OperatorID>> {
+        self.operator_id.as_ref()
+    }
+
+    /// Returns the total number of frames missed, according to the transport message counters.
+    ///
+    /// A frame received out of order, up to 16 frames late, is no longer counted as missed.
+    /// Saturates at [`u32::MAX`].
+    pub fn missed_frames(&self) -> u32 {
+        self.missed_frames
+    }
+
+    pub(crate) fn new(key: K, time: u64) -> Self {
+        Self {
+            key,
+            first_seen: time,
+            last_seen: time,
+            basic_id: None,
+            location: None,
+            authentication: [None; 16],
+            self_id: None,
+            system: None,
+            operator_id: None,
+            message_counters: [None; 7],
+            missing_counters: [0; 7],
+            missed_frames: 0,
+        }
+    }
+
+    // records the message counter of a frame carrying a message of the given type.
+    //
+    // the counter is tracked per message type, as Bluetooth legacy transmitters keep one counter
+    // per message type, while packs are tracked in their own slot. a repeated counter is the same
+    // frame received again, for example on another advertising channel, and is not a gap. a counter
+    // up to `REORDER_WINDOW` behind the last one is a frame received out of order, which is no
+    // longer missed if it was counted as such.
+    pub(crate) fn record_message_counter(&mut self, message: &Message, message_counter: u8) {
+        let slot = match message.message_type() {
+            MessageType::BasicID(_) => 0,
+            MessageType::Location(_) => 1,
+            MessageType::Authentication(_) => 2,
+            MessageType::SelfID(_) => 3,
+            MessageType::System(_) => 4,
+            MessageType::OperatorID(_) => 5,
+            MessageType::Pack(_) => 6,
+        };
+
+        if let Some(last_counter) = self.message_counters[slot] {
+            let behind = last_counter.wrapping_sub(message_counter);
+
+            if behind <= Self::REORDER_WINDOW {
+                let bit = (1u32 << behind >> 1) as u16;
+
+                if self.missing_counters[slot] & bit != 0 {
+                    self.missing_counters[slot] &= !bit;
+                    self.missed_frames = self.missed_frames.saturating_sub(1);
+                }
+
+                return;
+            }
+
+            let gap = message_counter.wrapping_sub(last_counter).wrapping_sub(1);
+
+            // shifts the window forward past the new counter, marking the gap as missed.
+            let missing = self.missing_counters[slot]
+                .checked_shl(gap as u32 + 1)
+                .unwrap_or(0);
+            let gap_bits = 1u16.checked_shl(gap as u32).map_or(u16::MAX, |bit| bit - 1);
+
+            self.missing_counters[slot] = missing | gap_bits;
+            self.missed_frames = self.missed_frames.saturating_add(gap as u32);
+        }
+
+        self.message_counters[slot] = Some(message_counter);
+    }
+
+    // records a single message, packs only update the last seen time as they are unpacked first.
+    pub(crate) fn record_message(&mut self, message_type: &MessageType, time: u64) {
+        self.last_seen = time;
+
+        match *message_type {
+            MessageType::BasicID(value) => self.basic_id = Some(Received { value, time }),
+            MessageType::Location(value) => self.location = Some(Received { value, time }),
+            MessageType::Authentication(value) => {
+                let page_number = match value {
+                    Authentication::Initial(initial) => initial.page_number(),
+                    Authentication::Subsequent(subsequent) => subsequent.page_number(),
+                };
+
+                if let Some(page) = self.authentication.get_mut(page_number) {
+                    *page = Some(Received { value, time });
+                }
+            }
+            MessageType::SelfID(value) => self.self_id = Some(Received { value, time }),
+            MessageType::System(value) => self.system = Some(Received { value, time }),
+            MessageType::OperatorID(value) => self.operator_id = Some(Received { value, time }),
+            MessageType::Pack(_) => (),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! ## Receiver
+//!
+//! A single broadcast frame carries at most one [`Pack`](crate::pack::Pack), and the complete
+//! picture of an aircraft is spread across many frames of different message types. The
+//! [`Tracker`] stitches decoded [`Message`](crate::messages::Message)s back together into one
+//! [`Aircraft`] per UA.
+//!
+//! Aircraft are keyed by whatever the receiver uses to tell transmitters apart, generally the
+//! transport address (a Bluetooth or Wi-Fi MAC address), though any `Copy + PartialEq` type works.
+//!
+//! This library has no clock, so every update takes the time at which the frame was received as
+//! milliseconds since an arbitrary epoch chosen by the caller. The only requirement is that the
+//! clock does not go backwards; a monotonic clock since boot is perfectly fine.
+//!
+//! The tracker does not allocate; its capacity is a const generic parameter. When it is full, the
+//! aircraft least recently seen is evicted to make room for a new one.
+mod aircraft;
+mod tracker;
+
+pub use aircraft::Aircraft;
+pub use aircraft::Received;
+pub use tracker::Tracker;
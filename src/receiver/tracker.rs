@@ -0,0 +1,393 @@
+use crate::error::Error;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::receiver::aircraft::Aircraft;
+
+/// Aircraft Tracker
+///
+/// Aggregates decoded messages into up to `N` [`Aircraft`], keyed by `K`.
+///
+/// Aircraft not seen for longer than the timeout are removed by [`Tracker::expire`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Tracker<K, const N: usize> {
+    timeout: u64,
+    aircraft: [Option<Aircraft<K>>; N],
+}
+
+impl<K: Copy + PartialEq, const N: usize> Tracker<K, N> {
+    /// Constructs a new, empty tracker.
+    ///
+    /// `timeout` is the number of milliseconds after which an aircraft not seen is considered
+    /// stale.
+    ///
+    /// A tracker holds at least one aircraft, a capacity `N` of zero fails to compile:
+    ///
+    /// ```compile_fail
+    /// use drone_id::receiver::Tracker;
+    ///
+    /// let tracker = Tracker::<u8, 0>::new(3_000);
+    /// ```
+    pub fn new(timeout: u64) -> Self {
+        const {
+            assert!(
+                N > 0,
+                "a tracker must have a capacity of at least one aircraft"
+            )
+        };
+
+        Self {
+            timeout,
+            aircraft: [None; N],
+        }
+    }
+
+    /// Returns the timeout in milliseconds.
+    pub fn timeout(&self) -> u64 {
+        self.timeout
+    }
+
+    /// Returns the maximum number of aircraft tracked at once.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of aircraft currently tracked.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns true if no aircraft are currently tracked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the aircraft with the given key, if tracked.
+    pub fn get(&self, key: &K) -> Option<&Aircraft<K>> {
+        self.iter().find(|aircraft| aircraft.key() == key)
+    }
+
+    /// Returns an iterator over the tracked aircraft.
+    pub fn iter(&self) -> impl Iterator<Item = &Aircraft<K>> {
+        self.aircraft.iter().flatten()
+    }
+
+    /// Records a message received from the aircraft with the given key at the given time.
+    ///
+    /// `message_counter` is the transport message counter of the frame carrying the message, if the
+    /// transport provides one. [`Pack`](crate::pack::Pack) messages are unpacked and each message
+    /// inside of them is recorded.
+    ///
+    /// If the aircraft is not yet tracked and the tracker is full, the aircraft least recently seen
+    /// is evicted.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `message` is a pack containing a message which fails to be decoded.
+    pub fn update(
+        &mut self,
+        key: K,
+        message_counter: Option<u8>,
+        message: &Message,
+        time: u64,
+    ) -> Result<&Aircraft<K>, Error> {
+        // unpack before touching any state so a malformed pack is not partially recorded.
+        let packed_messages = match message.message_type() {
+            MessageType::Pack(pack) => Some(pack.try_decode_all()?),
+            _ => None,
+        };
+
+        // INVARIANT: the tracker has a capacity of at least one, so a slot is always found.
+        let aircraft = self.entry(key, time).ok_or(Error::Unreachable).unwrap();
+
+        if let Some(message_counter) = message_counter {
+            aircraft.record_message_counter(message, message_counter);
+        }
+
+        if let Some(packed_messages) = packed_messages {
+            for packed_message in packed_messages {
+                aircraft.record_message(packed_message.message_type(), time);
+            }
+        }
+
+        // a pack only updates the last seen time, so an empty pack still shows the aircraft is
+        // present.
+        aircraft.record_message(message.message_type(), time);
+
+        Ok(aircraft)
+    }
+
+    /// Removes every aircraft not seen for longer than the timeout as of the given time.
+    ///
+    /// Returns the number of aircraft removed.
+    pub fn expire(&mut self, time: u64) -> usize {
+        let mut removed = 0;
+
+        for slot in self.aircraft.iter_mut() {
+            let is_stale = slot
+                .as_ref()
+                .is_some_and(|aircraft| time.saturating_sub(aircraft.last_seen()) > self.timeout);
+
+            if is_stale {
+                *slot = None;
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Removes the aircraft with the given key, returning it if it was tracked.
+    pub fn remove(&mut self, key: &K) -> Option<Aircraft<K>> {
+        self.aircraft
+            .iter_mut()
+            .find(|slot| slot.as_ref().is_some_and(|aircraft| aircraft.key() == key))?
+            .take()
+    }
+
+    // finds the aircraft with the given key, or makes room for it in an empty or evicted slot.
+    fn entry(&mut self, key: K, time: u64) -> Option<&mut Aircraft<K>> {
+        let existing = self
+            .aircraft
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|aircraft| aircraft.key() == &key));
+
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                let index = self.aircraft.iter().position(Option::is_none).or_else(|| {
+                    self.aircraft
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|(_, slot)| slot.as_ref().map(Aircraft::last_seen))
+                        .map(|(index, _)| index)
+                })?;
+
+                self.aircraft[index] = Some(Aircraft::new(key, time));
+
+                index
+            }
+        };
+
+        self.aircraft[index].as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        authentication::{Authentication, AuthenticationType, Subsequent},
+        basic_id::{BasicID, UASID, UAType},
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        receiver::Tracker,
+        self_id::{DescriptionType, SelfID},
+    };
+
+    fn basic_id() -> Message {
+        Message::from(BasicID::new(UAType::Helicopter, UASID::None))
+    }
+
+    fn operator_id() -> Message {
+        Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]))
+    }
+
+    #[test]
+    fn test_update_new_aircraft() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        assert!(tracker.is_empty());
+
+        let aircraft = tracker.update(1, Some(0), &basic_id(), 100).unwrap();
+
+        assert_eq!(aircraft.key(), &1);
+        assert_eq!(aircraft.first_seen(), 100);
+        assert_eq!(aircraft.last_seen(), 100);
+        assert_eq!(
+            aircraft.basic_id().map(|received| received.value()),
+            Some(&BasicID::new(UAType::Helicopter, UASID::None))
+        );
+        assert_eq!(
+            aircraft.basic_id().map(|received| received.time()),
+            Some(100)
+        );
+        assert_eq!(aircraft.operator_id(), None);
+        assert_eq!(aircraft.missed_frames(), 0);
+
+        assert_eq!(tracker.len(), 1);
+        assert_eq!(tracker.capacity(), 4);
+        assert_eq!(tracker.timeout(), 3_000);
+    }
+
+    #[test]
+    fn test_update_keeps_latest() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        let first = SelfID::new(DescriptionType::Text, [b'a'; 23]);
+        let second = SelfID::new(DescriptionType::Text, [b'b'; 23]);
+
+        tracker.update(1, None, &Message::from(first), 100).unwrap();
+        tracker.update(1, None, &operator_id(), 150).unwrap();
+        tracker
+            .update(1, None, &Message::from(second), 200)
+            .unwrap();
+
+        let aircraft = tracker.get(&1).unwrap();
+
+        assert_eq!(
+            aircraft.self_id().map(|received| *received.value()),
+            Some(second)
+        );
+        assert_eq!(
+            aircraft.self_id().map(|received| received.time()),
+            Some(200)
+        );
+        assert_eq!(
+            aircraft.operator_id().map(|received| received.time()),
+            Some(150)
+        );
+        assert_eq!(aircraft.first_seen(), 100);
+        assert_eq!(aircraft.last_seen(), 200);
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn test_update_separates_keys() {
+        let mut tracker = Tracker::<[u8; 6], 4>::new(3_000);
+
+        tracker.update([1; 6], None, &basic_id(), 100).unwrap();
+        tracker.update([2; 6], None, &operator_id(), 100).unwrap();
+
+        assert!(tracker.get(&[1; 6]).unwrap().operator_id().is_none());
+        assert!(tracker.get(&[2; 6]).unwrap().basic_id().is_none());
+        assert!(tracker.get(&[3; 6]).is_none());
+        assert_eq!(tracker.len(), 2);
+    }
+
+    #[test]
+    fn test_update_pack() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        let pack = Message::from(Pack::try_from([basic_id(), operator_id()]).unwrap());
+
+        let aircraft = tracker.update(1, Some(0), &pack, 100).unwrap();
+
+        assert!(aircraft.basic_id().is_some());
+        assert!(aircraft.operator_id().is_some());
+        assert!(aircraft.location().is_none());
+    }
+
+    #[test]
+    fn test_update_authentication_pages() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        let page = Subsequent::try_new(AuthenticationType::UASIDSignature, 3, [1u8; 23]).unwrap();
+
+        let aircraft = tracker
+            .update(
+                1,
+                None,
+                &Message::from(Authentication::Subsequent(page)),
+                100,
+            )
+            .unwrap();
+
+        assert_eq!(
+            aircraft
+                .authentication_page(3)
+                .map(|received| *received.value()),
+            Some(Authentication::Subsequent(page))
+        );
+        assert!(aircraft.authentication_page(0).is_none());
+        assert!(aircraft.authentication_page(16).is_none());
+    }
+
+    #[test]
+    fn test_update_counts_missed_frames() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        tracker.update(1, Some(254), &basic_id(), 100).unwrap();
+        // same frame again on another channel.
+        tracker.update(1, Some(254), &basic_id(), 101).unwrap();
+        // counters are tracked per message type.
+        tracker.update(1, Some(10), &operator_id(), 102).unwrap();
+        // wraps around, missing 255 and 0.
+        tracker.update(1, Some(1), &basic_id(), 103).unwrap();
+        // misses 11 through 14.
+        let aircraft = tracker.update(1, Some(15), &operator_id(), 104).unwrap();
+
+        assert_eq!(aircraft.missed_frames(), 6);
+    }
+
+    #[test]
+    fn test_update_ignores_reordered_frames() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        tracker.update(1, Some(10), &basic_id(), 100).unwrap();
+        // misses 11.
+        tracker.update(1, Some(12), &basic_id(), 101).unwrap();
+        // 11 arrives late and is no longer missed, then again on another channel.
+        tracker.update(1, Some(11), &basic_id(), 102).unwrap();
+        tracker.update(1, Some(11), &basic_id(), 102).unwrap();
+        tracker.update(1, Some(13), &basic_id(), 103).unwrap();
+        // wraps around, missing 251 through 1.
+        tracker.update(1, Some(250), &operator_id(), 104).unwrap();
+        tracker.update(1, Some(2), &operator_id(), 105).unwrap();
+        // 250 again, a late frame across the wrap around rather than 247 missed frames.
+        let aircraft = tracker.update(1, Some(250), &operator_id(), 106).unwrap();
+
+        assert_eq!(aircraft.missed_frames(), 7);
+    }
+
+    #[test]
+    fn test_update_recovers_late_frames_across_wrap_around() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        // misses 240 through 255, then receives the oldest and newest of them late.
+        tracker.update(1, Some(239), &operator_id(), 100).unwrap();
+        tracker.update(1, Some(0), &operator_id(), 101).unwrap();
+        tracker.update(1, Some(240), &operator_id(), 102).unwrap();
+        let aircraft = tracker.update(1, Some(255), &operator_id(), 103).unwrap();
+
+        assert_eq!(aircraft.missed_frames(), 14);
+    }
+
+    #[test]
+    fn test_update_evicts_least_recently_seen() {
+        let mut tracker = Tracker::<u8, 2>::new(3_000);
+
+        tracker.update(1, None, &basic_id(), 100).unwrap();
+        tracker.update(2, None, &basic_id(), 50).unwrap();
+        tracker.update(3, None, &basic_id(), 150).unwrap();
+
+        assert!(tracker.get(&1).is_some());
+        assert!(tracker.get(&2).is_none());
+        assert!(tracker.get(&3).is_some());
+        assert_eq!(tracker.len(), 2);
+    }
+
+    #[test]
+    fn test_expire() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        tracker.update(1, None, &basic_id(), 1_000).unwrap();
+        tracker.update(2, None, &basic_id(), 2_000).unwrap();
+
+        assert_eq!(tracker.expire(4_000), 0);
+        assert_eq!(tracker.expire(4_500), 1);
+
+        assert!(tracker.get(&1).is_none());
+        assert!(tracker.get(&2).is_some());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tracker = Tracker::<u8, 4>::new(3_000);
+
+        tracker.update(1, None, &basic_id(), 100).unwrap();
+
+        assert_eq!(tracker.remove(&1).map(|aircraft| *aircraft.key()), Some(1));
+        assert_eq!(tracker.remove(&1), None);
+        assert!(tracker.is_empty());
+    }
+}
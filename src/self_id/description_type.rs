@@ -4,6 +4,7 @@ use crate::error::Error;
 ///
 /// Reserved values are `3` to `200`, private use values are `201` to `255`.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DescriptionType {
     /// Free-text ASCII.
     Text,
@@ -13,10 +14,10 @@ pub enum DescriptionType {
     ///
     /// Included in the specification, but no details about it.
     ExtendedStatus,
-    /// Reserved.
-    Reserved,
-    /// Available for private use.
-    PrivateUse,
+    /// Reserved, codes 3 to 200.
+    Reserved(u8),
+    /// Available for private use, codes 201 to 255.
+    PrivateUse(u8),
 }
 
 impl DescriptionType {
@@ -31,17 +32,18 @@ impl DescriptionType {
 impl From<u8> for DescriptionType {
     fn from(value: u8) -> Self {
         if value >= Self::PRIVATE_USE_THRESHOLD {
-            return Self::PrivateUse;
+            return Self::PrivateUse(value);
         }
 
         if value >= Self::RESERVED_THRESHOLD {
-            return Self::Reserved;
+            return Self::Reserved(value);
         }
 
         match value {
             0 => Self::Text,
             1 => Self::Emergency,
             2 => Self::ExtendedStatus,
+            #[allow(clippy::unnecessary_literal_unwrap)]
             _ => Err(Error::Unreachable).unwrap(),
         }
     }
@@ -53,8 +55,11 @@ impl From<DescriptionType> for u8 {
             DescriptionType::Text => 0,
             DescriptionType::Emergency => 1,
             DescriptionType::ExtendedStatus => 2,
-            DescriptionType::Reserved => DescriptionType::RESERVED_THRESHOLD,
-            DescriptionType::PrivateUse => DescriptionType::PRIVATE_USE_THRESHOLD,
+            DescriptionType::Reserved(n) => n.clamp(
+                DescriptionType::RESERVED_THRESHOLD,
+                DescriptionType::PRIVATE_USE_THRESHOLD - 1,
+            ),
+            DescriptionType::PrivateUse(n) => n.max(DescriptionType::PRIVATE_USE_THRESHOLD),
         }
     }
 }
@@ -72,14 +77,19 @@ mod tests {
 
     #[test]
     fn test_encode_reserved() {
-        let reserved = DescriptionType::Reserved;
+        let reserved = DescriptionType::Reserved(DescriptionType::RESERVED_THRESHOLD);
 
         assert_eq!(u8::from(reserved), DescriptionType::RESERVED_THRESHOLD);
+        assert_eq!(
+            u8::from(DescriptionType::Reserved(0)),
+            DescriptionType::RESERVED_THRESHOLD
+        );
+        assert_eq!(u8::from(DescriptionType::Reserved(0xff)), 200);
     }
 
     #[test]
     fn test_encode_private_use() {
-        let private_use = DescriptionType::PrivateUse;
+        let private_use = DescriptionType::PrivateUse(DescriptionType::PRIVATE_USE_THRESHOLD);
 
         assert_eq!(
             u8::from(private_use),
@@ -98,13 +108,28 @@ mod tests {
     fn test_decode_reserved() {
         let decoded = DescriptionType::from(DescriptionType::RESERVED_THRESHOLD);
 
-        assert_eq!(decoded, DescriptionType::Reserved);
+        assert_eq!(
+            decoded,
+            DescriptionType::Reserved(DescriptionType::RESERVED_THRESHOLD)
+        );
     }
 
     #[test]
     fn test_decode_private_use() {
         let decoded = DescriptionType::from(DescriptionType::PRIVATE_USE_THRESHOLD);
 
-        assert_eq!(decoded, DescriptionType::PrivateUse);
+        assert_eq!(
+            decoded,
+            DescriptionType::PrivateUse(DescriptionType::PRIVATE_USE_THRESHOLD)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_is_lossless() {
+        for code in 0..=u8::MAX {
+            assert_eq!(u8::from(DescriptionType::from(code)), code);
+        }
+
+        assert_eq!(DescriptionType::from(230), DescriptionType::PrivateUse(230));
     }
 }
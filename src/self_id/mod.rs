@@ -22,8 +22,10 @@ use crate::try_serialize::TrySerialize;
 /// Description is a free-form ASCII text field, this can be any description of operations limited
 /// to 23 characters.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelfID {
     description_type: DescriptionType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_support::ascii"))]
     description: [u8; 23],
 }
 
@@ -159,4 +161,20 @@ mod tests {
         assert!(SelfID::try_from(too_short.as_ref()).is_err());
         assert!(SelfID::try_from(too_long.as_ref()).is_err());
     }
+
+    #[test]
+    fn test_decode_encode_private_use() {
+        let mut encoded = [0u8; 24];
+        encoded[0] = 230;
+        encoded[1..].clone_from_slice(&TEXT);
+
+        let self_id = SelfID::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(self_id.description_type(), DescriptionType::PrivateUse(230));
+
+        let mut reencoded = [0u8; 24];
+        self_id.try_serialize(&mut reencoded).unwrap();
+
+        assert_eq!(reencoded, encoded);
+    }
 }
@@ -0,0 +1,372 @@
+//! ## Serde Support
+//!
+//! Helpers shared by the [`serde`] implementations of the data types, enabled by the `serde`
+//! feature.
+//!
+//! Fixed length byte fields are written as strings so the JSON stays readable. Identifiers meant to
+//! be text, such as the [`SerialNumber`](crate::basic_id::SerialNumber), are written as their
+//! ASCII characters without the trailing null padding. Identifiers which are binary, such as the
+//! [`UTMAssignedUUID`](crate::basic_id::UTMAssignedUUID), and text fields which do not hold
+//! printable ASCII are written as `0x` prefixed lowercase hex. The hex form is always longer than
+//! the field can hold as ASCII, so the two never collide.
+//!
+//! None of the helpers allocate, the core library stays `#![no_std]`.
+use core::fmt;
+
+use serde::Deserializer;
+use serde::Serializer;
+use serde::de;
+use serde::de::Visitor;
+
+use crate::error::Error;
+
+// fixed capacity buffer to format strings into without an allocator.
+struct StrBuffer<const N: usize> {
+    bytes: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> StrBuffer<N> {
+    fn new() -> Self {
+        Self {
+            bytes: [0u8; N],
+            length: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // INVARIANT: only `&str` values are written into the buffer.
+        str::from_utf8(&self.bytes[..self.length])
+            .map_err(|_| Error::Unreachable)
+            .unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Write for StrBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.length + s.len();
+
+        if end > N {
+            return Err(fmt::Error);
+        }
+
+        self.bytes[self.length..end].clone_from_slice(s.as_bytes());
+        self.length = end;
+
+        Ok(())
+    }
+}
+
+// writes `bytes` as `0x` prefixed lowercase hex.
+fn serialize_hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    use fmt::Write;
+
+    // `0x` followed by two characters per byte, enough for the largest byte field.
+    let mut buffer = StrBuffer::<{ 2 + 2 * 32 }>::new();
+
+    buffer
+        .write_str("0x")
+        .map_err(|_| serde::ser::Error::custom("byte field too long"))?;
+
+    for byte in bytes {
+        write!(buffer, "{:02x}", byte)
+            .map_err(|_| serde::ser::Error::custom("byte field too long"))?;
+    }
+
+    serializer.serialize_str(buffer.as_str())
+}
+
+// parses `0x` prefixed hex of exactly `N` bytes.
+fn parse_hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let digits = value.strip_prefix("0x")?.as_bytes();
+
+    if digits.len() != 2 * N {
+        return None;
+    }
+
+    let mut bytes = [0u8; N];
+
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        let pair = str::from_utf8(pair).ok()?;
+
+        *byte = u8::from_str_radix(pair, 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+// visits a string into a byte field of `N` bytes, either as `0x` prefixed hex or, if `ascii`, as
+// ASCII characters padded with nulls.
+struct BytesVisitor<const N: usize> {
+    ascii: bool,
+}
+
+impl<const N: usize> Visitor<'_> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.ascii {
+            true => write!(formatter, "up to {} ASCII characters or {} hex bytes", N, N),
+            false => write!(formatter, "{} hex bytes", N),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        if let Some(bytes) = parse_hex(value) {
+            return Ok(bytes);
+        }
+
+        if !self.ascii || value.len() > N || !value.is_ascii() {
+            return Err(E::invalid_value(de::Unexpected::Str(value), &self));
+        }
+
+        let mut bytes = [0u8; N];
+        bytes[..value.len()].clone_from_slice(value.as_bytes());
+
+        Ok(bytes)
+    }
+}
+
+/// Fixed length byte field written as ASCII text, falling back to hex.
+pub(crate) mod ascii {
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    use crate::serde_support::BytesVisitor;
+    use crate::serde_support::serialize_hex;
+
+    pub(crate) fn serialize<const N: usize, S: Serializer>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let end = bytes
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |index| index + 1);
+
+        let text = &bytes[..end];
+
+        if !text
+            .iter()
+            .all(|byte| byte.is_ascii_graphic() || *byte == b' ')
+        {
+            return serialize_hex(bytes, serializer);
+        }
+
+        // INVARIANT: printable ASCII is valid utf8.
+        serializer.serialize_str(
+            str::from_utf8(text)
+                .map_err(|_| crate::error::Error::Unreachable)
+                .unwrap(),
+        )
+    }
+
+    pub(crate) fn deserialize<'de, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        deserializer.deserialize_str(BytesVisitor::<N> { ascii: true })
+    }
+}
+
+/// Fixed length byte field written as `0x` prefixed hex.
+pub(crate) mod hex {
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    use crate::serde_support::BytesVisitor;
+    use crate::serde_support::serialize_hex;
+
+    pub(crate) fn serialize<const N: usize, S: Serializer>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_hex(bytes, serializer)
+    }
+
+    pub(crate) fn deserialize<'de, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        deserializer.deserialize_str(BytesVisitor::<N> { ascii: false })
+    }
+}
+
+/// Page index, at most 15.
+pub(crate) fn deserialize_page_index<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<usize, D::Error> {
+    let value: usize = serde::Deserialize::deserialize(deserializer)?;
+
+    if value > 15 {
+        return Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(value as u64),
+            &"a page index of at most 15",
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Byte length, at most 255.
+pub(crate) fn deserialize_byte_length<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<usize, D::Error> {
+    let value: u8 = serde::Deserialize::deserialize(deserializer)?;
+
+    Ok(value as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::String;
+
+    use crate::{
+        authentication::{Authentication, AuthenticationType, Subsequent},
+        basic_id::{BasicID, RegistrationID, SerialNumber, UASID, UAType, UTMAssignedUUID},
+        fixtures::operator_id_message,
+        location::Latitude,
+        messages::{Message, ProtocolVersion},
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        system::AreaCount,
+    };
+
+    fn serial_number_message() -> Message {
+        Message::from(BasicID::new(
+            UAType::Helicopter,
+            UASID::SerialNumber(SerialNumber::try_new("ABCD", "1234").unwrap()),
+        ))
+    }
+
+    #[test]
+    fn test_serialize_tagged() {
+        assert_eq!(
+            serde_json::to_string(&Latitude::Known(37.5)).unwrap(),
+            r#"{"Known":37.5}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Latitude::Unknown).unwrap(),
+            r#""Unknown""#
+        );
+    }
+
+    #[test]
+    fn test_serialize_ascii() {
+        assert_eq!(
+            serde_json::to_string(&serial_number_message()).unwrap(),
+            r#"{"protocol_version":"Version2","message_type":{"BasicID":{"ua_type":"Helicopter","uas_id":{"SerialNumber":"ABCD41234"}}}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&RegistrationID::try_new("N", "123ABC").unwrap()).unwrap(),
+            r#""N.123ABC""#
+        );
+    }
+
+    #[test]
+    fn test_serialize_hex() {
+        let mut uuid = [0u8; 20];
+        uuid[0] = 0xab;
+        uuid[19] = 0x01;
+
+        assert_eq!(
+            serde_json::to_string(&UTMAssignedUUID::new(uuid)).unwrap(),
+            r#""0xab00000000000000000000000000000000000001""#
+        );
+    }
+
+    #[test]
+    fn test_serialize_ascii_falls_back_to_hex() {
+        let mut id = [0u8; 20];
+        id[0] = 0xff;
+
+        let operator_id = OperatorID::new(OperatorIDType::OperatorID, id);
+
+        let json = serde_json::to_string(&operator_id).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"operator_id_type":"OperatorID","id":"0xff00000000000000000000000000000000000000"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<OperatorID>(&json).unwrap(),
+            operator_id
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for message in [serial_number_message(), operator_id_message()] {
+            let json = serde_json::to_string(&message).unwrap();
+
+            assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_pack() {
+        let message = Message::from(
+            Pack::try_from([serial_number_message(), operator_id_message()]).unwrap(),
+        );
+
+        let json = serde_json::to_string(&message).unwrap();
+
+        assert!(json.contains(r#"{"Pack":[{"protocol_version""#));
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+    }
+
+    #[test]
+    fn test_deserialize_defaults_protocol_version() {
+        let message: Message = serde_json::from_str(
+            r#"{"message_type":{"OperatorID":{"operator_id_type":"OperatorID","id":"FIN-OP"}}}"#,
+        )
+        .unwrap();
+
+        let mut id = [0u8; 20];
+        id[..6].clone_from_slice(b"FIN-OP");
+
+        assert_eq!(message.version(), ProtocolVersion::Version2);
+        assert_eq!(
+            message,
+            Message::from(OperatorID::new(OperatorIDType::OperatorID, id))
+        );
+    }
+
+    #[test]
+    fn test_deserialize_fails_invalid() {
+        // serial number characters exclude "O".
+        assert!(serde_json::from_str::<SerialNumber>(r#""ABCD4OOOO""#).is_err());
+        // longer than the 20 byte field.
+        assert!(serde_json::from_str::<SerialNumber>(r#""ABCDF1234567890123456""#).is_err());
+        assert!(serde_json::from_str::<AreaCount>("65001").is_err());
+
+        let subsequent = Authentication::Subsequent(
+            Subsequent::try_new(AuthenticationType::UASIDSignature, 1, [0u8; 23]).unwrap(),
+        );
+
+        let json = serde_json::to_string(&subsequent)
+            .unwrap()
+            .replace(r#""page_number":1"#, r#""page_number":16"#);
+
+        assert!(serde_json::from_str::<Authentication>(&json).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_pack_fails_too_many_messages() {
+        let message = serde_json::to_string(&operator_id_message()).unwrap();
+
+        let mut json = String::from(r#"{"message_type":{"Pack":["#);
+
+        for index in 0..10 {
+            if index != 0 {
+                json.push(',');
+            }
+
+            json.push_str(&message);
+        }
+
+        json.push_str("]}}");
+
+        assert!(serde_json::from_str::<Message>(&json).is_err());
+    }
+}
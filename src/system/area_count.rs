@@ -5,6 +5,8 @@ use crate::error::Error;
 /// Possible values go up to 65,000 despite the maximum value of a 16 bit unsigned integer being
 /// slightly larger than this.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
 pub struct AreaCount(u16);
 
 impl AreaCount {
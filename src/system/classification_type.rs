@@ -4,13 +4,14 @@ use crate::error::Error;
 ///
 /// Determines the classification type for a given region.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClassificationType {
     /// Undeclared.
     Undeclared,
     /// European Union-specific classification.
     EuropeanUnion,
-    /// Reserved.
-    Reserved,
+    /// Reserved, codes 2 to 7.
+    Reserved(u8),
 }
 
 impl ClassificationType {
@@ -32,14 +33,21 @@ impl TryFrom<u8> for ClassificationType {
         match value {
             0 => Ok(Self::Undeclared),
             1 => Ok(Self::EuropeanUnion),
-            _ => Ok(Self::Reserved),
+            n => Ok(Self::Reserved(n)),
         }
     }
 }
 
 impl From<ClassificationType> for u8 {
     fn from(value: ClassificationType) -> Self {
-        value as u8
+        match value {
+            ClassificationType::Undeclared => 0,
+            ClassificationType::EuropeanUnion => 1,
+            ClassificationType::Reserved(n) => n.clamp(
+                ClassificationType::RESERVED_THRESHOLD,
+                ClassificationType::MAX,
+            ),
+        }
     }
 }
 
@@ -56,9 +64,11 @@ mod tests {
 
     #[test]
     fn test_encode_reserved() {
-        let reserved = ClassificationType::Reserved;
+        let reserved = ClassificationType::Reserved(2);
 
         assert_eq!(u8::from(reserved), 2);
+        assert_eq!(u8::from(ClassificationType::Reserved(0)), 2);
+        assert_eq!(u8::from(ClassificationType::Reserved(0xff)), 7);
     }
 
     #[test]
@@ -68,6 +78,16 @@ mod tests {
         assert_eq!(decoded, ClassificationType::EuropeanUnion);
     }
 
+    #[test]
+    fn test_encode_decode_reserved_is_lossless() {
+        for code in ClassificationType::RESERVED_THRESHOLD..=ClassificationType::MAX {
+            let decoded = ClassificationType::try_from(code).unwrap();
+
+            assert_eq!(decoded, ClassificationType::Reserved(code));
+            assert_eq!(u8::from(decoded), code);
+        }
+    }
+
     #[test]
     fn test_decode_fails_invalid_integer() {
         assert!(ClassificationType::try_from(ClassificationType::MAX + 1).is_err());
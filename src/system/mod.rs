@@ -25,11 +25,18 @@
 //!
 //! The altitudes of the operator, operating area floor, and operating area ceiling are geodetic,
 //! that is, they are the height above the WGS-84 ellipsoid.
+//!
+//! ### Building
+//!
+//! [`System::builder`] sets each field by name, rather than by its position among the three
+//! altitudes and other arguments of [`System::new`]. The builder checks the classification and
+//! operating area fields agree with one another.
 
 mod area_count;
 mod classification_type;
 mod operating_area_radius;
 mod operator_location_source_type;
+mod system_builder;
 mod timestamp;
 mod ua_classification;
 
@@ -37,14 +44,21 @@ pub use area_count::AreaCount;
 pub use classification_type::ClassificationType;
 pub use operating_area_radius::OperatingAreaRadius;
 pub use operator_location_source_type::OperatorLocationSourceType;
+pub use system_builder::SystemBuilder;
 pub use timestamp::Timestamp;
 pub use ua_classification::OpenClassification;
 pub use ua_classification::UAClassification;
 
 use crate::error::Error;
+#[cfg(feature = "geo")]
+use crate::geo::OperatingArea;
+#[cfg(feature = "geo")]
+use crate::geo::Position;
 use crate::location::Altitude;
 use crate::location::Latitude;
 use crate::location::Longitude;
+use crate::messages::ProtocolVersion;
+use crate::quantize::Quantize;
 use crate::try_serialize::TrySerialize;
 
 /// System Message
@@ -53,6 +67,7 @@ use crate::try_serialize::TrySerialize;
 /// area parameters such as the radius, ceiling, floor, and number of aircraft operating in the
 /// area.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct System {
     classification_type: ClassificationType,
     operator_location_source_type: OperatorLocationSourceType,
@@ -68,7 +83,11 @@ pub struct System {
 }
 
 impl System {
+    /// Message code for system messages is `0x04`.
+    pub const SYSTEM_MESSAGE_CODE: u8 = 0x04;
+
     /// Constructs a new System message.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         classification_type: ClassificationType,
         operator_location_source_type: OperatorLocationSourceType,
@@ -97,6 +116,11 @@ impl System {
         }
     }
 
+    /// Returns a builder which names each field, see [`SystemBuilder`].
+    pub fn builder() -> SystemBuilder {
+        SystemBuilder::new()
+    }
+
     /// Returns the classification type.
     ///
     /// See the [classification disambiguation](crate::system) for more.
@@ -155,6 +179,54 @@ impl System {
     pub fn timestamp(&self) -> Timestamp {
         self.timestamp
     }
+
+    /// Returns a copy with each field quantized, see [`Quantize`].
+    pub fn normalized(&self) -> Self {
+        Self {
+            operator_latitude: self.operator_latitude.quantize(),
+            operator_longitude: self.operator_longitude.quantize(),
+            area_radius: self.area_radius.quantize(),
+            area_ceiling: self.area_ceiling.quantize(),
+            area_floor: self.area_floor.quantize(),
+            operator_altitude: self.operator_altitude.quantize(),
+            ..*self
+        }
+    }
+
+    // clears the fields `protocol_version` reserves, as they decode.
+    pub(crate) fn conformed(&self, protocol_version: ProtocolVersion) -> Self {
+        match protocol_version.has_classification() {
+            true => *self,
+            false => Self {
+                classification_type: ClassificationType::Undeclared,
+                ua_classification: UAClassification::Undefined,
+                ..*self
+            },
+        }
+    }
+}
+
+#[cfg(feature = "geo")]
+impl System {
+    /// Returns the position of the operator, at its geodetic altitude.
+    pub fn operator_position(&self) -> Position {
+        Position::new(
+            self.operator_latitude,
+            self.operator_longitude,
+            self.operator_altitude,
+        )
+    }
+
+    /// Returns the operating area, centered on the operator position.
+    pub fn operating_area(&self) -> OperatingArea {
+        OperatingArea::new(
+            self.operator_position(),
+            self.area_count,
+            self.area_radius,
+            self.area_ceiling,
+            self.area_floor,
+        )
+    }
 }
 
 impl TryFrom<&[u8]> for System {
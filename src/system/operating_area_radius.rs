@@ -1,9 +1,12 @@
 use crate::error::Error;
+use crate::quantize::Quantize;
 
 /// Operating Area Radius
 ///
 /// Contains the area, in meters, of the radius of the operating area.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
 pub struct OperatingAreaRadius(u16);
 
 impl OperatingAreaRadius {
@@ -32,6 +35,20 @@ impl OperatingAreaRadius {
     }
 }
 
+impl TryFrom<u16> for OperatingAreaRadius {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<OperatingAreaRadius> for u16 {
+    fn from(value: OperatingAreaRadius) -> Self {
+        value.0
+    }
+}
+
 impl From<u8> for OperatingAreaRadius {
     fn from(value: u8) -> Self {
         Self(value as u16 * Self::MULTIPLIER)
@@ -44,8 +61,19 @@ impl From<OperatingAreaRadius> for u8 {
     }
 }
 
+impl Quantize for OperatingAreaRadius {
+    fn quantize(&self) -> Self {
+        u8::from(*self).into()
+    }
+
+    fn known_value(&self) -> Option<f64> {
+        Some(self.0 as f64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::quantize::Quantize;
     use crate::system::OperatingAreaRadius;
 
     #[test]
@@ -73,4 +101,13 @@ mod tests {
 
         assert_eq!(decoded, OperatingAreaRadius::try_new(10).unwrap());
     }
+
+    #[test]
+    fn test_quantize() {
+        let radius = OperatingAreaRadius::try_new(15).unwrap();
+
+        assert_eq!(radius.quantize(), OperatingAreaRadius::try_new(10).unwrap());
+        assert_eq!(radius.quantization_error(), Some(5.0));
+        assert!(OperatingAreaRadius::try_new(2_550).unwrap().is_lossless());
+    }
 }
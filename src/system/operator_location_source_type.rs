@@ -5,6 +5,7 @@ use crate::error::Error;
 /// Represents the type of operator location that is transmitted; it may represent the take-off
 /// location of the aircraft, as well as a different location which can be fixed or dynamic.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OperatorLocationSourceType {
     /// Take-off location.
     TakeOff,
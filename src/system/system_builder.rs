@@ -0,0 +1,288 @@
+use crate::error::Error;
+use crate::location;
+use crate::location::Altitude;
+use crate::location::Latitude;
+use crate::location::Longitude;
+use crate::system::AreaCount;
+use crate::system::ClassificationType;
+use crate::system::OperatingAreaRadius;
+use crate::system::OperatorLocationSourceType;
+use crate::system::System;
+use crate::system::Timestamp;
+use crate::system::UAClassification;
+
+/// System Builder
+///
+/// Builds a [`System`] through named setters, see [`System::builder`]. Fields which are not set
+/// default to their unknown or undeclared value, a single aircraft, and a zero radius.
+///
+/// The timestamp has no unknown value, so it must always be set.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SystemBuilder {
+    classification_type: ClassificationType,
+    operator_location_source_type: OperatorLocationSourceType,
+    operator_latitude: Latitude,
+    operator_longitude: Longitude,
+    area_count: AreaCount,
+    area_radius: OperatingAreaRadius,
+    area_ceiling: Altitude,
+    area_floor: Altitude,
+    ua_classification: UAClassification,
+    operator_altitude: Altitude,
+    timestamp: Option<Timestamp>,
+}
+
+impl SystemBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            classification_type: ClassificationType::Undeclared,
+            operator_location_source_type: OperatorLocationSourceType::TakeOff,
+            operator_latitude: Latitude::Unknown,
+            operator_longitude: Longitude::Unknown,
+            // INVARIANT: one is within the area count range.
+            area_count: AreaCount::try_from(1)
+                .map_err(|_| Error::Unreachable)
+                .unwrap(),
+            area_radius: OperatingAreaRadius::from(0),
+            area_ceiling: Altitude::Unknown,
+            area_floor: Altitude::Unknown,
+            ua_classification: UAClassification::Undefined,
+            operator_altitude: Altitude::Unknown,
+            timestamp: None,
+        }
+    }
+
+    /// Sets the classification type.
+    pub fn classification_type(mut self, classification_type: ClassificationType) -> Self {
+        self.classification_type = classification_type;
+
+        self
+    }
+
+    /// Sets the operator location's source type.
+    pub fn operator_location_source_type(
+        mut self,
+        operator_location_source_type: OperatorLocationSourceType,
+    ) -> Self {
+        self.operator_location_source_type = operator_location_source_type;
+
+        self
+    }
+
+    /// Sets the operator's latitude.
+    pub fn operator_latitude(mut self, operator_latitude: Latitude) -> Self {
+        self.operator_latitude = operator_latitude;
+
+        self
+    }
+
+    /// Sets the operator's longitude.
+    pub fn operator_longitude(mut self, operator_longitude: Longitude) -> Self {
+        self.operator_longitude = operator_longitude;
+
+        self
+    }
+
+    /// Sets the number of UAS's in the operating area.
+    pub fn area_count(mut self, area_count: AreaCount) -> Self {
+        self.area_count = area_count;
+
+        self
+    }
+
+    /// Sets the radius of the operating area.
+    pub fn area_radius(mut self, area_radius: OperatingAreaRadius) -> Self {
+        self.area_radius = area_radius;
+
+        self
+    }
+
+    /// Sets the ceiling altitude of the operating area.
+    pub fn area_ceiling(mut self, area_ceiling: Altitude) -> Self {
+        self.area_ceiling = area_ceiling;
+
+        self
+    }
+
+    /// Sets the floor altitude of the operating area.
+    pub fn area_floor(mut self, area_floor: Altitude) -> Self {
+        self.area_floor = area_floor;
+
+        self
+    }
+
+    /// Sets the UA classification.
+    ///
+    /// Anything but [`UAClassification::Undefined`] requires the
+    /// [`ClassificationType::EuropeanUnion`] classification type.
+    pub fn ua_classification(mut self, ua_classification: UAClassification) -> Self {
+        self.ua_classification = ua_classification;
+
+        self
+    }
+
+    /// Sets the operator's altitude.
+    pub fn operator_altitude(mut self, operator_altitude: Altitude) -> Self {
+        self.operator_altitude = operator_altitude;
+
+        self
+    }
+
+    /// Sets the timestamp.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = Some(timestamp);
+
+        self
+    }
+
+    /// Builds the system message.
+    ///
+    /// Returns an error if:
+    ///
+    /// - the timestamp was not set.
+    /// - the UA classification is defined but the classification type is not European Union.
+    /// - only one of the operator latitude and longitude is known.
+    /// - the area floor is above the area ceiling.
+    /// - the operator latitude or longitude is known but out of range.
+    pub fn build(self) -> Result<System, Error> {
+        let timestamp = self.timestamp.ok_or(Error::MissingField)?;
+
+        match (self.classification_type, self.ua_classification) {
+            (ClassificationType::EuropeanUnion, _) | (_, UAClassification::Undefined) => (),
+            _ => return Err(Error::InconsistentFields),
+        }
+
+        location::check_coordinates(self.operator_latitude, self.operator_longitude)?;
+
+        let floor_above_ceiling = matches!(
+            (self.area_floor, self.area_ceiling),
+            (Altitude::Known(floor), Altitude::Known(ceiling)) if floor > ceiling
+        );
+
+        if floor_above_ceiling {
+            return Err(Error::InconsistentFields);
+        }
+
+        Ok(System::new(
+            self.classification_type,
+            self.operator_location_source_type,
+            self.operator_latitude,
+            self.operator_longitude,
+            self.area_count,
+            self.area_radius,
+            self.area_ceiling,
+            self.area_floor,
+            self.ua_classification,
+            self.operator_altitude,
+            timestamp,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        location::{Altitude, Latitude, Longitude},
+        system::{
+            AreaCount, ClassificationType, OpenClassification, OperatingAreaRadius,
+            OperatorLocationSourceType, System, Timestamp, UAClassification,
+        },
+    };
+
+    #[test]
+    fn test_build() {
+        let system = System::builder()
+            .classification_type(ClassificationType::EuropeanUnion)
+            .operator_location_source_type(OperatorLocationSourceType::Fixed)
+            .operator_latitude(Latitude::Known(37.5))
+            .operator_longitude(Longitude::Known(-122.25))
+            .area_count(AreaCount::try_from(3).unwrap())
+            .area_radius(OperatingAreaRadius::try_new(500).unwrap())
+            .area_ceiling(Altitude::Known(150.0))
+            .area_floor(Altitude::Known(10.0))
+            .ua_classification(UAClassification::Open(OpenClassification::Class1))
+            .operator_altitude(Altitude::Known(12.0))
+            .timestamp(Timestamp::new(1_000))
+            .build()
+            .unwrap();
+
+        let expected = System::new(
+            ClassificationType::EuropeanUnion,
+            OperatorLocationSourceType::Fixed,
+            Latitude::Known(37.5),
+            Longitude::Known(-122.25),
+            AreaCount::try_from(3).unwrap(),
+            OperatingAreaRadius::try_new(500).unwrap(),
+            Altitude::Known(150.0),
+            Altitude::Known(10.0),
+            UAClassification::Open(OpenClassification::Class1),
+            Altitude::Known(12.0),
+            Timestamp::new(1_000),
+        );
+
+        assert_eq!(system, expected);
+    }
+
+    #[test]
+    fn test_build_defaults() {
+        let system = System::builder()
+            .timestamp(Timestamp::new(1_000))
+            .build()
+            .unwrap();
+
+        let expected = System::new(
+            ClassificationType::Undeclared,
+            OperatorLocationSourceType::TakeOff,
+            Latitude::Unknown,
+            Longitude::Unknown,
+            AreaCount::try_from(1).unwrap(),
+            OperatingAreaRadius::try_new(0).unwrap(),
+            Altitude::Unknown,
+            Altitude::Unknown,
+            UAClassification::Undefined,
+            Altitude::Unknown,
+            Timestamp::new(1_000),
+        );
+
+        assert_eq!(system, expected);
+    }
+
+    #[test]
+    fn test_build_fails_missing_timestamp() {
+        assert_eq!(System::builder().build(), Err(Error::MissingField));
+    }
+
+    #[test]
+    fn test_build_fails_inconsistent_fields() {
+        let builder = System::builder().timestamp(Timestamp::new(1_000));
+
+        assert_eq!(
+            builder
+                .ua_classification(UAClassification::Specific)
+                .build(),
+            Err(Error::InconsistentFields)
+        );
+        assert_eq!(
+            builder.operator_latitude(Latitude::Known(37.5)).build(),
+            Err(Error::InconsistentFields)
+        );
+        assert_eq!(
+            builder
+                .area_floor(Altitude::Known(150.0))
+                .area_ceiling(Altitude::Known(10.0))
+                .build(),
+            Err(Error::InconsistentFields)
+        );
+    }
+
+    #[test]
+    fn test_build_fails_out_of_range() {
+        let builder = System::builder()
+            .timestamp(Timestamp::new(1_000))
+            .operator_latitude(Latitude::Known(95.0))
+            .operator_longitude(Longitude::Known(-122.25));
+
+        assert_eq!(builder.build(), Err(Error::OutOfRange));
+    }
+}
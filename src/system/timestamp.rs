@@ -6,6 +6,8 @@
 ///
 /// Adjusting to the Unix timestamp may be done by adding [`Timestamp::UNIX_TIMESTAMP_OFFSET`].
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Timestamp(u32);
 
 impl Timestamp {
@@ -5,6 +5,7 @@ use crate::error::Error;
 /// If classification is set to [`UAClassification::Open`] (`1`), it includes an encoded form of
 /// [`OpenClassification`] internally. Otherwise it is empty.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UAClassification {
     /// Undefined classification.
     Undefined,
@@ -20,8 +21,15 @@ pub enum UAClassification {
     ///
     /// Included in specification but does not elaborate.
     Certified,
-    /// Reserved.
-    Reserved,
+    /// Reserved, carrying the whole byte, whose upper four bits hold a classification from 4 to
+    /// 15. A byte below `0x40` encodes as `0x40`.
+    Reserved(u8),
+}
+
+impl UAClassification {
+    /// Special value representing the minimum "reserved" classification, before it is shifted into
+    /// the upper four bits.
+    pub const RESERVED_THRESHOLD: u8 = 4;
 }
 
 impl From<u8> for UAClassification {
@@ -38,7 +46,7 @@ impl From<u8> for UAClassification {
             1 => Self::Open(open_classification),
             2 => Self::Specific,
             3 => Self::Certified,
-            _ => Self::Reserved,
+            _ => Self::Reserved(value),
         }
     }
 }
@@ -50,7 +58,7 @@ impl From<UAClassification> for u8 {
             UAClassification::Open(open_classification) => 1 << 4 | u8::from(open_classification),
             UAClassification::Specific => 2 << 4,
             UAClassification::Certified => 3 << 4,
-            UAClassification::Reserved => 4 << 4,
+            UAClassification::Reserved(n) => n.max(UAClassification::RESERVED_THRESHOLD << 4),
         }
     }
 }
@@ -59,6 +67,7 @@ impl From<UAClassification> for u8 {
 ///
 /// Generic system which can also be converted to region specific classification.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OpenClassification {
     /// Undefined open classification.
     Undefined,
@@ -76,16 +85,24 @@ pub enum OpenClassification {
     Class5,
     /// Class 6.
     Class6,
-    /// Reserved.
-    Reserved,
+    /// Reserved, codes 8 to 15.
+    Reserved(u8),
+}
+
+impl OpenClassification {
+    /// Special value representing the minimum "reserved" value.
+    pub const RESERVED_THRESHOLD: u8 = 8;
+
+    /// Special value representing the maximum valid value.
+    pub const MAX: u8 = 15;
 }
 
 impl TryFrom<u8> for OpenClassification {
     type Error = Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if value > 7 && value < 16 {
-            return Ok(Self::Reserved);
+        if (Self::RESERVED_THRESHOLD..=Self::MAX).contains(&value) {
+            return Ok(Self::Reserved(value));
         }
 
         match value {
@@ -104,7 +121,20 @@ impl TryFrom<u8> for OpenClassification {
 
 impl From<OpenClassification> for u8 {
     fn from(value: OpenClassification) -> Self {
-        value as u8
+        match value {
+            OpenClassification::Undefined => 0,
+            OpenClassification::Class0 => 1,
+            OpenClassification::Class1 => 2,
+            OpenClassification::Class2 => 3,
+            OpenClassification::Class3 => 4,
+            OpenClassification::Class4 => 5,
+            OpenClassification::Class5 => 6,
+            OpenClassification::Class6 => 7,
+            OpenClassification::Reserved(n) => n.clamp(
+                OpenClassification::RESERVED_THRESHOLD,
+                OpenClassification::MAX,
+            ),
+        }
     }
 }
 
@@ -121,9 +151,10 @@ mod tests {
 
     #[test]
     fn test_encode_ua_classification_reserved() {
-        let reserved = UAClassification::Reserved;
+        let reserved = UAClassification::Reserved(4 << 4);
 
         assert_eq!(u8::from(reserved), 4 << 4);
+        assert_eq!(u8::from(UAClassification::Reserved(0x27)), 4 << 4);
     }
 
     #[test]
@@ -147,7 +178,17 @@ mod tests {
     fn test_decode_ua_classification_reserved() {
         let decoded = UAClassification::from(4 << 4);
 
-        assert_eq!(decoded, UAClassification::Reserved);
+        assert_eq!(decoded, UAClassification::Reserved(4 << 4));
+    }
+
+    #[test]
+    fn test_encode_decode_ua_classification_reserved_is_lossless() {
+        for code in (UAClassification::RESERVED_THRESHOLD << 4)..=u8::MAX {
+            let decoded = UAClassification::from(code);
+
+            assert_eq!(decoded, UAClassification::Reserved(code));
+            assert_eq!(u8::from(decoded), code);
+        }
     }
 
     #[test]
@@ -171,6 +212,18 @@ mod tests {
         assert_eq!(decoded, OpenClassification::Class0);
     }
 
+    #[test]
+    fn test_encode_decode_open_classification_reserved_is_lossless() {
+        for code in OpenClassification::RESERVED_THRESHOLD..=OpenClassification::MAX {
+            let decoded = OpenClassification::try_from(code).unwrap();
+
+            assert_eq!(decoded, OpenClassification::Reserved(code));
+            assert_eq!(u8::from(decoded), code);
+        }
+
+        assert_eq!(u8::from(OpenClassification::Reserved(0)), 8);
+    }
+
     #[test]
     fn test_decode_open_classification_fails_invalid_integer() {
         assert!(OpenClassification::try_from(16).is_err());
@@ -0,0 +1,276 @@
+//! ## Bluetooth 5 Extended Advertising
+//!
+//! Extended advertising, including the long range (coded PHY) variant, carries a full [`Pack`] in a
+//! single advertising data (AD) structure. The AD structure is laid out as follows.
+//!
+//! | Offset | Length       | Field                                     |
+//! | ------ | ------------ | ----------------------------------------- |
+//! | 0      | 1            | AD length (`8 + 25 * n`, excludes itself) |
+//! | 1      | 1            | AD type, service data (`0x16`)            |
+//! | 2      | 2            | ASTM service UUID (`0xFFFA`, little end)  |
+//! | 4      | 1            | Application code (`0x0D`)                 |
+//! | 5      | 1            | Message counter                           |
+//! | 6      | `3 + 25 * n` | Pack message                              |
+//!
+//! The AD length byte bounds the structure to 255 bytes, which a full pack of nine messages fits in
+//! with an AD length of 233.
+//!
+//! Decoding distinguishes a capture cut short by the receiver, [`Error::TruncatedFrame`], from a
+//! malformed frame, [`Error::InvalidDataLength`] or [`Error::InvalidFrameHeader`].
+use crate::error::Error;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::pack::Pack;
+use crate::transport::bluetooth_legacy::Advertisement as LegacyAdvertisement;
+use crate::try_serialize::TrySerialize;
+
+/// Bluetooth Extended Advertisement
+///
+/// Contains the message counter and a [`Pack`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Advertisement {
+    message_counter: u8,
+    pack: Pack,
+}
+
+impl Advertisement {
+    /// Byte length of the AD header up to and including the message counter.
+    pub const HEADER_LENGTH: usize = 6;
+
+    /// Constructs a new extended advertisement.
+    pub fn new(message_counter: u8, pack: Pack) -> Self {
+        Self {
+            message_counter,
+            pack,
+        }
+    }
+
+    /// Returns the message counter.
+    pub fn message_counter(&self) -> u8 {
+        self.message_counter
+    }
+
+    /// Returns the pack.
+    pub fn pack(&self) -> &Pack {
+        &self.pack
+    }
+
+    /// Returns the byte length of the full AD structure, including the AD length byte.
+    pub fn encoding_byte_length(&self) -> usize {
+        1 + self.ad_length()
+    }
+
+    // value of the AD length byte, which does not count itself.
+    fn ad_length(&self) -> usize {
+        Self::HEADER_LENGTH - 1 + Message::from(self.pack).encoding_byte_length()
+    }
+}
+
+impl TryFrom<&[u8]> for Advertisement {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < Self::HEADER_LENGTH {
+            return Err(Error::TruncatedFrame);
+        }
+
+        let declared_length = 1 + value[0] as usize;
+
+        if value.len() < declared_length {
+            return Err(Error::TruncatedFrame);
+        }
+
+        if value.len() > declared_length {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let service_uuid = u16::from_le_bytes([value[2], value[3]]);
+
+        if value[1] != LegacyAdvertisement::AD_TYPE_SERVICE_DATA
+            || service_uuid != LegacyAdvertisement::SERVICE_UUID
+            || value[4] != LegacyAdvertisement::APP_CODE
+        {
+            return Err(Error::InvalidFrameHeader);
+        }
+
+        let message_counter = value[5];
+
+        let message = Message::try_from(&value[Self::HEADER_LENGTH..])?;
+
+        let MessageType::Pack(pack) = message.message_type() else {
+            return Err(Error::UnsupportedMessageType);
+        };
+
+        Ok(Self {
+            message_counter,
+            pack: *pack,
+        })
+    }
+}
+
+impl TrySerialize for Advertisement {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != self.encoding_byte_length() {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer[0] = self.ad_length() as u8;
+        buffer[1] = LegacyAdvertisement::AD_TYPE_SERVICE_DATA;
+        buffer[2..4].clone_from_slice(&LegacyAdvertisement::SERVICE_UUID.to_le_bytes());
+        buffer[4] = LegacyAdvertisement::APP_CODE;
+        buffer[5] = self.message_counter;
+
+        Message::from(self.pack).try_serialize(&mut buffer[Self::HEADER_LENGTH..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        fixtures::pack,
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        transport::bluetooth_extended::Advertisement,
+        try_serialize::TrySerialize,
+    };
+
+    #[test]
+    fn test_getters() {
+        let advertisement = Advertisement::new(9, pack());
+
+        assert_eq!(advertisement.message_counter(), 9);
+        assert_eq!(advertisement.pack(), &pack());
+        assert_eq!(advertisement.encoding_byte_length(), 9 + 2 * 25);
+    }
+
+    #[test]
+    fn test_encode() {
+        let advertisement = Advertisement::new(9, pack());
+
+        let mut encoded_pack = [0u8; 53];
+        Message::from(pack())
+            .try_serialize(&mut encoded_pack)
+            .unwrap();
+
+        let mut encoded = [0u8; 59];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[..6], [58, 0x16, 0xfa, 0xff, 0x0d, 9]);
+        assert_eq!(encoded[6..], encoded_pack);
+    }
+
+    #[test]
+    fn test_encode_full_pack() {
+        let message = Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]));
+        let advertisement = Advertisement::new(0, Pack::try_from([message; 9]).unwrap());
+
+        let mut encoded = [0u8; 234];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[0], 233);
+        assert_eq!(Advertisement::try_from(encoded.as_ref()), Ok(advertisement));
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 58];
+        let mut too_long = [0u8; 60];
+
+        let advertisement = Advertisement::new(9, pack());
+
+        assert!(advertisement.try_serialize(&mut too_short).is_err());
+        assert!(advertisement.try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let advertisement = Advertisement::new(9, pack());
+
+        let mut encoded = [0u8; 59];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        let decoded = Advertisement::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(decoded, advertisement);
+    }
+
+    #[test]
+    fn test_decode_fails_truncated() {
+        let advertisement = Advertisement::new(9, pack());
+
+        let mut encoded = [0u8; 59];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            Advertisement::try_from(&encoded[..0]),
+            Err(Error::TruncatedFrame)
+        );
+        assert_eq!(
+            Advertisement::try_from(&encoded[..5]),
+            Err(Error::TruncatedFrame)
+        );
+        assert_eq!(
+            Advertisement::try_from(&encoded[..58]),
+            Err(Error::TruncatedFrame)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let advertisement = Advertisement::new(9, pack());
+
+        let mut too_long = [0u8; 60];
+        advertisement.try_serialize(&mut too_long[..59]).unwrap();
+
+        assert_eq!(
+            Advertisement::try_from(too_long.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+
+        // AD length consistent with the slice, but not with the pack inside of it.
+        let mut inconsistent = [0u8; 60];
+        inconsistent[..59].clone_from_slice(&too_long[..59]);
+        inconsistent[0] = 59;
+
+        assert_eq!(
+            Advertisement::try_from(inconsistent.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_header() {
+        let advertisement = Advertisement::new(9, pack());
+
+        let mut encoded = [0u8; 59];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        for index in 1..5 {
+            let mut invalid = encoded;
+            invalid[index] ^= 0xff;
+
+            assert_eq!(
+                Advertisement::try_from(invalid.as_ref()),
+                Err(Error::InvalidFrameHeader)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_fails_not_pack() {
+        let message = Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]));
+
+        let mut encoded = [0u8; 31];
+        encoded[..6].clone_from_slice(&[30, 0x16, 0xfa, 0xff, 0x0d, 9]);
+        message.try_serialize(&mut encoded[6..]).unwrap();
+
+        assert_eq!(
+            Advertisement::try_from(encoded.as_ref()),
+            Err(Error::UnsupportedMessageType)
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! ## Bluetooth 4 Legacy Advertising
+//!
+//! Legacy advertising payloads are limited to 31 bytes, which is exactly enough for one advertising
+//! data (AD) structure carrying a single 25 byte [`Message`]. [`Pack`] messages
+//! cannot be carried and are rejected.
+//!
+//! The AD structure is laid out as follows.
+//!
+//! | Offset | Length | Field                                    |
+//! | ------ | ------ | ---------------------------------------- |
+//! | 0      | 1      | AD length (`30`, excludes itself)        |
+//! | 1      | 1      | AD type, service data (`0x16`)           |
+//! | 2      | 2      | ASTM service UUID (`0xFFFA`, little end) |
+//! | 4      | 1      | Application code (`0x0D`)                |
+//! | 5      | 1      | Message counter                          |
+//! | 6      | 25     | Message                                  |
+use crate::error::Error;
+use crate::messages::Message;
+use crate::pack::Pack;
+use crate::try_serialize::TrySerialize;
+
+/// Bluetooth Legacy Advertisement
+///
+/// Contains the message counter and a single, non-pack message.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Advertisement {
+    message_counter: u8,
+    message: Message,
+}
+
+impl Advertisement {
+    /// Total byte length of the AD structure, including the AD length byte.
+    pub const ENCODED_LENGTH: usize = 31;
+
+    /// Value of the AD length byte, which does not count itself.
+    pub const AD_LENGTH: u8 = 30;
+
+    /// AD type for "Service Data - 16-bit UUID".
+    pub const AD_TYPE_SERVICE_DATA: u8 = 0x16;
+
+    /// ASTM International service UUID, encoded little endian.
+    pub const SERVICE_UUID: u16 = 0xfffa;
+
+    /// Application code identifying Open Drone ID.
+    pub const APP_CODE: u8 = 0x0d;
+
+    /// Constructs a new legacy advertisement.
+    ///
+    /// Returns an error if:
+    ///
+    /// - `message` is a [`MessageType::Pack`](crate::messages::MessageType::Pack).
+    pub fn try_new(message_counter: u8, message: Message) -> Result<Self, Error> {
+        if message.is_pack() {
+            return Err(Error::UnsupportedMessageType);
+        }
+
+        Ok(Self {
+            message_counter,
+            message,
+        })
+    }
+
+    /// Returns the message counter.
+    pub fn message_counter(&self) -> u8 {
+        self.message_counter
+    }
+
+    /// Returns the message.
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+}
+
+impl TryFrom<&[u8]> for Advertisement {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() != Self::ENCODED_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let service_uuid = u16::from_le_bytes([value[2], value[3]]);
+
+        if value[0] != Self::AD_LENGTH
+            || value[1] != Self::AD_TYPE_SERVICE_DATA
+            || service_uuid != Self::SERVICE_UUID
+            || value[4] != Self::APP_CODE
+        {
+            return Err(Error::InvalidFrameHeader);
+        }
+
+        let message_counter = value[5];
+
+        // a pack header would declare a longer message, which fails the length check inside the
+        // message decoding, but we reject it explicitly for a clearer error.
+        if value[6] >> 4 == Pack::PACK_MESSAGE_CODE {
+            return Err(Error::UnsupportedMessageType);
+        }
+
+        let message = value[6..].try_into()?;
+
+        Ok(Self {
+            message_counter,
+            message,
+        })
+    }
+}
+
+impl TrySerialize for Advertisement {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != Self::ENCODED_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer[0] = Self::AD_LENGTH;
+        buffer[1] = Self::AD_TYPE_SERVICE_DATA;
+        buffer[2..4].clone_from_slice(&Self::SERVICE_UUID.to_le_bytes());
+        buffer[4] = Self::APP_CODE;
+        buffer[5] = self.message_counter;
+
+        self.message.try_serialize(&mut buffer[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        pack::Pack,
+        transport::bluetooth_legacy::Advertisement,
+        try_serialize::TrySerialize,
+    };
+
+    fn message() -> Message {
+        Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]))
+    }
+
+    #[test]
+    fn test_getters() {
+        let advertisement = Advertisement::try_new(7, message()).unwrap();
+
+        assert_eq!(advertisement.message_counter(), 7);
+        assert_eq!(advertisement.message(), &message());
+    }
+
+    #[test]
+    fn test_try_new_fails_pack() {
+        let pack = Message::from(Pack::try_from([message()]).unwrap());
+
+        assert_eq!(
+            Advertisement::try_new(0, pack),
+            Err(Error::UnsupportedMessageType)
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let advertisement = Advertisement::try_new(7, message()).unwrap();
+
+        let mut encoded_message = [0u8; 25];
+        message().try_serialize(&mut encoded_message).unwrap();
+
+        let mut encoded = [0u8; Advertisement::ENCODED_LENGTH];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[..6], [30, 0x16, 0xfa, 0xff, 0x0d, 7]);
+        assert_eq!(encoded[6..], encoded_message);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 30];
+        let mut too_long = [0u8; 32];
+
+        let advertisement = Advertisement::try_new(7, message()).unwrap();
+
+        assert!(advertisement.try_serialize(&mut too_short).is_err());
+        assert!(advertisement.try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let advertisement = Advertisement::try_new(7, message()).unwrap();
+
+        let mut encoded = [0u8; Advertisement::ENCODED_LENGTH];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        let decoded = Advertisement::try_from(encoded.as_ref()).unwrap();
+
+        assert_eq!(decoded, advertisement);
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let too_short = [0u8; 30];
+        let too_long = [0u8; 32];
+
+        assert!(Advertisement::try_from(too_short.as_ref()).is_err());
+        assert!(Advertisement::try_from(too_long.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_header() {
+        let advertisement = Advertisement::try_new(7, message()).unwrap();
+
+        let mut encoded = [0u8; Advertisement::ENCODED_LENGTH];
+        advertisement.try_serialize(&mut encoded).unwrap();
+
+        for index in 0..5 {
+            let mut invalid = encoded;
+            invalid[index] ^= 0xff;
+
+            assert_eq!(
+                Advertisement::try_from(invalid.as_ref()),
+                Err(Error::InvalidFrameHeader)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_fails_pack() {
+        let advertisement = Advertisement::try_new(7, message()).unwrap();
+
+        let mut encoded = [0u8; Advertisement::ENCODED_LENGTH];
+        advertisement.try_serialize(&mut encoded).unwrap();
+        encoded[6] = Message::PROTOCOL_VERSION | Pack::PACK_MESSAGE_CODE << 4;
+
+        assert_eq!(
+            Advertisement::try_from(encoded.as_ref()),
+            Err(Error::UnsupportedMessageType)
+        );
+    }
+}
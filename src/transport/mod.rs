@@ -0,0 +1,21 @@
+//! ## Transports
+//!
+//! The specification defines how messages are carried over each broadcast transport. The radio
+//! hardware itself is beyond the scope of this library, though the byte structures wrapping the
+//! messages are not, so this module encodes and decodes those structures around
+//! [`Message`](crate::messages::Message).
+//!
+//! Each transport prefixes its payload with an 8-bit message counter. The counter is incremented
+//! by the transmitter each time a new message is broadcast so receivers can detect dropped frames,
+//! it wraps around from `255` to `0`.
+//!
+//! 1. [`bluetooth_legacy`] wraps a single message in Bluetooth 4 legacy advertising data.
+//! 2. [`bluetooth_extended`] wraps a [`Pack`](crate::pack::Pack) in Bluetooth 5 extended
+//!    advertising data, which is also used by the long range (coded PHY) transport.
+//! 3. [`wifi_beacon`] wraps a [`Pack`](crate::pack::Pack) in a vendor specific information element
+//!    of an 802.11 Beacon frame.
+//! 4. [`wifi_nan`] wraps a [`Pack`](crate::pack::Pack) in a Wi-Fi NAN Service Discovery Frame.
+pub mod bluetooth_extended;
+pub mod bluetooth_legacy;
+pub mod wifi_beacon;
+pub mod wifi_nan;
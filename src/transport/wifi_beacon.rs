@@ -0,0 +1,305 @@
+//! ## Wi-Fi Beacon
+//!
+//! Wi-Fi Beacon transmitters carry a [`Pack`] in a vendor specific information element (IE) of an
+//! 802.11 Beacon management frame. The IE is laid out as follows.
+//!
+//! | Offset | Length       | Field                                   |
+//! | ------ | ------------ | --------------------------------------- |
+//! | 0      | 1            | Element ID, vendor specific (`0xDD`)    |
+//! | 1      | 1            | Length (`8 + 25 * n`, excludes 2 bytes) |
+//! | 2      | 3            | ASTM OUI (`FA:0B:BC`)                   |
+//! | 5      | 1            | OUI type (`0x0D`)                       |
+//! | 6      | 1            | Message counter                         |
+//! | 7      | `3 + 25 * n` | Pack message                            |
+//!
+//! Receivers generally capture the whole Beacon frame body, which starts with 12 bytes of fixed
+//! fields (timestamp, beacon interval, and capability information) followed by the IEs. The
+//! [`VendorSpecificElement::find_in_frame_body`] function walks those IEs to find the one carrying
+//! the pack.
+use crate::error::Error;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::pack::Pack;
+use crate::try_serialize::TrySerialize;
+
+/// Vendor Specific Information Element
+///
+/// Contains the message counter and a [`Pack`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VendorSpecificElement {
+    message_counter: u8,
+    pack: Pack,
+}
+
+impl VendorSpecificElement {
+    /// Byte length of the IE header up to and including the message counter.
+    pub const HEADER_LENGTH: usize = 7;
+
+    /// Byte length of the fixed fields at the start of a Beacon frame body.
+    pub const FRAME_BODY_FIXED_LENGTH: usize = 12;
+
+    /// Element ID for vendor specific IEs.
+    pub const ELEMENT_ID: u8 = 0xdd;
+
+    /// ASTM International organizationally unique identifier.
+    pub const OUI: [u8; 3] = [0xfa, 0x0b, 0xbc];
+
+    /// OUI type identifying Open Drone ID.
+    pub const OUI_TYPE: u8 = 0x0d;
+
+    /// Constructs a new vendor specific IE.
+    pub fn new(message_counter: u8, pack: Pack) -> Self {
+        Self {
+            message_counter,
+            pack,
+        }
+    }
+
+    /// Returns the message counter.
+    pub fn message_counter(&self) -> u8 {
+        self.message_counter
+    }
+
+    /// Returns the pack.
+    pub fn pack(&self) -> &Pack {
+        &self.pack
+    }
+
+    /// Returns the byte length of the full IE, including the element ID and length bytes.
+    pub fn encoding_byte_length(&self) -> usize {
+        Self::HEADER_LENGTH + Message::from(self.pack).encoding_byte_length()
+    }
+
+    /// Finds and decodes the vendor specific IE in a Beacon frame body.
+    ///
+    /// Returns [`Option::None`] if no IE carries the ASTM OUI and OUI type.
+    ///
+    /// Returns [`Result::Err`] if the frame body is truncated or the matching IE fails to be
+    /// deserialized.
+    pub fn find_in_frame_body(frame_body: &[u8]) -> Option<Result<Self, Error>> {
+        if frame_body.len() < Self::FRAME_BODY_FIXED_LENGTH {
+            return Some(Err(Error::TruncatedFrame));
+        }
+
+        let mut elements = &frame_body[Self::FRAME_BODY_FIXED_LENGTH..];
+
+        while !elements.is_empty() {
+            if elements.len() < 2 {
+                return Some(Err(Error::TruncatedFrame));
+            }
+
+            let element_length = 2 + elements[1] as usize;
+
+            if elements.len() < element_length {
+                return Some(Err(Error::TruncatedFrame));
+            }
+
+            let element = &elements[..element_length];
+
+            let is_astm_element = element[0] == Self::ELEMENT_ID
+                && element_length >= 6
+                && element[2..5] == Self::OUI
+                && element[5] == Self::OUI_TYPE;
+
+            if is_astm_element {
+                return Some(Self::try_from(element));
+            }
+
+            elements = &elements[element_length..];
+        }
+
+        None
+    }
+}
+
+impl TryFrom<&[u8]> for VendorSpecificElement {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < Self::HEADER_LENGTH {
+            return Err(Error::TruncatedFrame);
+        }
+
+        let declared_length = 2 + value[1] as usize;
+
+        if value.len() < declared_length {
+            return Err(Error::TruncatedFrame);
+        }
+
+        if value.len() > declared_length {
+            return Err(Error::InvalidDataLength);
+        }
+
+        if value[0] != Self::ELEMENT_ID || value[2..5] != Self::OUI || value[5] != Self::OUI_TYPE {
+            return Err(Error::InvalidFrameHeader);
+        }
+
+        let message_counter = value[6];
+
+        let message = Message::try_from(&value[Self::HEADER_LENGTH..])?;
+
+        let MessageType::Pack(pack) = message.message_type() else {
+            return Err(Error::UnsupportedMessageType);
+        };
+
+        Ok(Self {
+            message_counter,
+            pack: *pack,
+        })
+    }
+}
+
+impl TrySerialize for VendorSpecificElement {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != self.encoding_byte_length() {
+            return Err(Error::InvalidDataLength);
+        }
+
+        buffer[0] = Self::ELEMENT_ID;
+        buffer[1] = (self.encoding_byte_length() - 2) as u8;
+        buffer[2..5].clone_from_slice(&Self::OUI);
+        buffer[5] = Self::OUI_TYPE;
+        buffer[6] = self.message_counter;
+
+        Message::from(self.pack).try_serialize(&mut buffer[Self::HEADER_LENGTH..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error, fixtures::pack, messages::Message,
+        transport::wifi_beacon::VendorSpecificElement, try_serialize::TrySerialize,
+    };
+
+    #[test]
+    fn test_getters() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        assert_eq!(element.message_counter(), 3);
+        assert_eq!(element.pack(), &pack());
+        assert_eq!(element.encoding_byte_length(), 10 + 50);
+    }
+
+    #[test]
+    fn test_encode() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        let mut encoded_pack = [0u8; 53];
+        Message::from(pack())
+            .try_serialize(&mut encoded_pack)
+            .unwrap();
+
+        let mut encoded = [0u8; 60];
+        element.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(encoded[..7], [0xdd, 58, 0xfa, 0x0b, 0xbc, 0x0d, 3]);
+        assert_eq!(encoded[7..], encoded_pack);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 59];
+        let mut too_long = [0u8; 61];
+
+        let element = VendorSpecificElement::new(3, pack());
+
+        assert!(element.try_serialize(&mut too_short).is_err());
+        assert!(element.try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        let mut encoded = [0u8; 60];
+        element.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            VendorSpecificElement::try_from(encoded.as_ref()),
+            Ok(element)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_truncated() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        let mut encoded = [0u8; 60];
+        element.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            VendorSpecificElement::try_from(&encoded[..6]),
+            Err(Error::TruncatedFrame)
+        );
+        assert_eq!(
+            VendorSpecificElement::try_from(&encoded[..59]),
+            Err(Error::TruncatedFrame)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_header() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        let mut encoded = [0u8; 60];
+        element.try_serialize(&mut encoded).unwrap();
+
+        for index in [0, 2, 3, 4, 5] {
+            let mut invalid = encoded;
+            invalid[index] ^= 0xff;
+
+            assert_eq!(
+                VendorSpecificElement::try_from(invalid.as_ref()),
+                Err(Error::InvalidFrameHeader)
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_in_frame_body() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        // fixed fields, an SSID element, a vendor specific element with another OUI, then ours.
+        let mut frame_body = [0u8; 12 + 6 + 6 + 60];
+        frame_body[12..18].clone_from_slice(&[0x00, 4, b'R', b'I', b'D', b'!']);
+        frame_body[18..24].clone_from_slice(&[0xdd, 4, 0x00, 0x50, 0xf2, 0x02]);
+        element.try_serialize(&mut frame_body[24..]).unwrap();
+
+        assert_eq!(
+            VendorSpecificElement::find_in_frame_body(&frame_body),
+            Some(Ok(element))
+        );
+    }
+
+    #[test]
+    fn test_find_in_frame_body_none() {
+        let mut frame_body = [0u8; 12 + 6];
+        frame_body[12..18].clone_from_slice(&[0x00, 4, b'R', b'I', b'D', b'!']);
+
+        assert_eq!(VendorSpecificElement::find_in_frame_body(&frame_body), None);
+    }
+
+    #[test]
+    fn test_find_in_frame_body_fails_truncated() {
+        let element = VendorSpecificElement::new(3, pack());
+
+        let mut frame_body = [0u8; 12 + 60];
+        element.try_serialize(&mut frame_body[12..]).unwrap();
+
+        assert_eq!(
+            VendorSpecificElement::find_in_frame_body(&frame_body[..11]),
+            Some(Err(Error::TruncatedFrame))
+        );
+        assert_eq!(
+            VendorSpecificElement::find_in_frame_body(&frame_body[..13]),
+            Some(Err(Error::TruncatedFrame))
+        );
+        assert_eq!(
+            VendorSpecificElement::find_in_frame_body(&frame_body[..71]),
+            Some(Err(Error::TruncatedFrame))
+        );
+    }
+}
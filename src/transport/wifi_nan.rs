@@ -0,0 +1,346 @@
+//! ## Wi-Fi Neighbor Awareness Networking
+//!
+//! Wi-Fi NAN transmitters carry a [`Pack`] in the service specific info of a Service Descriptor
+//! Attribute (SDA), inside a NAN Service Discovery Frame (SDF). The SDF is a public action frame,
+//! and its frame body is laid out as follows.
+//!
+//! | Offset | Length       | Field                                          |
+//! | ------ | ------------ | ---------------------------------------------- |
+//! | 0      | 1            | Category, public action (`0x04`)               |
+//! | 1      | 1            | Action, vendor specific (`0x09`)               |
+//! | 2      | 3            | Wi-Fi Alliance OUI (`50:6F:9A`)                |
+//! | 5      | 1            | OUI type, NAN (`0x13`)                         |
+//! | 6      | 1            | Attribute ID, SDA (`0x03`)                     |
+//! | 7      | 2            | Attribute length (`14 + 25 * n`, little end)   |
+//! | 9      | 6            | Service ID                                     |
+//! | 15     | 1            | Instance ID (`0x01`)                           |
+//! | 16     | 1            | Requestor instance ID (`0x00`)                 |
+//! | 17     | 1            | Service control, publish with info (`0x10`)    |
+//! | 18     | 1            | Service info length (`4 + 25 * n`)             |
+//! | 19     | 1            | Message counter                                |
+//! | 20     | `3 + 25 * n` | Pack message                                   |
+//! | ...    | 7            | Service Descriptor Extension Attribute (SDEA)  |
+//!
+//! The service ID is the first 48 bits of the SHA-256 hash of the service name
+//! `org.opendroneid.remoteid`. The trailing SDEA is written with the message counter as its
+//! service update indicator, though decoding ignores any attributes after the SDA.
+use crate::error::Error;
+use crate::messages::Message;
+use crate::messages::MessageType;
+use crate::pack::Pack;
+use crate::try_serialize::TrySerialize;
+
+/// NAN Service Discovery Frame
+///
+/// Contains the message counter and a [`Pack`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ServiceDiscoveryFrame {
+    message_counter: u8,
+    pack: Pack,
+}
+
+impl ServiceDiscoveryFrame {
+    /// Byte length of the frame header up to and including the message counter.
+    pub const HEADER_LENGTH: usize = 20;
+
+    /// Byte length of the trailing Service Descriptor Extension Attribute.
+    pub const EXTENSION_LENGTH: usize = 7;
+
+    /// Public action frame category.
+    pub const CATEGORY_PUBLIC_ACTION: u8 = 0x04;
+
+    /// Vendor specific public action.
+    pub const ACTION_VENDOR_SPECIFIC: u8 = 0x09;
+
+    /// Wi-Fi Alliance organizationally unique identifier.
+    pub const OUI: [u8; 3] = [0x50, 0x6f, 0x9a];
+
+    /// OUI type identifying NAN.
+    pub const OUI_TYPE: u8 = 0x13;
+
+    /// Attribute ID of the Service Descriptor Attribute.
+    pub const SERVICE_DESCRIPTOR_ATTRIBUTE_ID: u8 = 0x03;
+
+    /// Attribute ID of the Service Descriptor Extension Attribute.
+    pub const SERVICE_DESCRIPTOR_EXTENSION_ATTRIBUTE_ID: u8 = 0x0e;
+
+    /// Service name hashed into the service ID.
+    pub const SERVICE_NAME: &'static str = "org.opendroneid.remoteid";
+
+    /// First 48 bits of the SHA-256 hash of [`ServiceDiscoveryFrame::SERVICE_NAME`].
+    pub const SERVICE_ID: [u8; 6] = [0x88, 0x69, 0x19, 0x9d, 0x92, 0x09];
+
+    /// Instance ID of the published service.
+    pub const INSTANCE_ID: u8 = 0x01;
+
+    /// Requestor instance ID, zero for unsolicited publishes.
+    pub const REQUESTOR_INSTANCE_ID: u8 = 0x00;
+
+    /// Service control for a publish carrying service specific info.
+    pub const SERVICE_CONTROL: u8 = 0x10;
+
+    /// Service Descriptor Extension Attribute control field, service update indicator present.
+    pub const EXTENSION_CONTROL: u16 = 0x0200;
+
+    /// Constructs a new service discovery frame.
+    pub fn new(message_counter: u8, pack: Pack) -> Self {
+        Self {
+            message_counter,
+            pack,
+        }
+    }
+
+    /// Returns the message counter.
+    pub fn message_counter(&self) -> u8 {
+        self.message_counter
+    }
+
+    /// Returns the pack.
+    pub fn pack(&self) -> &Pack {
+        &self.pack
+    }
+
+    /// Returns the byte length of the full frame body, including the trailing extension attribute.
+    pub fn encoding_byte_length(&self) -> usize {
+        Self::HEADER_LENGTH + self.pack_length() + Self::EXTENSION_LENGTH
+    }
+
+    // byte length of the encoded pack message.
+    fn pack_length(&self) -> usize {
+        Message::from(self.pack).encoding_byte_length()
+    }
+}
+
+impl TryFrom<&[u8]> for ServiceDiscoveryFrame {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < Self::HEADER_LENGTH {
+            return Err(Error::TruncatedFrame);
+        }
+
+        if value[0] != Self::CATEGORY_PUBLIC_ACTION
+            || value[1] != Self::ACTION_VENDOR_SPECIFIC
+            || value[2..5] != Self::OUI
+            || value[5] != Self::OUI_TYPE
+            || value[6] != Self::SERVICE_DESCRIPTOR_ATTRIBUTE_ID
+            || value[9..15] != Self::SERVICE_ID
+        {
+            return Err(Error::InvalidFrameHeader);
+        }
+
+        // the attribute length counts everything after itself, the service info length counts
+        // everything after itself, and both must agree with each other.
+        let attribute_length = u16::from_le_bytes([value[7], value[8]]) as usize;
+
+        let service_info_length = value[18] as usize;
+
+        if attribute_length != service_info_length + 10 || service_info_length == 0 {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let attribute_end = 9 + attribute_length;
+
+        if value.len() < attribute_end {
+            return Err(Error::TruncatedFrame);
+        }
+
+        let message_counter = value[19];
+
+        let message = Message::try_from(&value[Self::HEADER_LENGTH..attribute_end])?;
+
+        let MessageType::Pack(pack) = message.message_type() else {
+            return Err(Error::UnsupportedMessageType);
+        };
+
+        Ok(Self {
+            message_counter,
+            pack: *pack,
+        })
+    }
+}
+
+impl TrySerialize for ServiceDiscoveryFrame {
+    type Error = Error;
+
+    fn try_serialize(&self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() != self.encoding_byte_length() {
+            return Err(Error::InvalidDataLength);
+        }
+
+        let service_info_length = 1 + self.pack_length();
+
+        let attribute_length = 10 + service_info_length as u16;
+
+        buffer[0] = Self::CATEGORY_PUBLIC_ACTION;
+        buffer[1] = Self::ACTION_VENDOR_SPECIFIC;
+        buffer[2..5].clone_from_slice(&Self::OUI);
+        buffer[5] = Self::OUI_TYPE;
+        buffer[6] = Self::SERVICE_DESCRIPTOR_ATTRIBUTE_ID;
+        buffer[7..9].clone_from_slice(&attribute_length.to_le_bytes());
+        buffer[9..15].clone_from_slice(&Self::SERVICE_ID);
+        buffer[15] = Self::INSTANCE_ID;
+        buffer[16] = Self::REQUESTOR_INSTANCE_ID;
+        buffer[17] = Self::SERVICE_CONTROL;
+        buffer[18] = service_info_length as u8;
+        buffer[19] = self.message_counter;
+
+        let extension_start = Self::HEADER_LENGTH + self.pack_length();
+
+        Message::from(self.pack)
+            .try_serialize(&mut buffer[Self::HEADER_LENGTH..extension_start])?;
+
+        let extension = &mut buffer[extension_start..];
+
+        extension[0] = Self::SERVICE_DESCRIPTOR_EXTENSION_ATTRIBUTE_ID;
+        extension[1..3].clone_from_slice(&4u16.to_le_bytes());
+        extension[3] = Self::INSTANCE_ID;
+        extension[4..6].clone_from_slice(&Self::EXTENSION_CONTROL.to_le_bytes());
+        extension[6] = self.message_counter;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        fixtures::pack,
+        messages::Message,
+        operator_id::{OperatorID, OperatorIDType},
+        transport::wifi_nan::ServiceDiscoveryFrame,
+        try_serialize::TrySerialize,
+    };
+
+    #[test]
+    fn test_getters() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        assert_eq!(frame.message_counter(), 5);
+        assert_eq!(frame.pack(), &pack());
+        assert_eq!(frame.encoding_byte_length(), 30 + 2 * 25);
+    }
+
+    #[test]
+    fn test_encode() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        let mut encoded_pack = [0u8; 53];
+        Message::from(pack())
+            .try_serialize(&mut encoded_pack)
+            .unwrap();
+
+        let mut encoded = [0u8; 80];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            encoded[..20],
+            [
+                0x04, 0x09, 0x50, 0x6f, 0x9a, 0x13, 0x03, 64, 0, 0x88, 0x69, 0x19, 0x9d, 0x92,
+                0x09, 0x01, 0x00, 0x10, 54, 5
+            ]
+        );
+        assert_eq!(encoded[20..73], encoded_pack);
+        assert_eq!(encoded[73..], [0x0e, 4, 0, 0x01, 0x00, 0x02, 5]);
+    }
+
+    #[test]
+    fn test_encode_fails_invalid_length() {
+        let mut too_short = [0u8; 79];
+        let mut too_long = [0u8; 81];
+
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        assert!(frame.try_serialize(&mut too_short).is_err());
+        assert!(frame.try_serialize(&mut too_long).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        let mut encoded = [0u8; 80];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(ServiceDiscoveryFrame::try_from(encoded.as_ref()), Ok(frame));
+    }
+
+    #[test]
+    fn test_decode_ignores_trailing_attributes() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        let mut encoded = [0u8; 80];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(ServiceDiscoveryFrame::try_from(&encoded[..73]), Ok(frame));
+    }
+
+    #[test]
+    fn test_decode_fails_truncated() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        let mut encoded = [0u8; 80];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        assert_eq!(
+            ServiceDiscoveryFrame::try_from(&encoded[..19]),
+            Err(Error::TruncatedFrame)
+        );
+        assert_eq!(
+            ServiceDiscoveryFrame::try_from(&encoded[..72]),
+            Err(Error::TruncatedFrame)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_length() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        let mut encoded = [0u8; 80];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        let mut inconsistent = encoded;
+        inconsistent[18] += 1;
+
+        assert_eq!(
+            ServiceDiscoveryFrame::try_from(inconsistent.as_ref()),
+            Err(Error::InvalidDataLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_fails_invalid_header() {
+        let frame = ServiceDiscoveryFrame::new(5, pack());
+
+        let mut encoded = [0u8; 80];
+        frame.try_serialize(&mut encoded).unwrap();
+
+        for index in [0, 1, 2, 3, 4, 5, 6, 9, 10, 11, 12, 13, 14] {
+            let mut invalid = encoded;
+            invalid[index] ^= 0xff;
+
+            assert_eq!(
+                ServiceDiscoveryFrame::try_from(invalid.as_ref()),
+                Err(Error::InvalidFrameHeader)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_fails_not_pack() {
+        let message = Message::from(OperatorID::new(OperatorIDType::OperatorID, [2u8; 20]));
+
+        let mut encoded = [0u8; 45];
+        encoded[..20].clone_from_slice(&[
+            0x04, 0x09, 0x50, 0x6f, 0x9a, 0x13, 0x03, 36, 0, 0x88, 0x69, 0x19, 0x9d, 0x92, 0x09,
+            0x01, 0x00, 0x10, 26, 5,
+        ]);
+        message.try_serialize(&mut encoded[20..]).unwrap();
+
+        assert_eq!(
+            ServiceDiscoveryFrame::try_from(encoded.as_ref()),
+            Err(Error::UnsupportedMessageType)
+        );
+    }
+}